@@ -1,40 +1,45 @@
 /**
- * The `@polkadot/extension-dapp` package can be dynamically imported.
- * Usually it is wise to use a package manager like npm or yarn to install it as a dependency.
+ * Wallet extensions like Talisman, SubWallet and the Polkadot.js extension inject themselves
+ * under `window.injectedWeb3[source]`, each one has to be enabled before its accounts can be read.
  *
- * The `getPolkadotJsExtensionMod` closure returns the `@polkadot/extension-dapp` module on demand.
+ * The `enableSource` closure enables a source once and returns the injected extension on demand.
  */
-let getPolkadotJsExtensionMod = (() => {
-    let mod = null;
+let enableSource = (() => {
+    let enabled = {};
 
-    // initialize `@polkadot/extension-dapp` module on page load
-    let initPromise = (async () => {
-        mod = await import(
-            "https://cdn.jsdelivr.net/npm/@polkadot/extension-dapp@0.46.3/+esm"
-            );
-    })();
-
-    // return a function that waits for initialization to be finished, in case mod is not initialized yet.
-    return async () => {
-        if (mod == null) {
-            await initPromise;
+    return async (source) => {
+        if (enabled[source] == null) {
+            const injected = window.injectedWeb3?.[source];
+            if (!injected) {
+                throw `The ${source} extension is not installed`;
+            }
+            enabled[source] = await injected.enable("Corematch");
         }
-        return mod;
+        return enabled[source];
     };
 })();
 
 /**
- *  Queries wallets from browser extensions like Talisman and the Polkadot.js extension for user accounts.
+ *  Lists the wallet extensions injected in the browser.
+ *
+ *  @returns a json string with the source names found, e.g. ["talisman", "polkadot-js"].
+ */
+function getInjectedSources() {
+    return JSON.stringify(Object.keys(window.injectedWeb3 || {}));
+}
+
+/**
+ *  Queries the accounts of a wallet extension, enabling it first.
  *
+ *  @param source the extension to query, e.g. "talisman", "subwallet-js", "polkadot-js"
  *  @returns a json string that contains all the accounts that were found.
  */
-async function getAccounts() {
-    const extensionMod = await getPolkadotJsExtensionMod();
-    await extensionMod.web3Enable("Corematch");
-    const allAccounts = await extensionMod.web3Accounts();
+async function getAccounts(source) {
+    const extension = await enableSource(source);
+    const allAccounts = await extension.accounts.get();
     const accountObjects = allAccounts.map((account) => ({
-        name: account.meta.name, // e.g. "Alice"
-        source: account.meta.source, // e.g. "talisman", "polkadot-js"
+        name: account.name, // e.g. "Alice"
+        source: source, // e.g. "talisman", "polkadot-js"
         ty: account.type, // e.g. "sr25519"
         address: account.address // e.g. "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
     }));
@@ -76,9 +81,8 @@ async function getAccounts() {
  */
 async function signPayload(payloadAsStr, source, address) {
     let payload = JSON.parse(payloadAsStr);
-    const extensionMod = await getPolkadotJsExtensionMod();
-    const injector = await extensionMod.web3FromSource(source);
-    const signPayload = injector?.signer?.signPayload;
+    const extension = await enableSource(source);
+    const signPayload = extension?.signer?.signPayload;
     if (!!signPayload) {
         const {signature} = await signPayload(payload);
         console.log("signature js:", signature)
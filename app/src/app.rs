@@ -1,6 +1,9 @@
-use crate::components::buttons::NetworkButton;
+use crate::components::account_provider::{asset_hub_source, AccountProvider};
+use crate::components::buttons::{QueryButton, RecordingButton};
+use crate::components::leaderboard::LeaderboardBoard;
 use crate::components::subscription_provider::SubscriptionProvider;
 use crate::router::Query;
+use crate::settings;
 use corematch_common::components::block::{Block, BlockNumber};
 use corematch_common::components::block_timer::BlockTimer;
 use corematch_common::components::buttons::{
@@ -8,17 +11,27 @@ use corematch_common::components::buttons::{
 };
 use corematch_common::components::keyboard::SupportedKeys;
 use corematch_common::components::views::ColumnInfoView;
+use corematch_common::engine::{
+    Direction, GameCommand, GameEngine, GameEvent, DEFAULT_INITIAL_HELPS, DEFAULT_INITIAL_TRIES,
+};
+use corematch_common::runtimes::identity::{Identity, IdentityService};
 use corematch_common::runtimes::support::SupportedRelayRuntime;
-use corematch_common::types::account::{Account, SigningStatus};
-use corematch_common::types::game::{BoardStatus, GameHelpStatus, GameLevel, GameStatus};
+use corematch_common::types::account::{
+    available_wallets, Account, AccountState, AccountStatus, MintRequest, SigningStatus, Wallet,
+};
+use corematch_common::types::daily::{DailyHistory, DailyResult};
+use corematch_common::types::game::{BoardStatus, GameLevel, GameMode, GameStatus};
 use corematch_common::types::network::{
-    generate_parachain_colors, NetworkState, NetworkStatus, ParachainIds, SubscriptionId,
+    generate_parachain_colors, ConnectionMode, Daily, NetworkState, NetworkStatus, ParachainIds,
+    SubscriptionId,
 };
+use corematch_common::types::score::ScoreRecord;
 use gloo::events::EventListener;
 use gloo::timers::callback::Timeout;
-use log::info;
-use std::{collections::BTreeMap, rc::Rc};
-use subxt::utils::H256;
+use log::{error, info};
+use std::rc::Rc;
+use strum::IntoEnumIterator;
+use subxt::utils::AccountId32;
 use wasm_bindgen::{JsCast, UnwrapThrowExt};
 use web_sys::window;
 use yew::{
@@ -27,64 +40,54 @@ use yew::{
 };
 use yew_router::{prelude::LocationHandle, scope_ext::RouterScopeExt};
 
-const DEFAULT_INITIAL_POINTS: u32 = 0;
-const DEFAULT_BASE_POINTS: u32 = 4;
-const DEFAULT_INITIAL_DURATION: u32 = 0;
-const DEFAULT_INITIAL_TRIES: u32 = 4;
-const DEFAULT_INITIAL_HELPS: u32 = 8;
-pub const DEFAULT_TOTAL_BLOCKS: u32 = 9;
-
 pub enum Msg {
     NetworkSubscriptionCreated(SubscriptionId),
     NetworkDataReceived((SubscriptionId, Block)),
     NetworkParachainsCollected(ParachainIds),
+    NetworkStatusChanged(NetworkStatus),
     NetworkChanged,
     BlockClicked(usize),
     BlockPressed(usize),
-    BlockMatched(usize),
-    BlockMissed(usize),
     BlockAnimationEnded(BlockNumber),
-    CheckGameStatus,
     StartButtonClicked,
     HelpButtonClicked,
     LevelButtonClicked(GameLevel),
+    GameModeButtonClicked,
     InfoButtonClicked,
+    LeaderboardButtonClicked,
     MintButtonClicked,
+    OverwriteButtonClicked,
     NextLevel(GameLevel),
     NextLevelTimeout(GameLevel),
     //
     AccountsLoaded(Vec<Account>),
+    WalletClicked(Wallet),
     AccountClicked(Account),
+    AccountIdentityResolved(Option<Identity>),
     //
-    SigningFinished(SigningStatus),
+    SigningStatusChanged(SigningStatus),
+    HistoryLoaded(Vec<ScoreRecord>),
     //
     KeyPressed(SupportedKeys),
 }
 
-type X = u8;
-type Y = u8;
-type Position = (X, Y);
-
 pub struct App {
     board_status: BoardStatus,
     previous_board_status: Option<BoardStatus>,
     network_state: Rc<NetworkState>,
-    blocks: Vec<Option<Block>>,
-    match_position: Option<Position>,
-    match_counter: u32,
-    matches: BTreeMap<H256, u32>,
-    previous_match_block: Option<Block>,
-    game_status: GameStatus,
-    game_level: GameLevel,
-    duration: u32,
-    points: u32,
-    previous_points: u32,
-    tries: u32,
-    helps: u32,
-    game_help_status: GameHelpStatus,
-    // account_state: Rc<AccountState>,
+    engine: GameEngine,
+    account_state: Rc<AccountState>,
+    // Identities resolved from the People chain of the network
+    identity_service: Rc<IdentityService>,
+    // Identity of the selected account
+    account_identity: Option<Identity>,
+    // Progress of the latest mint, shown on the board
+    signing_status: Option<SigningStatus>,
+    // Scores minted by the selected account
+    minted_scores: Vec<ScoreRecord>,
+    // Results of the daily challenges played on the network
+    daily_history: DailyHistory,
     keyboard_listener: Option<EventListener>,
-    cursor_position: Position,
     timeout: Option<Timeout>,
     _location_listener: LocationHandle,
 }
@@ -96,10 +99,10 @@ impl Component for App {
     fn create(ctx: &Context<Self>) -> Self {
         // subscribe network from query params or default to polkadot
         let location = ctx.link().location().unwrap();
-        let runtime = location
-            .query::<Query>()
-            .map(|it| it.chain)
-            .unwrap_or(SupportedRelayRuntime::Polkadot);
+        let query = location.query::<Query>().unwrap_or_default();
+        let runtime = query.chain;
+        let connection_mode = settings::connection_mode(query.mode);
+        let rpc_urls = settings::rpc_urls(runtime, query.rpc.clone());
 
         // listener to handle location changes
         let location_listener = ctx
@@ -111,43 +114,51 @@ impl Component for App {
         let runtime_callback = ctx.link().callback(Msg::NetworkDataReceived);
         let subscription_callback = ctx.link().callback(Msg::NetworkSubscriptionCreated);
         let parachains_callback = ctx.link().callback(Msg::NetworkParachainsCollected);
+        let status_callback = ctx.link().callback(Msg::NetworkStatusChanged);
         // Initialized shared state
-        let network_state = Rc::new(NetworkState::new(
-            runtime.clone(),
+        let mut network_state = NetworkState::new(
+            runtime,
+            connection_mode,
+            rpc_urls,
             runtime_callback,
             subscription_callback,
             parachains_callback,
-        ));
-
-        // TODO: verify if account is available from localstorage
+            status_callback,
+        );
+        network_state.replay = settings::replay(&query);
+        network_state.practice = settings::practice(runtime, &query);
+        network_state.recording = settings::recording(&query);
+        network_state.board_size = settings::board_size(query.board);
+        let game_mode = settings::game_mode(query.game);
+        network_state.daily = settings::daily(game_mode, &query);
+        let mut engine = GameEngine::new();
+        engine.handle(GameCommand::ResizeBoard(network_state.board_size));
+        engine.handle(GameCommand::ChangeMode(game_mode));
+        let network_state = Rc::new(network_state);
+
+        // define account callbacks
         let accounts_callback = ctx.link().callback(Msg::AccountsLoaded);
-        let signing_callback = ctx.link().callback(Msg::SigningFinished);
-        // let account_state = Rc::new(AccountState::new(
-        //     SupportedParachainRuntime::AssetHubPolkadot,
-        //     accounts_callback.clone(),
-        //     signing_callback.clone(),
-        // ));
+        let signing_callback = ctx.link().callback(Msg::SigningStatusChanged);
+        let history_callback = ctx.link().callback(Msg::HistoryLoaded);
+        let account_state = Rc::new(AccountState::new(
+            asset_hub_source().runtime(),
+            accounts_callback,
+            signing_callback,
+            history_callback,
+        ));
 
         Self {
             board_status: BoardStatus::Game,
             previous_board_status: None,
             network_state,
-            blocks: vec![None; DEFAULT_TOTAL_BLOCKS.try_into().unwrap()],
-            match_position: None,
-            match_counter: 0,
-            matches: BTreeMap::new(),
-            previous_match_block: None,
-            game_status: GameStatus::Init,
-            game_level: GameLevel::Level1,
-            duration: DEFAULT_INITIAL_DURATION,
-            points: DEFAULT_INITIAL_POINTS,
-            previous_points: DEFAULT_INITIAL_POINTS,
-            tries: DEFAULT_INITIAL_TRIES,
-            helps: DEFAULT_INITIAL_HELPS,
-            game_help_status: GameHelpStatus::Available,
-            // account_state,
+            engine,
+            account_state,
+            identity_service: Rc::new(IdentityService::new(runtime)),
+            account_identity: None,
+            signing_status: None,
+            minted_scores: vec![],
+            daily_history: settings::daily_history(runtime),
             keyboard_listener: None,
-            cursor_position: (0, 0),
             timeout: None,
             _location_listener: location_listener,
         }
@@ -157,237 +168,68 @@ impl Component for App {
         match msg {
             Msg::NetworkChanged => {
                 let location = ctx.link().location().unwrap();
-                let runtime = location
-                    .query::<Query>()
-                    .map(|it| it.chain)
-                    .unwrap_or(SupportedRelayRuntime::Polkadot);
+                let query = location.query::<Query>().unwrap_or_default();
+                let runtime = query.chain;
+                let connection_mode = settings::connection_mode(query.mode);
+                let rpc_urls = settings::rpc_urls(runtime, query.rpc.clone());
                 if self.network_state.is_active() {
                     let network_state = Rc::make_mut(&mut self.network_state);
                     network_state.status = NetworkStatus::Switching;
-                    // network_state.runtime = SupportedRelayRuntime::from(network);
                     network_state.runtime = runtime;
-                    // NOTE: if network (relay) changes than account_state runtime (asset-hub) also changes
-                    // let account_state = Rc::make_mut(&mut self.account_state);
-                    // account_state.runtime = network_state.runtime.asset_hub_runtime();
+                    network_state.connection_mode = connection_mode;
+                    network_state.rpc_urls = rpc_urls;
+                    network_state.replay = settings::replay(&query);
+                    network_state.practice = settings::practice(runtime, &query);
+                    network_state.recording = settings::recording(&query);
+                    network_state.board_size = settings::board_size(query.board);
+                    network_state.daily = settings::daily(self.engine.game_mode(), &query);
+                    if network_state.board_size != self.engine.board_size() {
+                        self.engine
+                            .handle(GameCommand::ResizeBoard(network_state.board_size));
+                    }
+                    if self.identity_service.runtime() != runtime {
+                        self.identity_service = Rc::new(IdentityService::new(runtime));
+                        self.resolve_account_identity(ctx);
+                        self.daily_history = settings::daily_history(runtime);
+                    }
 
-                    self.game_status = GameStatus::Reload;
+                    self.engine.handle(GameCommand::Reload);
                 }
             }
             Msg::NetworkSubscriptionCreated(subscription_id) => {
                 let network_state = Rc::make_mut(&mut self.network_state);
                 network_state.subscription_id = Some(subscription_id);
+                // NOTE: a subscription recreated after a connection failure carries on from the
+                // last block received, so the game in progress is kept
+                if !network_state.is_reconnecting() {
+                    // apply a full reset
+                    self.engine.handle(GameCommand::FullReset);
+                }
                 network_state.status = NetworkStatus::Active;
-                // apply a full reset
-                self.full_reset();
+            }
+            Msg::NetworkStatusChanged(status) => {
+                let network_state = Rc::make_mut(&mut self.network_state);
+                network_state.status = status;
             }
             Msg::NetworkParachainsCollected(para_ids) => {
                 let network_state = Rc::make_mut(&mut self.network_state);
+                if !network_state.is_practicing() && !network_state.is_replaying() {
+                    settings::remember_para_ids(network_state.runtime, &para_ids);
+                }
                 network_state.parachain_colors = generate_parachain_colors(para_ids.clone());
             }
             Msg::NetworkDataReceived((subscription_id, block)) => {
-                // FOR TESTING ONLY -- start
-                // if self.game_status == GameStatus::Minting {
-                //     return true;
-                // }
-                // self.game_status = GameStatus::Over;
-                // self.board_status = BoardStatus::Options;
-                // FOR TESTING ONLY -- end
-
                 if self.network_state.is_valid(subscription_id) {
-                    // reset match block
-                    self.reset_match_block();
-                    // add latest block into the first position
-                    self.blocks.insert(0, Some(block.clone()));
-                    let block_hash = block.corespace_hash(self.game_level.clone());
-                    // add match counter for block_hash_key
-                    self.matches
-                        .entry(block_hash)
-                        .and_modify(|m| *m += 1)
-                        .or_insert(1);
-                    // oldest block gets removed
-                    if self.blocks.len() > DEFAULT_TOTAL_BLOCKS.try_into().unwrap() {
-                        if let Some(opt) = self.blocks.pop() {
-                            if let Some(block) = opt {
-                                let block_hash = block.corespace_hash(self.game_level.clone());
-                                // subtract counter from block_hash_key
-                                self.matches.entry(block_hash.clone()).and_modify(|m| {
-                                    if *m >= 1 {
-                                        *m -= 1
-                                    }
-                                });
-                                // remove if counter is zero
-                                if let Some(counter) = self.matches.get(&block_hash) {
-                                    if *counter == 0 {
-                                        self.matches.remove(&block_hash);
-                                    }
-                                }
-                            }
-                        }
-                    }
-
-                    if self.is_game_on() {
-                        // guarantee that only the current cursor position is selected
-                        let cursor_index = self.get_cursor_index();
-                        for (i, opt) in self.blocks.iter_mut().enumerate() {
-                            if let Some(block) = opt {
-                                if self.game_status == GameStatus::On && cursor_index == i {
-                                    block.selected();
-                                } else {
-                                    block.unselected();
-                                    block.cleared();
-                                }
-                            }
-                        }
-
-                        // highlight matches if help is on
-                        if self.game_help_status.is_on() {
-                            let matches: Vec<_> = Vec::from_iter(self.matches.iter())
-                                .iter()
-                                .filter(|(_, counter)| **counter > 1)
-                                .map(|(hash, _)| **hash)
-                                .collect();
-
-                            // highlight only the same pattern at a time
-                            if matches.len() > 1 {
-                                let mut help_matches_counter = 0;
-                                if let Some(block_hash) = matches.get(matches.len() - 1) {
-                                    for opt in self.blocks.iter_mut() {
-                                        if let Some(block) = opt {
-                                            if *block_hash
-                                                == block.corespace_hash(self.game_level.clone())
-                                            {
-                                                if block.is_help_available() && !block.is_disabled()
-                                                {
-                                                    block.help();
-                                                    help_matches_counter += 1;
-                                                }
-                                            }
-                                        }
-                                    }
-                                    self.decr_help_matches(help_matches_counter);
-                                }
-                            }
-                        }
-                    }
-
-                    // update game stats if game is on
-                    self.incr_duration();
+                    self.dispatch(ctx, GameCommand::PushBlock(block));
                 }
             }
             Msg::BlockClicked(i) => {
-                if self.is_game_on() {
-                    let cursor_index = self.get_cursor_index();
-                    if cursor_index != i {
-                        self.unselect_block(cursor_index);
-                    }
-                    self.set_cursor_position(i);
-                    self.select_block(i);
-                }
+                self.dispatch(ctx, GameCommand::SelectCell(i));
             }
             Msg::BlockPressed(i) => {
-                if self.is_game_on() {
-                    let match_block = self.get_match_block();
-                    if let Some(opt) = self.blocks.get(i) {
-                        if let Some(block) = opt {
-                            if block.is_matched() || block.is_disabled() {
-                                return false;
-                            }
-                            if let Some(match_block) = match_block {
-                                let corespace_hash = block.corespace_hash(self.game_level.clone());
-                                if match_block.block_number == block.block_number {
-                                    // unselect previous match block
-                                    self.match_position = None;
-                                    return false;
-                                } else if match_block.corespace_hash(self.game_level.clone())
-                                    == corespace_hash
-                                {
-                                    ctx.link().send_message(Msg::BlockMatched(i));
-                                } else {
-                                    ctx.link().send_message(Msg::BlockMissed(i));
-                                }
-                            } else {
-                                // first block pressed is the one to be matched
-                                let cursor_index = self.get_cursor_index();
-                                self.set_match_position(cursor_index);
-                            }
-                        }
-                    }
-                }
-            }
-            Msg::BlockMatched(i) => {
-                info!("Congrats, you found a match!");
-                if let Some(opt) = self.blocks.get_mut(i) {
-                    if let Some(block) = opt {
-                        // highlight block matched
-                        block.matched();
-                    }
-                }
-                if let Some(i) = self.get_match_index() {
-                    if let Some(opt) = self.blocks.get_mut(i) {
-                        if let Some(block) = opt {
-                            // highlight block matched
-                            block.matched();
-                            // remove from matches
-                            let block_hash = block.corespace_hash(self.game_level.clone());
-                            // subtract counter from block_hash_key
-                            self.matches.entry(block_hash.clone()).and_modify(|m| {
-                                if *m >= 2 {
-                                    *m -= 2
-                                }
-                            });
-                        }
-                    }
-                }
-                // increase points
-                self.match_succeed();
-            }
-            Msg::BlockMissed(i) => {
-                info!("Wrong match!");
-                if let Some(opt) = self.blocks.get_mut(i) {
-                    if let Some(block) = opt {
-                        block.missed();
-                    }
-                }
-                if let Some(i) = self.get_match_index() {
-                    if let Some(opt) = self.blocks.get_mut(i) {
-                        if let Some(block) = opt {
-                            // highlight block matched
-                            block.missed();
-                        }
-                    }
-                }
-                // decrease attempts
-                self.match_failed();
-                // check status
-                ctx.link().send_message(Msg::CheckGameStatus);
-            }
-            Msg::CheckGameStatus => {
-                // verify if game is over
-                if self.is_game_over() {
-                    info!("** Game Over **");
-                    // keep a copy of the last match block
-                    if let Some(index) = self.get_match_index() {
-                        if let Some(opt) = self.blocks.get(index) {
-                            if let Some(match_block) = opt {
-                                self.previous_match_block.replace(match_block.clone());
-                                info!("\n{}", self.share_message().unwrap_or_default());
-                                // clear selected block
-                                let i = self.get_cursor_index();
-                                self.unselect_block(i);
-                                // show available options
-                                self.board_status = BoardStatus::Options;
-                            }
-                        }
-                    }
-                }
-                // reset match block
-                self.reset_match_block();
+                self.dispatch(ctx, GameCommand::PressCell(i));
             }
             Msg::NextLevel(next_level) => {
-                self.game_status = GameStatus::MoveTo(next_level.clone());
-                // restore helps at each new level
-                self.helps = DEFAULT_INITIAL_HELPS;
-                self.game_help_status = GameHelpStatus::Available;
                 // set timeout to continue
                 let handle = {
                     let link = ctx.link().clone();
@@ -398,162 +240,142 @@ impl Component for App {
                 self.timeout = Some(handle);
             }
             Msg::NextLevelTimeout(next_level) => {
-                self.game_level = next_level;
-                self.game_status = GameStatus::On;
+                self.dispatch(ctx, GameCommand::EnterLevel(next_level));
                 self.timeout = None;
             }
             Msg::BlockAnimationEnded(block_number) => {
-                if let Some(i) = self
-                    .blocks
-                    .iter()
-                    .position(|opt| opt.clone().unwrap().block_number == block_number)
-                {
-                    if let Some(block_option) = self.blocks.get_mut(i) {
-                        if let Some(block) = block_option {
-                            if block.is_matched() {
-                                // disable block
-                                block.disabled();
-                                // check if is time to move to next level
-                                if self.is_next_level_available(GameLevel::Level1) {
-                                    info!("Well Done! Level 2 available for playing.");
-                                    ctx.link().send_message(Msg::NextLevel(GameLevel::Level2));
-                                }
-                            } else {
-                                block.cleared();
-                            }
-                        }
-                    }
-                }
+                self.dispatch(ctx, GameCommand::AnimationEnded(block_number));
             }
             Msg::StartButtonClicked => {
-                self.start();
+                self.start_or_stop(ctx);
             }
             Msg::HelpButtonClicked => {
-                // reset matches map
-                let mut matches: BTreeMap<H256, u32> = BTreeMap::new();
-                for opt in self.blocks.iter() {
-                    if let Some(block) = opt {
-                        let block_hash = block.corespace_hash(self.game_level.clone());
-                        matches
-                            .entry(block_hash)
-                            .and_modify(|m| *m += 1)
-                            .or_insert(1);
-                    }
-                }
-                self.matches = matches.clone();
-                self.start_help();
+                self.dispatch(ctx, GameCommand::RequestHelp);
             }
             Msg::InfoButtonClicked => {
-                if self.board_status == BoardStatus::About {
-                    if let Some(previous) = self.previous_board_status.clone() {
-                        self.board_status = previous;
-                    }
+                self.toggle_board(BoardStatus::About);
+            }
+            Msg::LeaderboardButtonClicked => {
+                self.toggle_board(BoardStatus::Leaderboard);
+            }
+            Msg::MintButtonClicked => {
+                let Some(score) = self.engine.score_record() else {
+                    return false;
+                };
+                self.signing_status = None;
+                self.minted_scores = vec![];
+                self.previous_board_status = Some(self.board_status.clone());
+                let account_state = Rc::make_mut(&mut self.account_state);
+                if account_state.account.is_none() {
+                    // select the wallet to load accounts from for the user to select one
+                    account_state.status =
+                        AccountStatus::WalletSelection(available_wallets(account_state.runtime));
+                    self.board_status = BoardStatus::Account;
                 } else {
-                    self.previous_board_status = Some(self.board_status.clone());
-                    self.board_status = BoardStatus::About;
+                    account_state.status = AccountStatus::Signing(MintRequest::new(score));
+                    self.board_status = BoardStatus::Mint;
                 }
             }
-            Msg::MintButtonClicked => {
-                info!("MintButtonClicked");
-                // TODO:
-                // self.board_status = BoardStatus::Mint;
-                // if self.account_state.is_none() {
-                //     let account_state = Rc::make_mut(&mut self.account_state);
-                //     account_state.status = AccountStatus::Requesting;
-                // } else if self.account_state.is_available() {
-                //     let results = self.game_results().unwrap();
-                //     let account_state = Rc::make_mut(&mut self.account_state);
-                //     account_state.status = AccountStatus::Signing(results);
-                // }
-
-                // TODO
-                // 1. check if account is already loaded from pjs
-                // 1.1 If Not in state launch pjs and list accounts for user to select
-                // 1.2 If account in state send mint tx
-                // if self.account_state.account.is_none() {
-                //     ctx.link()
-                //         .send_future(get_accounts().map(|accounts_or_err| match accounts_or_err {
-                //             Ok(accounts) => Message::ReceivedAccounts(accounts),
-                //             Err(err) => Message::Error(err),
-                //         }));
-                // }
+            Msg::OverwriteButtonClicked => {
+                let (Some(score), Some(_)) =
+                    (self.engine.score_record(), &self.account_state.account)
+                else {
+                    return false;
+                };
+                self.signing_status = None;
+                let account_state = Rc::make_mut(&mut self.account_state);
+                account_state.status = AccountStatus::Signing(MintRequest {
+                    score,
+                    overwrite: true,
+                });
             }
             Msg::LevelButtonClicked(game_level) => {
-                self.game_level = game_level;
+                self.dispatch(ctx, GameCommand::ChangeLevel(game_level));
+            }
+            Msg::GameModeButtonClicked => {
+                let game_mode = self.engine.game_mode().next();
+                self.dispatch(ctx, GameCommand::ChangeMode(game_mode));
             }
             Msg::AccountsLoaded(accounts) => {
-                // let account_state = Rc::make_mut(&mut self.account_state);
-                // account_state.status = AccountStatus::Selection(accounts);
-                // change board view to manage accounts
-                self.previous_board_status = Some(self.board_status.clone());
-                self.board_status = BoardStatus::Account;
+                let account_state = Rc::make_mut(&mut self.account_state);
+                account_state.status = AccountStatus::Selection(accounts);
+            }
+            Msg::WalletClicked(wallet) => {
+                let account_state = Rc::make_mut(&mut self.account_state);
+                account_state.status = AccountStatus::Requesting(wallet);
             }
             Msg::AccountClicked(account) => {
-                // let account_state = Rc::make_mut(&mut self.account_state);
-                // account_state.account = Some(account);
-                // account_state.status = AccountStatus::Selected;
-                //
-                // change board to previous board or game view
-                if let Some(previous) = self.previous_board_status.clone() {
-                    self.board_status = previous;
-                } else {
-                    self.board_status = BoardStatus::Game;
+                let account_state = Rc::make_mut(&mut self.account_state);
+                account_state.account = Some(account);
+                // mint results straight away, the account was selected to do so
+                match self.engine.score_record() {
+                    Some(score) => {
+                        account_state.status = AccountStatus::Signing(MintRequest::new(score));
+                        self.board_status = BoardStatus::Mint;
+                    }
+                    None => {
+                        account_state.status = AccountStatus::Selected;
+                        self.board_status = self
+                            .previous_board_status
+                            .clone()
+                            .unwrap_or(BoardStatus::Game);
+                    }
                 }
+                self.resolve_account_identity(ctx);
             }
-            Msg::SigningFinished(status) => {
-                info!("__SigningFinished {:?}", status);
-                match status {
-                    SigningStatus::Failed => {
-                        // Restore game status and let user decide what to do next
-                        self.game_status = GameStatus::Over;
-                        //
-                        // let account_state = Rc::make_mut(&mut self.account_state);
-                        // account_state.status = AccountStatus::Selected;
+            Msg::SigningStatusChanged(status) => {
+                info!("Signing status changed: {:?}", status);
+                if status.is_finished() {
+                    let account_state = Rc::make_mut(&mut self.account_state);
+                    account_state.status = if account_state.account.is_some() {
+                        AccountStatus::Selected
+                    } else {
+                        AccountStatus::None
+                    };
+                    // NOTE: accounts could not be loaded, show the failure on the mint board
+                    if self.board_status == BoardStatus::Account {
+                        self.board_status = BoardStatus::Mint;
                     }
-                    _ => todo!(),
                 }
-                // let account_state = Rc::make_mut(&mut self.account_state);
-                // account_state.status = AccountStatus::Selected;
-                // // reset game
-                // self.reset();
+                self.signing_status = Some(status);
+            }
+            Msg::AccountIdentityResolved(identity) => {
+                self.account_identity = identity;
+            }
+            Msg::HistoryLoaded(scores) => {
+                self.minted_scores = scores;
             }
             Msg::KeyPressed(key) => {
                 match key {
                     SupportedKeys::Enter => {
-                        if !self.is_game_on() {
-                            self.start()
+                        if !self.engine.is_game_on() {
+                            self.start_or_stop(ctx)
                         } else {
-                            let i = self.get_cursor_index();
+                            let i = self.engine.cursor_index();
                             ctx.link().send_message(Msg::BlockPressed(i))
                         }
                     }
                     SupportedKeys::Space => {
-                        if self.is_game_on() {
-                            let i = self.get_cursor_index();
+                        if self.engine.is_game_on() {
+                            let i = self.engine.cursor_index();
                             ctx.link().send_message(Msg::BlockPressed(i))
                         }
                         // TODO: if game over space could be used to restart the game
                         info!("Skip")
                     }
-                    SupportedKeys::Up => match self.cursor_position.1 {
-                        0 => self.move_cursor((self.cursor_position.0, 2)),
-                        _ => self.move_cursor((self.cursor_position.0, self.cursor_position.1 - 1)),
-                    },
-                    SupportedKeys::Down => match self.cursor_position.1 {
-                        2 => self.move_cursor((self.cursor_position.0, 0)),
-                        _ => self.move_cursor((self.cursor_position.0, self.cursor_position.1 + 1)),
-                    },
-                    SupportedKeys::Left => match self.cursor_position.0 {
-                        0 => self.move_cursor((2, self.cursor_position.1)),
-                        _ => self.move_cursor((self.cursor_position.0 - 1, self.cursor_position.1)),
-                    },
-                    SupportedKeys::Right => match self.cursor_position.0 {
-                        2 => self.move_cursor((0, self.cursor_position.1)),
-                        _ => self.move_cursor((self.cursor_position.0 + 1, self.cursor_position.1)),
-                    },
-                    SupportedKeys::S => self.start(),
-                    SupportedKeys::H => self.start_help(),
-                    SupportedKeys::F => self.show_details(),
+                    SupportedKeys::Up => self.dispatch(ctx, GameCommand::MoveCursor(Direction::Up)),
+                    SupportedKeys::Down => {
+                        self.dispatch(ctx, GameCommand::MoveCursor(Direction::Down))
+                    }
+                    SupportedKeys::Left => {
+                        self.dispatch(ctx, GameCommand::MoveCursor(Direction::Left))
+                    }
+                    SupportedKeys::Right => {
+                        self.dispatch(ctx, GameCommand::MoveCursor(Direction::Right))
+                    }
+                    SupportedKeys::S => self.start_or_stop(ctx),
+                    SupportedKeys::H => self.dispatch(ctx, GameCommand::RequestHelp),
+                    SupportedKeys::F => self.dispatch(ctx, GameCommand::FlipCell),
                     _ => info!("Skip"),
                 };
             }
@@ -563,22 +385,15 @@ impl Component for App {
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let network_state = self.network_state.clone();
-        // let account_state = self.account_state.clone();
-        // html! {
-        //     <ContextProvider<Rc<NetworkState>> context={ network_state.clone() }>
-
-        //         <ContextProvider<Rc<AccountState>> context={ account_state.clone() }>
-
-        //             { self.app_view(ctx.link()) }
-
-        //         </ContextProvider<Rc<AccountState>>>
-
-        //     </ContextProvider<Rc<NetworkState>>>
-        // }
+        let account_state = self.account_state.clone();
         html! {
             <ContextProvider<Rc<NetworkState>> context={ network_state.clone() }>
 
-                { self.app_view(ctx.link()) }
+                <ContextProvider<Rc<AccountState>> context={ account_state.clone() }>
+
+                    { self.app_view(ctx.link()) }
+
+                </ContextProvider<Rc<AccountState>>>
 
             </ContextProvider<Rc<NetworkState>>>
         }
@@ -609,15 +424,19 @@ impl App {
                     </div>
                     <div class="content__body">
                         <div class="cb__left">
-                        //     { self.left_top_view(link) }
-                        //     { self.left_bottom_view(link) }
+                        //     { self._left_top_view(link) }
+                        //     { self._left_bottom_view(link) }
                         </div>
                         <div class="cb__middle">
                             {
                                 match self.board_status {
                                     BoardStatus::About => { html! {  self.about_view(link) } }
                                     BoardStatus::Options => { html! {  self.options_view(link) } }
-                                    // BoardStatus::Account => { html! {  self.accounts_view(link) } }
+                                    BoardStatus::Account => { html! {  self.accounts_view(link) } }
+                                    BoardStatus::Mint => { html! {  self.mint_view(link) } }
+                                    BoardStatus::Leaderboard => { html! {
+                                        <LeaderboardBoard runtime={self.network_state.runtime} account={self.account_state.account.clone()} identity_service={self.identity_service.clone()} />
+                                    } }
                                     _ => { self.game_view(link) }
                                 }
                             }
@@ -641,7 +460,20 @@ impl App {
             <div class="gameover">
                 <img class="corematch__icon" src="/images/corematch_icon_animated_gameover.svg" alt="corematch icon animated" />
                 <div class="score">
-                    <span>{"SCORE: "}<b>{game_results}</b></span>
+                    {
+                        match self.daily_result() {
+                            Some(result) => html! {
+                                <>
+                                    <span>{"DAILY CHALLENGE: "}<b>{result.day.to_string()}</b></span>
+                                    <span>{"SCORE: "}<b>{game_results}</b></span>
+                                    <span>{"STREAK: "}<b>{format!("{} days (best {})", self.daily_history.streak(result.day), self.daily_history.best_streak())}</b></span>
+                                </>
+                            },
+                            None => html! {
+                                <span>{"SCORE: "}<b>{game_results}</b></span>
+                            },
+                        }
+                    }
                 </div>
                 <div class="action">
                     <ActionButton label={"play"} disable={false} onclick={play_again_onclick}>
@@ -650,7 +482,7 @@ impl App {
                     <ShareButton label={"share"} data={data.clone()}>
                         <img class="icon" src="/images/share_icon_white_clear.svg" alt="share_icon" />
                     </ShareButton>
-                    <MintButton  label={"mint"} onclick={mint_onclick}>
+                    <MintButton  label={"mint"} disable={!self.is_mint_supported()} onclick={mint_onclick}>
                         <img class="icon" src="/images/mint_icon_white_clear.svg" alt="mint_icon" />
                     </MintButton>
                 </div>
@@ -658,41 +490,142 @@ impl App {
         }
     }
 
-    // fn accounts_status_view(&self, msg: &str) -> Html {
-    //     html! {
-    //         <div class="status__msg">
-    //             <h4>{msg}</h4>
-    //         </div>
-    //     }
-    // }
+    fn accounts_status_view(&self, msg: &str) -> Html {
+        html! {
+            <div class="status__msg">
+                <h4>{msg}</h4>
+            </div>
+        }
+    }
 
-    // fn list_accounts_view(&self, accounts: Vec<Account>, link: &Scope<Self>) -> Html {
-    //     html! {
-    //         <div>
-    //             { for accounts.iter().map(|account| {
-    //                     let acc = account.clone();
-    //                     let account_clicked = link.callback(move |_| Msg::AccountClicked(acc.clone()));
-    //                     account.render(account_clicked.clone())
-    //                 })
-    //             }
-    //         </div>
-    //     }
-    // }
+    fn list_wallets_view(&self, wallets: &[Wallet], link: &Scope<Self>) -> Html {
+        if wallets.is_empty() {
+            return self.accounts_status_view("no wallets found");
+        }
+        html! {
+            <div>
+                <h6>{"Select a wallet"}</h6>
+                { for wallets.iter().map(|wallet| {
+                        let wallet = *wallet;
+                        let wallet_clicked = link.callback(move |_| Msg::WalletClicked(wallet));
+                        wallet.render(wallet_clicked)
+                    })
+                }
+            </div>
+        }
+    }
 
-    // fn accounts_view(&self, link: &Scope<Self>) -> Html {
-    //     html! {
-    //         <div class="accounts">
-    //             {
-    //                 match &self.account_state.status {
-    //                     AccountStatus::Requesting => { html! {  self.accounts_status_view("loading...") } }
-    //                     AccountStatus::Signing(_) => { html! {  self.accounts_status_view("signing...") } }
-    //                     AccountStatus::Selection(accounts) => { html! {  self.list_accounts_view(accounts.clone(), link) } }
-    //                     _ => unimplemented!()
-    //                 }
-    //             }
-    //         </div>
-    //     }
-    // }
+    fn list_accounts_view(&self, accounts: &[Account], link: &Scope<Self>) -> Html {
+        if accounts.is_empty() {
+            return self.accounts_status_view("no accounts found");
+        }
+        html! {
+            <div>
+                <h6>{format!("Select an {} account", self.account_state.runtime)}</h6>
+                { for accounts.iter().map(|account| {
+                        let acc = account.clone();
+                        let account_clicked = link.callback(move |_| Msg::AccountClicked(acc.clone()));
+                        account.render(account_clicked)
+                    })
+                }
+            </div>
+        }
+    }
+
+    fn accounts_view(&self, link: &Scope<Self>) -> Html {
+        html! {
+            <div class="accounts">
+                {
+                    match &self.account_state.status {
+                        AccountStatus::WalletSelection(wallets) => { self.list_wallets_view(wallets, link) }
+                        AccountStatus::Selection(accounts) => { self.list_accounts_view(accounts, link) }
+                        _ => { self.accounts_status_view("loading...") }
+                    }
+                }
+            </div>
+        }
+    }
+
+    fn mint_view(&self, link: &Scope<Self>) -> Html {
+        let play_again_onclick = link.callback(move |_| Msg::StartButtonClicked);
+        let mint_onclick = link.callback(move |_| Msg::MintButtonClicked);
+        let overwrite_onclick = link.callback(move |_| Msg::OverwriteButtonClicked);
+        let account_name = self
+            .account_state
+            .account
+            .as_ref()
+            .map(|account| self.account_label(account))
+            .unwrap_or_default();
+        let (title, details) = match &self.signing_status {
+            None => ("minting...", format!("Estimating fees for {account_name}")),
+            Some(status) => (status.title(), status.details()),
+        };
+        let is_finished = self
+            .signing_status
+            .as_ref()
+            .is_some_and(|status| status.is_finished());
+        let is_succeeded = self
+            .signing_status
+            .as_ref()
+            .is_some_and(|status| status.is_succeeded());
+        let is_not_improved = matches!(self.signing_status, Some(SigningStatus::NotImproved(_)));
+        html! {
+            <div class="game-minting">
+                <div class="status__msg">
+                    <h4>{title}</h4>
+                    <p class="mint__details">{details}</p>
+                </div>
+                { self.history_view() }
+                {
+                    if is_finished {
+                        html! {
+                            <div class="action">
+                                <ActionButton label={"play"} disable={false} onclick={play_again_onclick}>
+                                    <img class="icon" src="/images/start_icon_white_clear.svg" alt="start_icon" />
+                                </ActionButton>
+                                {
+                                    if is_not_improved {
+                                        html! {
+                                            <MintButton label={"overwrite"} disable={!self.is_mint_supported()} onclick={overwrite_onclick}>
+                                                <img class="icon" src="/images/mint_icon_white_clear.svg" alt="mint_icon" />
+                                            </MintButton>
+                                        }
+                                    } else if !is_succeeded {
+                                        html! {
+                                            <MintButton label={"retry"} disable={!self.is_mint_supported()} onclick={mint_onclick}>
+                                                <img class="icon" src="/images/mint_icon_white_clear.svg" alt="mint_icon" />
+                                            </MintButton>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        }
+    }
+
+    fn history_view(&self) -> Html {
+        if self.minted_scores.is_empty() {
+            return html! {};
+        }
+        html! {
+            <ul class="history">
+                { for self.minted_scores.iter().map(|score| html! {
+                    <li class="history__item">
+                        <span class="history__level">{format!("{} · Level {}", score.network, score.level)}</span>
+                        <span class="history__score">{score.summary()}</span>
+                        <span class="history__block">{format!("#{}", score.block_number)}</span>
+                    </li>
+                }) }
+            </ul>
+        }
+    }
 
     fn score_view(&self, link: &Scope<Self>) -> Html {
         let visible_class = if self.is_game_on() {
@@ -706,8 +639,26 @@ impl App {
                 { self.block_countdown_view(link)}
                 </div>
                 <div>
-                    <span>{"POINTS: "} <b>{format!("{}", self.points)}</b></span>
-                    <span>{"DURATION: "} <b>{format!("{}", self.duration)}</b></span>
+                    {
+                        match self.engine.game_mode() {
+                            GameMode::Zen => html! {
+                                <span>{"MATCHES: "} <b>{format!("{}", self.engine.matched())}</b></span>
+                            },
+                            _ => html! {
+                                <span>{"POINTS: "} <b>{format!("{}", self.engine.points())}</b></span>
+                            },
+                        }
+                    }
+                    {
+                        match self.engine.game_mode().duration_limit() {
+                            Some(limit) => html! {
+                                <span>{"BLOCKS LEFT: "} <b>{format!("{}", limit.saturating_sub(self.engine.duration()))}</b></span>
+                            },
+                            None => html! {
+                                <span>{"DURATION: "} <b>{format!("{}", self.engine.duration())}</b></span>
+                            },
+                        }
+                    }
                 </div>
                 // <span>{"Attempts: "} <b>{format!("{}", self.tries)}</b></span>
                 // <span>{"Helps: "} <b>{format!("{}", self.helps)}</b></span>
@@ -721,7 +672,7 @@ impl App {
                 <span class={classes!("keyboard__info", "visible")}>
                     <span>{"← ↑ → ↓ =MOVE"}</span>
                     {
-                        if self.get_match_index().is_none() {
+                        if self.engine.match_index().is_none() {
                             html! { <span>{"SPACE/ENTER=SELECT"}</span> }
                         } else {
                             html! { <span>{"SPACE/ENTER=MATCH"}</span> }
@@ -729,6 +680,13 @@ impl App {
                     }
                     <span>{"H=HIGHLIGHT"}</span>
                     <span>{"F=FLIP"}</span>
+                    {
+                        if self.engine.game_mode().is_endless() {
+                            html! { <span>{"S=STOP"}</span> }
+                        } else {
+                            html! {}
+                        }
+                    }
                 </span>
             }
         } else {
@@ -742,19 +700,9 @@ impl App {
 
     fn block_countdown_view(&self, _link: &Scope<Self>) -> Html {
         // reset countdown every time a new block is added to the board
-        // let block_number = if let Some(opt) = self.blocks.get(0) {
-        //     if let Some(block) = opt {
-        //         Some(block.block_number)
-        //     } else {
-        //         None
-        //     }
-        // } else {
-        //     None
-        // };
-
-        let block_number = self.get_last_finalized_block_number();
+        let block_number = self.engine.last_finalized_block_number();
 
-        html! { <BlockTimer block_number={block_number.clone()} visible={self.is_game_on()} /> }
+        html! { <BlockTimer block_number={block_number} visible={self.is_game_on()} /> }
     }
 
     fn _match_caption_view(&self, _link: &Scope<Self>) -> Html {
@@ -763,17 +711,18 @@ impl App {
         } else {
             Some("hidden")
         };
-        html! { <span class={classes!("help__info", visible_class)}>{format!("{} highlights left!", self.helps)} </span> }
+        html! { <span class={classes!("help__info", visible_class)}>{format!("{} highlights left!", self.engine.helps())} </span> }
     }
 
     fn attempts_column_view(&self, _link: &Scope<Self>) -> Html {
-        let visible_class = if self.is_game_on() {
+        // NOTE: only classic games are over once all tries are lost
+        let visible_class = if self.is_game_on() && self.engine.game_mode().has_tries() {
             Some("visible")
         } else {
             Some("hidden")
         };
         let box_class: Option<AttrValue> = None;
-        let value = self.tries.clone();
+        let value = self.engine.tries();
         html! { <ColumnInfoView max={DEFAULT_INITIAL_TRIES} {value} title="attempts left!"
         class={visible_class} position_class={Some("left")} {box_class} /> }
     }
@@ -790,7 +739,7 @@ impl App {
             None
         };
 
-        let value = self.helps.clone();
+        let value = self.engine.helps();
         html! { <ColumnInfoView max={DEFAULT_INITIAL_HELPS} {value} title="helps left!"
         class={visible_class} position_class={Some("right")} {box_class} /> }
     }
@@ -801,8 +750,13 @@ impl App {
         } else {
             None
         };
+        let is_reconnecting_class = if self.network_state.is_reconnecting() {
+            Some("reconnecting")
+        } else {
+            None
+        };
 
-        match &self.game_status {
+        match self.engine.game_status() {
             GameStatus::MoveTo(game_level) => html! {
                 <>
                     <div class={classes!("gameboard", "move__to")}>
@@ -817,6 +771,24 @@ impl App {
                     </div>
                 </>
             },
+            _ if self.network_state.is_syncing() => html! {
+                <>
+                    <div class={classes!("gameboard", "syncing")}>
+                        <h5>{"SYNCING LIGHT CLIENT"}</h5>
+                        {
+                            if let NetworkStatus::Syncing(sync_state) = &self.network_state.status {
+                                html! {
+                                    <p class="sync__progress">
+                                        {format!("#{} · {} peers", sync_state.best_block_number, sync_state.peers)}
+                                    </p>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                    </div>
+                </>
+            },
             _ => html! {
                 <>
                     { self.score_view(link) }
@@ -824,16 +796,17 @@ impl App {
                     // { self.base_points_view(link) }
                     { self.attempts_column_view(link) }
                     { self.helps_column_view(link) }
-                    <div class={classes!("gameboard", is_game_on_class, self.game_level.class(), self.match_class())}>
-                        { for self.blocks.iter().enumerate().map(|(i, block_option)| {
+                    <div class={classes!("gameboard", is_game_on_class, is_reconnecting_class, self.engine.board_size().class(), self.engine.game_level().class(), self.engine.match_class())}>
+                        { for self.engine.blocks().iter().enumerate().map(|(i, block_option)| {
                                 if let Some(block) = block_option {
-                                    let block_clicked = link.callback(move |_| Msg::BlockClicked(i.clone()));
-                                    let block_dblclicked = link.callback(move |_| Msg::BlockPressed(i.clone()));
-                                    let block_touchstart = link.callback(move |_| Msg::BlockClicked(i.clone()));
-                                    let block_touchend = link.callback(move |_| Msg::BlockPressed(i.clone()));
-                                    let block_animation_ended = link.callback(move |bn| Msg::BlockAnimationEnded(bn));
+                                    let block_clicked = link.callback(move |_| Msg::BlockClicked(i));
+                                    let block_dblclicked = link.callback(move |_| Msg::BlockPressed(i));
+                                    let block_touchstart = link.callback(move |_| Msg::BlockClicked(i));
+                                    let block_touchend = link.callback(move |_| Msg::BlockPressed(i));
+                                    let block_animation_ended = link.callback(Msg::BlockAnimationEnded);
                                     block.render(
-                                        self.game_level.core_view(Some(self.network_state.parachain_colors.clone())),
+                                        self.engine.game_level().block_view(),
+                                        self.engine.game_level().core_view(Some(self.network_state.parachain_colors.clone())),
                                         block_clicked.clone(),
                                         block_dblclicked.clone(),
                                         block_touchstart.clone(),
@@ -866,51 +839,35 @@ impl App {
                                 <img class="corematch__logo" src="/images/corematch_logo_kusama.svg" alt="corematch + kusama logo" />
                             }
                         }
-                    }
-                }
-            </div>
-        }
-    }
-
-    // fn head_right_view(&self, link: &Scope<Self>) -> Html {
-    //     html! { self.game_stats_view(link) }
-    // }
-
-    fn left_top_view(&self, _link: &Scope<Self>) -> Html {
-        html! {
-            <div class="top">
-                {
-                    match self.network_state.runtime {
-                        SupportedRelayRuntime::Polkadot => {
-                            html! {
-                                <a class="logo__polkadot" href="https://polkadot.network" target="_blank">
-                                    <img class="logo__img" src="/images/polkadot_logo_vertical.svg" alt="polkadot logo" />
-                                </a>
-                            }
-                        }
-                        SupportedRelayRuntime::Kusama => {
+                        SupportedRelayRuntime::Westend | SupportedRelayRuntime::Paseo => {
                             html! {
-                                <a class="logo__kusama" href="https://kusama.network" target="_blank">
-                                    <img class="logo__img" src="/images/kusama_logo_vertical.svg" alt="kusama logo" />
-                                </a>
+                                <img class="corematch__logo" src="/images/corematch_logo.svg" alt="corematch logo" />
                             }
                         }
                     }
                 }
+                {
+                    if self.network_state.is_practicing() {
+                        html! { <p class="subtitle">{"practice mode"}</p> }
+                    } else if self.network_state.is_replaying() {
+                        html! { <p class="subtitle">{"replay mode"}</p> }
+                    } else {
+                        html! {}
+                    }
+                }
             </div>
         }
     }
 
-    fn left_bottom_view(&self, link: &Scope<Self>) -> Html {
-        html! {
-            <div class="bottom">
-                { self.subscription_icon_view(link) }
-            </div>
-        }
-    }
+    // fn head_right_view(&self, link: &Scope<Self>) -> Html {
+    //     html! { self._game_stats_view(link) }
+    // }
 
-    fn subscription_icon_view(&self, _link: &Scope<Self>) -> Html {
+    fn subscription_icon_view(&self, link: &Scope<Self>) -> Html {
         let network_state = self.network_state.clone();
+        // NOTE: settings switched keep the other query params, e.g. the game mode or board size,
+        // while switching network resets the ones of the chain left, i.e. practice, replay and rpc
+        let query = location_query(link);
 
         let visible_class = if self.network_state.is_active() {
             Some("visible")
@@ -920,18 +877,64 @@ impl App {
 
         html! {
             <SubscriptionProvider>
-                { match network_state.runtime {
-                    SupportedRelayRuntime::Polkadot => html! {
-                        <NetworkButton switch_to_chain={SupportedRelayRuntime::Kusama} class={visible_class} >
-                            <img class="icon__img" src="/images/kusama_icon.svg" alt="kusama logo" />
-                        </NetworkButton>
-                    },
-                    SupportedRelayRuntime::Kusama => html! {
-                        <NetworkButton switch_to_chain={SupportedRelayRuntime::Polkadot} class={visible_class} >
-                            <img class="icon__img" src="/images/polkadot_icon_white.svg" alt="polkadot logo" />
-                        </NetworkButton>
+                {
+                    // NOTE: while practicing, the runtime in use can also be played live
+                    for SupportedRelayRuntime::iter()
+                        .filter(|runtime| network_state.is_practicing() || *runtime != network_state.runtime)
+                        .map(|runtime| html! {
+                            <QueryButton query={Query { chain: runtime, practice: None, replay: None, rpc: None, ..query.clone() }} class={visible_class} >
+                                { network_icon_view(runtime) }
+                            </QueryButton>
+                        })
+                }
+                {
+                    if network_state.is_practicing() || network_state.is_replaying() {
+                        html! {}
+                    } else {
+                        html! {
+                            <QueryButton query={Query { practice: Some(true), ..query.clone() }} class={visible_class} >
+                                <img class="icon__img" src="/images/practice_icon.svg" alt="practice icon" title="Practice offline" />
+                            </QueryButton>
+                        }
+                    }
+                }
+                {
+                    match &network_state.recording {
+                        Some(recording) => html! {
+                            <RecordingButton chain={network_state.runtime} recording={recording.clone()} class={visible_class} >
+                                <img class="icon__img" src="/images/download_icon.svg" alt="download icon" title="Download recorded blocks" />
+                            </RecordingButton>
+                        },
+                        None => html! {},
                     }
-                }}
+                }
+                {
+                    if self.is_game_on() {
+                        html! {}
+                    } else {
+                        let board_size = network_state.board_size.next();
+                        html! {
+                            <QueryButton query={Query { board: Some(board_size), ..query.clone() }} class={visible_class} >
+                                <img class="icon__img" src={format!("/images/board_{board_size}_icon.svg")} alt="board size icon" title={format!("Play on a {board_size} board")} />
+                            </QueryButton>
+                        }
+                    }
+                }
+                {
+                    match network_state.connection_mode {
+                        _ if network_state.is_replaying() || network_state.is_practicing() => html! {},
+                        ConnectionMode::Rpc => html! {
+                            <QueryButton query={Query { mode: Some(ConnectionMode::LightClient), ..query.clone() }} class={visible_class} >
+                                <img class="icon__img" src="/images/light_client_icon.svg" alt="light client icon" title="Connect via Light Client" />
+                            </QueryButton>
+                        },
+                        ConnectionMode::LightClient => html! {
+                            <QueryButton query={Query { mode: Some(ConnectionMode::Rpc), ..query.clone() }} class={visible_class} >
+                                <img class="icon__img" src="/images/rpc_icon.svg" alt="rpc icon" title="Connect via RPC" />
+                            </QueryButton>
+                        },
+                    }
+                }
             </SubscriptionProvider>
         }
     }
@@ -940,21 +943,23 @@ impl App {
         html! {
             <div class="top">
                 { self.game_commands_view(link) }
-                // <AccountProvider />
+                <AccountProvider />
             </div>
         }
     }
 
     fn about_view(&self, _link: &Scope<Self>) -> Html {
+        let board_size = self.engine.board_size();
         html! {
             <div class={classes!("game__about")}>
                 <h6>{"What is Corematch?"}</h6>
                 <p>{"Is an unstoppable memory game where players must spot a matching pattern to earn points."}</p>
-                <p>{"The board game holds a maximum of nine square objects named —  "} <b><i>{"Cells"}</i></b>{" — organized in a 3x3 matrix."}</p>
+                <p>{format!("The board game holds the latest {} finalized blocks as square objects named —  ", board_size.total_blocks())} <b><i>{"Cells"}</i></b>{format!(" — organized in a {board_size} matrix.
+                    The board can be switched between 2x2, 3x3 and 4x4 while the game is not on, a bigger board has more blocks to keep in mind, so every match is worth more points.")}</p>
                 <h6>{"What are Cells?"}</h6>
                 <p>{"Cells in Corematch serves as a representation of the sharded execution system currently designed and active in Polkadot. It specifically highlights the "}
                     <a class="link" href="https://wiki.polkadot.network/docs/polkadot-direction#core-usage-in-polkadot-10" target="_blank">{"Core Usage"}</a>
-                    {" of either Polkadot or Kusama multi-core protocol."}</p>
+                    {" of either Polkadot or Kusama multi-core protocol (or Westend and Paseo testnets)."}</p>
                 <p>{"On Polkadot, at each finalized block, a pattern is crafted from all cores available on-chain (on Kusama the top 64 cores are used) and embedded within its own Cell. Each core is color-coded based on its usage.
                     In the current version, a single core can only exist in two states: empty or full."}</p>
                 <h6>{"What are the game rules?"}</h6>
@@ -968,20 +973,37 @@ impl App {
                     {". During gameplay, you can make use of eight helps by pressing the 'H' key or button "}
                     <span><img class="icon__img" src="/images/match_icon.svg" alt="show_matches" /></span>
                     {", which highlights up to eight matches to assist you in spotting them on time."}</p>
-                <p>{"There are currently two levels at play: Level 1 is a binary representation of the multi-core usage of the network.
+                <p>{"There are currently four levels at play: Level 0 is the warm-up every game starts with, which only shows how busy the cores of the network are, Cells match when their usage falls in the same quarter (e.g. 50% to 74%).
+                    Level 1 is a binary representation of the multi-core usage of the network.
                     Level 2 adds a colorful representation based on para IDs and their respective core assignment.
-                    Level 2 is available as soon as a minimum of 32 points are reached and you can switch bettwen levels by pressing the respective level buttons "}
+                    Level 3 shows the same para IDs sorted, ignoring which core they were assigned to: Cells match when the same parachains were scheduled, wherever they were.
+                    Level 0 moves on to Level 1 at 16 points, Level 2 is available as soon as a minimum of 32 points are reached, Level 3 at 128 points. Pick Level 1 before starting to skip the warm-up, and you can switch bettwen levels by pressing the respective level buttons "}
+                    <span><img class="icon__img" src="/images/level0_icon.svg" alt="level 0" /></span>{" "}
                     <span><img class="icon__img" src="/images/level1_icon.svg" alt="level 1" /></span>{" "}
-                    <span><img class="icon__img" src="/images/level2_icon.svg" alt="level 2" /></span>
+                    <span><img class="icon__img" src="/images/level2_icon.svg" alt="level 2" /></span>{" "}
+                    <span><img class="icon__img" src="/images/level3_icon.svg" alt="level 3" /></span>
                 </p>
+                <h6>{"Game Modes"}</h6>
+                <p>{"Press the mode button "}
+                    <span><img class="icon__img" src="/images/mode__classic_icon.svg" alt="game mode" /></span>
+                    {" while the game is not on to switch between modes. In Classic the game concludes after four incorrect selections.
+                    In Time Attack incorrect selections are free, but the game concludes after 50 finalized blocks, so collect as many points as you can.
+                    In Zen there are no attempts nor points, matches are always highlighted and the game only concludes when you press the stop button "}
+                    <span><img class="icon__img" src="/images/stop_icon.svg" alt="stop game" /></span>
+                    {" or the 'S' key. The Daily Challenge is a Time Attack played on the blocks finalized right after midnight (UTC), the same board for everyone,
+                    it starts by itself and can be played again as many times as you like, only your best score of the day counts for your streak. Only Classic results can be minted."}</p>
                 <h6>{"Game Over - What can I do?"}</h6>
                 <p>{"When the game is over, press the share button "}
                     <span><img class="icon__img" src="/images/share_icon.svg" alt="share results" /></span>
                     {" and share results with friends and family. Challenge them to join you in the Corematch game and embark them to explore about "}<a class="link" href="https://polkadot.network/" target="_blank">{"Polkadot's technology"}</a>{" and learn how to build on Polkadot."}</p>
-                // <h6>{"Mint Results - Coming Soon!"}</h6>
-                // <p>{"If you would like your score to show up in the leadearboard, press the mint button "}
-                //     <span><img class="icon__img" src="/images/mint_icon.svg" alt="mint results" /></span>
-                // {" and you will be prompt to connnect an Asset Hub account and sign the transaction to mint the results. This account will be entitled to a soulbound NFT and it will hold your results history."}</p>
+                <h6>{"Mint Results"}</h6>
+                <p>{"If you would like to keep your score on-chain, press the mint button "}
+                    <span><img class="icon__img" src="/images/mint_icon.svg" alt="mint results" /></span>
+                {" and you will be prompt to connnect an Asset Hub account and sign the transaction to mint the results. This account will be entitled to a soulbound NFT and it will hold your best score per network and level, only beaten scores are minted unless you choose to overwrite them. The results of every network are minted on Asset Hub Westend."}</p>
+                <h6>{"Leaderboard"}</h6>
+                <p>{"Press the leaderboard button "}
+                    <span><img class="icon__img" src="/images/leaderboard_icon.svg" alt="leaderboard" /></span>
+                {" to see the best scores minted by all players, ranked per network and level."}</p>
                 <h6>{"What comes next?"}</h6>
                 <p>{"Corematch patterns will evolve into beautiful, colorful, core compositions, alongside Polkadot evolution into an agile composable computer. Explore more about Polkadot direction "}<a class="link" href="https://wiki.polkadot.network/docs/polkadot-direction#agile-composable-computer" target="_blank">{"here"}</a>{"."}</p>
                 <p>{"If you've read this far, we hope you enjoy our work and may it serve as inspiration for fellow tinkerers and builders out there."}</p>
@@ -990,47 +1012,48 @@ impl App {
         }
     }
 
-    fn game_stats_view(&self, _link: &Scope<Self>) -> Html {
-        html! {
-            <table class="game__stats">
-                <tr>
-                    <th>{"Points"}</th>
-                    <th>{"Duration"}</th>
-                    <th>{"Attempts"}</th>
-                </tr>
-                <tr>
-                    <td class="points">{self.points}</td>
-                    <td class="duration">{self.duration}</td>
-                    <td class="attempts">{self.tries}</td>
-                </tr>
-            </table>
-        }
-    }
-
     fn game_commands_view(&self, link: &Scope<Self>) -> Html {
         let start_onclick = link.callback(move |_| Msg::StartButtonClicked);
+        let game_mode_onclick = link.callback(move |_| Msg::GameModeButtonClicked);
+        let game_mode = self.engine.game_mode();
         let help_onclick = link.callback(move |_| Msg::HelpButtonClicked);
-        let option_click = link.callback(move |e| Msg::LevelButtonClicked(e));
+        let option_click = link.callback(Msg::LevelButtonClicked);
         let about_click = link.callback(move |_| Msg::InfoButtonClicked);
+        let leaderboard_click = link.callback(move |_| Msg::LeaderboardButtonClicked);
 
         html! {
             <div class="game__commands">
-                <IconButton disable={self.is_game_on()} onclick={start_onclick}>
-                    <img class="icon__img" src="/images/start_icon.svg" alt="start_game" title="Start Playing!" />
+                <IconButton disable={self.is_game_on() && !game_mode.is_endless()} onclick={start_onclick}>
+                    {
+                        if self.is_game_on() {
+                            html! { <img class="icon__img" src="/images/stop_icon.svg" alt="stop_game" title="Stop Playing!" /> }
+                        } else {
+                            html! { <img class="icon__img" src="/images/start_icon.svg" alt="start_game" title="Start Playing!" /> }
+                        }
+                    }
+                </IconButton>
+                <IconButton disable={self.is_game_on()} onclick={game_mode_onclick}>
+                    <img class="icon__img" src={format!("/images/{}_icon.svg", game_mode.class())} alt={game_mode.class()} title={format!("{} mode, switch to {}", game_mode, game_mode.next())} />
                 </IconButton>
                 <IconButton
-                    disable={!self.is_game_on() || self.is_help_on() || self.helps == 0} onclick={help_onclick}>
+                    disable={!self.is_game_on() || self.is_help_on() || self.engine.helps() == 0} onclick={help_onclick}>
                     <img class="icon__img"  src="/images/match_icon.svg" alt="show_matches" title="Highlight matches!" />
                 </IconButton>
-                <LevelButton level={GameLevel::Level2} disable={!self.is_level_x_completed(GameLevel::Level1) || self.game_level == GameLevel::Level2} onclick={option_click.clone()}>
+                <LevelButton level={GameLevel::Level3} disable={!self.is_game_on() || !self.engine.is_level_x_completed(GameLevel::Level2) || *self.engine.game_level() == GameLevel::Level3} onclick={option_click.clone()}>
+                    <img class="icon__img"  src="/images/level3_icon.svg" alt="level 3" title="Play Level 3" />
+                </LevelButton>
+                <LevelButton level={GameLevel::Level2} disable={!self.is_game_on() || !self.engine.is_level_x_completed(GameLevel::Level1) || *self.engine.game_level() == GameLevel::Level2} onclick={option_click.clone()}>
                     <img class="icon__img"  src="/images/level2_icon.svg" alt="level 2" title="Play Level 2" />
                 </LevelButton>
-                <LevelButton level={GameLevel::Level1} disable={!self.is_game_on() || self.game_level == GameLevel::Level1} onclick={option_click.clone()}>
+                <LevelButton level={GameLevel::Level1} disable={*self.engine.game_level() == GameLevel::Level1} onclick={option_click.clone()}>
                     <img class="icon__img"  src="/images/level1_icon.svg" alt="level 1" title="Play Level 1" />
                 </LevelButton>
-                // <LevelButton level={GameLevel::Level0} disable={!self.is_game_on() || self.game_level == GameLevel::Level0} onclick={option_click.clone()}>
-                //     <img class="icon__img"  src="/images/level0_icon.svg" alt="block_view" title="Play Level 0" />
-                // </LevelButton>
+                <LevelButton level={GameLevel::Level0} disable={*self.engine.game_level() == GameLevel::Level0} onclick={option_click.clone()}>
+                    <img class="icon__img"  src="/images/level0_icon.svg" alt="level 0" title="Play Level 0" />
+                </LevelButton>
+                <IconButton disable={false} onclick={leaderboard_click}>
+                    <img class="icon__img"  src="/images/leaderboard_icon.svg" alt="leaderboard" title="Leaderboard" />
+                </IconButton>
                 <IconButton disable={false} onclick={about_click}>
                     <img class="icon__img"  src="/images/question_icon.svg" alt="game_info" title="About Corematch" />
                 </IconButton>
@@ -1041,18 +1064,6 @@ impl App {
         }
     }
 
-    fn game_message_view(&self, _link: &Scope<Self>) -> Html {
-        html! {
-            <th class="message">
-                { if self.is_game_on() { html! { <div class="game-on">{"It's ON!"}</div> } }
-                  else {
-                    html! { <div>{"Play Corematch!"}</div> }
-                  }
-                }
-            </th>
-        }
-    }
-
     fn footer_view(&self) -> Html {
         html! {
             <footer class="footer">
@@ -1072,257 +1083,177 @@ impl App {
         }
     }
 
-    fn full_reset(&mut self) {
-        self.reset();
-        self.blocks = vec![None; DEFAULT_TOTAL_BLOCKS.try_into().unwrap()];
-    }
-
-    fn reset(&mut self) {
-        self.reset_blocks();
-        self.game_status = GameStatus::Ready;
-        self.game_level = GameLevel::Level2;
-        self.duration = DEFAULT_INITIAL_DURATION;
-        self.points = DEFAULT_INITIAL_POINTS;
-        self.tries = DEFAULT_INITIAL_TRIES;
-        self.helps = DEFAULT_INITIAL_HELPS;
-        self.game_help_status = GameHelpStatus::Available;
-        self.cursor_position = (0, 0);
-    }
-
-    fn reset_blocks(&mut self) {
-        for opt in self.blocks.iter_mut() {
-            if let Some(block) = opt {
-                block.reset();
-            }
-        }
-    }
-
-    fn is_game_on(&self) -> bool {
-        match &self.game_status {
-            GameStatus::On => true,
-            GameStatus::MoveTo(_) => true,
-            _ => false,
-        }
-    }
-
-    fn is_game_over(&self) -> bool {
-        self.game_status == GameStatus::Over
-    }
-
-    fn is_help_on(&self) -> bool {
-        self.game_help_status.is_on()
-    }
-
-    fn is_next_level_available(&self, current_level: GameLevel) -> bool {
-        self.game_level == current_level
-            && self.previous_points < self.game_level.collected_points_per_level_minimum()
-            && self.points >= self.game_level.collected_points_per_level_minimum()
-    }
-
-    fn is_level_x_completed(&self, game_level: GameLevel) -> bool {
-        self.points >= game_level.collected_points_per_level_minimum()
-    }
-
-    fn start(&mut self) {
-        if !self.is_game_on() {
-            self.reset();
-            self.previous_board_status = Some(self.board_status.clone());
-            self.board_status = BoardStatus::Game;
-            self.game_status = GameStatus::On;
-            self.game_level = GameLevel::Level1;
-        }
-    }
-
-    fn reset_match_block(&mut self) {
-        self.match_counter = 0;
-        self.match_position = None;
-    }
-
-    fn get_match_block(&self) -> Option<Block> {
-        if let Some(index) = self.get_match_index() {
-            if let Some(opt) = self.blocks.get(index) {
-                if let Some(match_block) = opt {
-                    return Some(match_block.clone());
+    fn dispatch(&mut self, ctx: &Context<Self>, command: GameCommand) {
+        for event in self.engine.handle(command) {
+            match event {
+                GameEvent::Started => {
+                    self.previous_board_status = Some(self.board_status.clone());
+                    self.board_status = BoardStatus::Game;
                 }
-            }
-        }
-        None
-    }
-
-    fn get_match_index(&self) -> Option<usize> {
-        if let Some(position) = self.match_position {
-            return Some((position.1 * 4 + position.0).into());
-        }
-        None
-    }
-
-    fn set_match_position(&mut self, i: usize) {
-        self.match_position = Some((
-            (i % 4).try_into().expect("usize with incorrect value"),
-            (i / 4).try_into().expect("usize with incorrect value"),
-        ));
-    }
-
-    pub fn match_class(&self) -> String {
-        if let Some(index) = self.get_match_index() {
-            return format!("match__{}", index);
-        }
-        "".to_string()
-    }
-
-    fn unselect_block(&mut self, i: usize) {
-        if let Some(opt) = self.blocks.get_mut(i) {
-            if let Some(block) = opt {
-                block.unselected();
-            }
-        }
-    }
-
-    fn select_block(&mut self, i: usize) {
-        if self.is_game_on() {
-            if let Some(opt) = self.blocks.get_mut(i) {
-                if let Some(block) = opt {
-                    block.selected();
+                GameEvent::Matched(_) => info!("Congrats, you found a match!"),
+                GameEvent::Missed(_) => info!("Wrong match!"),
+                GameEvent::NextLevel(next_level) => {
+                    info!("Well Done! {} available for playing.", next_level);
+                    ctx.link().send_message(Msg::NextLevel(next_level));
                 }
-            }
-        }
-    }
-
-    fn get_last_finalized_block_number(&self) -> Option<u32> {
-        if self.is_game_on() {
-            if let Some(opt) = self.blocks.get(0) {
-                if let Some(block) = opt {
-                    return Some(block.block_number);
+                GameEvent::ModeChanged(game_mode) => {
+                    info!("{} mode selected.", game_mode);
+                    settings::remember_game_mode(game_mode);
+                    let query = location_query(ctx.link());
+                    self.switch_daily(settings::daily(game_mode, &query));
                 }
-            }
-        }
-        None
-    }
-
-    fn move_cursor(&mut self, new_position: Position) {
-        if self.is_game_on() && new_position != self.cursor_position {
-            // clear previous selection
-            let i = self.get_cursor_index();
-            self.unselect_block(i);
-            // set new position
-            self.cursor_position = new_position;
-            // highlight the new block
-            let i = self.get_cursor_index();
-            self.select_block(i);
-        }
-    }
-
-    fn incr_cursor_position(&mut self) {
-        if self.is_game_on() {
-            self.cursor_position = if self.cursor_position.0 < 2 {
-                (self.cursor_position.0 + 1, self.cursor_position.1)
-            } else {
-                if self.cursor_position.1 < 2 {
-                    (0, self.cursor_position.1 + 1)
-                } else {
-                    (0, 0)
+                GameEvent::Over => {
+                    info!("** Game Over **");
+                    if let Some(result) = self.daily_result() {
+                        self.daily_history =
+                            settings::remember_daily_result(self.network_state.runtime, result);
+                    }
+                    if self.engine.previous_match_block().is_some() {
+                        info!("\n{}", self.share_message().unwrap_or_default());
+                        // show available options
+                        self.board_status = BoardStatus::Options;
+                    }
                 }
-            };
+                _ => (),
+            }
         }
     }
 
-    fn get_cursor_index(&self) -> usize {
-        (self.cursor_position.1 * 3 + self.cursor_position.0).into()
-    }
-
-    fn set_cursor_position(&mut self, i: usize) {
-        self.cursor_position = (
-            (i % 3).try_into().expect("usize with incorrect value"),
-            (i / 3).try_into().expect("usize with incorrect value"),
-        );
+    fn is_game_on(&self) -> bool {
+        self.engine.is_game_on()
     }
 
-    fn match_succeed(&mut self) {
+    /// Starts a new game, or ends the one on if it only ends when stopped.
+    ///
+    /// The daily challenge is played again from its first block instead.
+    fn start_or_stop(&mut self, ctx: &Context<Self>) {
         if self.is_game_on() {
-            self.incr_points();
-            self.match_counter += 1;
+            self.dispatch(ctx, GameCommand::Stop);
+        } else if let Some(daily) = self.network_state.daily {
+            self.board_status = BoardStatus::Game;
+            self.switch_daily(Some(daily.replay(settings::today())));
+        } else {
+            self.dispatch(ctx, GameCommand::Start);
         }
     }
 
-    fn match_failed(&mut self) {
-        if self.is_game_on() {
-            self.decr_tries();
+    /// Switches between the daily challenge and the latest finalized blocks, the network is
+    /// reloaded if it changes.
+    fn switch_daily(&mut self, daily: Option<Daily>) {
+        if self.network_state.daily == daily {
+            return;
         }
+        let network_state = Rc::make_mut(&mut self.network_state);
+        network_state.status = NetworkStatus::Switching;
+        network_state.daily = daily;
+        self.engine.handle(GameCommand::Reload);
     }
 
-    fn incr_points(&mut self) {
-        if self.is_game_on() {
-            let base: u32 = 2;
-            self.previous_points = self.points;
-            self.points += DEFAULT_BASE_POINTS * base.pow(self.match_counter);
+    /// The result of the daily challenge played, if any.
+    fn daily_result(&self) -> Option<DailyResult> {
+        if self.engine.game_mode() != GameMode::DailyChallenge {
+            return None;
         }
+        self.network_state.daily.map(|daily| DailyResult {
+            day: daily.day,
+            points: self.engine.points(),
+            matched: self.engine.matched(),
+        })
     }
 
-    fn incr_duration(&mut self) {
-        if self.is_game_on() {
-            self.duration += 1;
-        }
+    fn is_help_on(&self) -> bool {
+        self.engine.is_help_on()
     }
 
-    fn decr_tries(&mut self) {
-        if self.is_game_on() && self.tries > 0 {
-            self.tries -= 1;
-            // terminate game when no tries left to be played
-            if self.tries == 0 {
-                self.game_status = GameStatus::Over;
-            }
+    fn share_message(&self) -> Option<AttrValue> {
+        if let Some(result) = self.daily_result() {
+            let streak = self.daily_history.streak(result.day);
+            return Some(
+                result
+                    .share_message(self.network_state.runtime, streak)
+                    .into(),
+            );
         }
+        self.engine.share_message().map(AttrValue::from)
     }
 
-    fn start_help(&mut self) {
-        if self.is_game_on() && self.game_help_status.is_available() {
-            self.game_help_status = GameHelpStatus::On;
-        }
+    fn is_mint_supported(&self) -> bool {
+        self.engine.game_mode() == GameMode::Classic
+            && !self.network_state.is_practicing()
+            && !self.network_state.is_replaying()
     }
 
-    fn show_details(&mut self) {
-        if self.is_game_on() {
-            let i = self.get_cursor_index();
-            if let Some(opt) = self.blocks.get_mut(i) {
-                if let Some(block) = opt {
-                    // Note: only flip if an animation is not undergoing
-                    if !block.is_anim_live() {
-                        block.flipped();
-                    }
+    /// Resolves the People chain identity of the selected account.
+    fn resolve_account_identity(&mut self, ctx: &Context<Self>) {
+        self.account_identity = None;
+        let Some(account_id) = self
+            .account_state
+            .account
+            .as_ref()
+            .and_then(|account| account.address.parse::<AccountId32>().ok())
+        else {
+            return;
+        };
+        let identity_service = self.identity_service.clone();
+        ctx.link().send_future(async move {
+            match identity_service
+                .resolve(std::slice::from_ref(&account_id))
+                .await
+            {
+                Ok(mut identities) => Msg::AccountIdentityResolved(identities.remove(&account_id)),
+                Err(err) => {
+                    error!("Identity could not be resolved: {}", err);
+                    Msg::AccountIdentityResolved(None)
                 }
             }
-        }
+        });
     }
 
-    fn decr_help_matches(&mut self, v: u32) {
-        if self.is_help_on() && self.helps > 0 {
-            for _n in 0..v {
-                self.helps -= 1;
-                if self.helps == 0 {
-                    self.game_help_status = GameHelpStatus::NotAvailable;
-                    break;
-                }
-            }
+    /// The name shown for the account, its verified identity if any or the extension name.
+    fn account_label(&self, account: &Account) -> String {
+        match &self.account_identity {
+            Some(identity) => identity.label(),
+            None => account.name.clone(),
         }
     }
 
-    fn share_message(&self) -> Option<AttrValue> {
-        let game_results = self.game_results().unwrap_or_default();
-        if let Some(block) = &self.previous_match_block {
-            let mut data = Vec::new();
-            data.push(format!("corematch.xyz {} 👀\n", game_results));
-            data.push(block.runtime.hashtag());
-            Some(data.join("\n").into())
+    /// Shows the given board, or goes back to the previous one if already shown.
+    fn toggle_board(&mut self, board_status: BoardStatus) {
+        if self.board_status == board_status {
+            if let Some(previous) = self.previous_board_status.clone() {
+                self.board_status = previous;
+            }
         } else {
-            None
+            self.previous_board_status = Some(self.board_status.clone());
+            self.board_status = board_status;
         }
     }
 
     fn game_results(&self) -> Option<AttrValue> {
-        if let Some(block) = &self.previous_match_block {
-            Some(format!("{}/{}/{}", self.points, self.duration, block.block_number).into())
-        } else {
-            None
-        }
+        self.engine.game_results().map(AttrValue::from)
+    }
+}
+
+/// The query params of the current location, the defaults if there are none.
+fn location_query(link: &Scope<App>) -> Query {
+    link.location()
+        .and_then(|location| location.query::<Query>().ok())
+        .unwrap_or_default()
+}
+
+fn network_icon_view(runtime: SupportedRelayRuntime) -> Html {
+    match runtime {
+        SupportedRelayRuntime::Polkadot => html! {
+            <img class="icon__img" src="/images/polkadot_icon_white.svg" alt="polkadot logo" title="Switch to Polkadot" />
+        },
+        SupportedRelayRuntime::Kusama => html! {
+            <img class="icon__img" src="/images/kusama_icon.svg" alt="kusama logo" title="Switch to Kusama" />
+        },
+        SupportedRelayRuntime::Westend => html! {
+            <img class="icon__img" src="/images/westend_icon.svg" alt="westend logo" title="Switch to Westend (testnet)" />
+        },
+        SupportedRelayRuntime::Paseo => html! {
+            <img class="icon__img" src="/images/paseo_icon.svg" alt="paseo logo" title="Switch to Paseo (testnet)" />
+        },
     }
 }
@@ -0,0 +1,341 @@
+use anyhow::anyhow;
+use corematch_common::errors::CorematchError;
+use corematch_common::runtimes::{
+    asset_hub::{AssetHubSource, MintCosts},
+    support::SupportedParachainRuntime,
+};
+use corematch_common::types::account::{
+    get_accounts, sign_extrinsic, submittable_extrinsic, Account, AccountState, AccountStatus,
+    SigningOptions, SigningParams, SigningStatus, TxInclusion,
+};
+use corematch_common::types::score::{personal_best, ScoreRecord};
+use futures::FutureExt;
+use log::{error, info};
+use std::rc::Rc;
+use subxt::{
+    blocks::ExtrinsicEvents,
+    error::DispatchError,
+    tx::{TxInBlock, TxStatus},
+    utils::{AccountId32, MultiSignature},
+    Metadata, OnlineClient, PolkadotConfig,
+};
+use yew::{html, platform::spawn_local, Callback, Component, Context, ContextHandle, Html};
+
+use corematch_asset_hub_westend::asset_hub_westend::AssetHubWestendSource;
+
+/// Returns the NFT source the game results are minted on.
+///
+/// NOTE: the results of every network are minted in the AssetHub Westend collection, the score
+/// record keeps the network they were played on.
+pub fn asset_hub_source() -> Rc<dyn AssetHubSource> {
+    Rc::new(AssetHubWestendSource)
+}
+
+// Fees do not depend on the signature, so a dummy one is used to estimate them.
+const DUMMY_SIGNATURE: MultiSignature = MultiSignature::Sr25519([0u8; 64]);
+
+pub enum Msg {
+    Error(anyhow::Error),
+    AccountsLoaded(Vec<Account>),
+    OnlineClientCreated((SupportedParachainRuntime, OnlineClient<PolkadotConfig>)),
+    ContextChanged(Rc<AccountState>),
+}
+
+/// AccountProvider loads the browser extension accounts and mints the game results of the
+/// selected account, reporting the extrinsic progress through the signing callback.
+pub struct AccountProvider {
+    state: Rc<AccountState>,
+    _listener: ContextHandle<Rc<AccountState>>,
+    online_client: Option<OnlineClient<PolkadotConfig>>,
+}
+
+impl Component for AccountProvider {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (state, _listener) = ctx
+            .link()
+            .context::<Rc<AccountState>>(ctx.link().callback(Msg::ContextChanged))
+            .expect("context to be set");
+
+        // NOTE: the AssetHub connection is only established once the first mint is requested
+        Self {
+            state,
+            _listener,
+            online_client: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Error(err) => {
+                error!("{}", err);
+                self.state
+                    .signing_callback
+                    .emit(SigningStatus::Failed(err.to_string().into()));
+            }
+            Msg::AccountsLoaded(accounts) => {
+                // send accounts back to be handled by the app
+                self.state.accounts_callback.emit(accounts);
+            }
+            Msg::OnlineClientCreated((runtime, online_client)) => {
+                // NOTE: discard clients of an AssetHub no longer minted on
+                if runtime == self.state.runtime {
+                    info!("{} connected", runtime);
+                    self.online_client = Some(online_client);
+                    if self.state.is_signing() {
+                        self.mint();
+                    }
+                }
+            }
+            Msg::ContextChanged(state) => {
+                let previous = std::mem::replace(&mut self.state, state);
+                if self.state.runtime != previous.runtime {
+                    self.online_client = None;
+                }
+                if self.state.status != previous.status {
+                    match &self.state.status {
+                        AccountStatus::Requesting(wallet) => {
+                            let wallet = *wallet;
+                            ctx.link().send_future(get_accounts(wallet).map(
+                                move |accounts_or_err| match accounts_or_err {
+                                    Ok(accounts) => Msg::AccountsLoaded(accounts),
+                                    Err(err) => Msg::Error(anyhow!(
+                                        "Accounts could not be loaded from {wallet}: {err}"
+                                    )),
+                                },
+                            ));
+                        }
+                        AccountStatus::Signing(_) => {
+                            if self.online_client.is_some() {
+                                self.mint();
+                            } else {
+                                self.connect(ctx);
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        html! {}
+    }
+}
+
+impl AccountProvider {
+    fn connect(&self, ctx: &Context<Self>) {
+        let runtime = self.state.runtime;
+        ctx.link().send_future(
+            OnlineClient::<PolkadotConfig>::from_url(runtime.default_rpc_url()).map(
+                move |result| match result {
+                    Ok(online_client) => Msg::OnlineClientCreated((runtime, online_client)),
+                    Err(err) => Msg::Error(anyhow!(
+                        "RPC connection could not be established with {runtime}:\n{err}"
+                    )),
+                },
+            ),
+        );
+    }
+
+    /// Spawns the signing and submission of the game results of the selected account.
+    fn mint(&self) {
+        let (AccountStatus::Signing(request), Some(account), Some(api)) =
+            (&self.state.status, &self.state.account, &self.online_client)
+        else {
+            return;
+        };
+        let source = asset_hub_source();
+
+        let request = request.clone();
+        let account = account.clone();
+        let api = api.clone();
+        let options = self.state.signing_options.clone();
+        let cb = self.state.signing_callback.clone();
+        let history_cb = self.state.history_callback.clone();
+        spawn_local(async move {
+            // NOTE: fees are only paid if the score beats the personal best, or if the
+            // player has chosen to overwrite it anyway
+            match fetch_history(&api, source.clone(), &account).await {
+                Ok(scores) => {
+                    let best = personal_best(&scores, &request.score).cloned();
+                    history_cb.emit(scores);
+                    if let Some(best) = best {
+                        if !request.overwrite && !request.score.beats(&best) {
+                            cb.emit(SigningStatus::NotImproved(best.summary().into()));
+                            return;
+                        }
+                    }
+                }
+                Err(err) => {
+                    error!("{}", err);
+                    cb.emit(SigningStatus::Failed(
+                        format!("Minted scores could not be loaded: {err}").into(),
+                    ));
+                    return;
+                }
+            }
+
+            match sign_and_submit(
+                &api,
+                source.clone(),
+                account.clone(),
+                request.score,
+                options,
+                cb.clone(),
+            )
+            .await
+            {
+                Ok(true) => {
+                    // NOTE: the history is only informative, the mint itself has succeeded
+                    match fetch_history(&api, source, &account).await {
+                        Ok(scores) => history_cb.emit(scores),
+                        Err(err) => error!("Minted scores could not be loaded: {}", err),
+                    }
+                }
+                Ok(false) => (),
+                Err(err) => {
+                    error!("{}", err);
+                    cb.emit(SigningStatus::Failed(err.to_string().into()));
+                }
+            }
+        });
+    }
+}
+
+/// Signs the mint call via the browser extension, submits it and reports its progress
+/// until the extrinsic is finalized, dropped or found invalid.
+///
+/// Returns true if the score has been stored on-chain.
+async fn sign_and_submit(
+    api: &OnlineClient<PolkadotConfig>,
+    source: Rc<dyn AssetHubSource>,
+    account: Account,
+    score: ScoreRecord,
+    options: SigningOptions,
+    cb: Callback<SigningStatus>,
+) -> Result<bool, anyhow::Error> {
+    let account_id = account_id(&account)?;
+
+    let call_data = source
+        .prepare_mint_call(api, account_id.clone(), score)
+        .await?;
+
+    let params = SigningParams::fetch(api, source.runtime(), &account_id, &options).await?;
+
+    // NOTE: params must match the ones signed by the extension
+    let partial_signed = params.partial_extrinsic(&call_data, api)?;
+
+    // Pre-flight check that the account can afford the mint before asking for a signature
+    let unsigned_extrinsic =
+        submittable_extrinsic(&partial_signed, api, account_id.clone(), &DUMMY_SIGNATURE);
+    let costs = MintCosts::fetch(api, &account_id, &unsigned_extrinsic).await?;
+    let relay_runtime = source.runtime().relay_runtime();
+    costs.check(relay_runtime)?;
+    cb.emit(SigningStatus::Estimated(
+        costs.summary(relay_runtime).into(),
+    ));
+
+    let multi_signature =
+        sign_extrinsic(&partial_signed, &call_data, api, &params, &account).await?;
+
+    // Apply the signature
+    let signed_extrinsic =
+        submittable_extrinsic(&partial_signed, api, account_id, &multi_signature);
+
+    cb.emit(SigningStatus::Signed);
+
+    let mut tx_progress = signed_extrinsic.submit_and_watch().await?;
+
+    while let Some(status) = tx_progress.next().await {
+        match status? {
+            TxStatus::Validated | TxStatus::Broadcasted { .. } | TxStatus::NoLongerInBestBlock => {
+                cb.emit(SigningStatus::Broadcast);
+            }
+            TxStatus::InBestBlock(in_block) => {
+                let (inclusion, _) = tx_inclusion(api, &in_block).await?;
+                cb.emit(SigningStatus::InBlock(inclusion));
+            }
+            TxStatus::InFinalizedBlock(in_block) => {
+                let (inclusion, events) = tx_inclusion(api, &in_block).await?;
+                return match dispatch_error(&events, api.metadata())? {
+                    Some(err) => {
+                        cb.emit(SigningStatus::DispatchFailed(
+                            inclusion,
+                            CorematchError::DispatchError(err).to_string().into(),
+                        ));
+                        Ok(false)
+                    }
+                    None => {
+                        cb.emit(SigningStatus::Finalized(inclusion));
+                        Ok(true)
+                    }
+                };
+            }
+            TxStatus::Dropped { message } => {
+                cb.emit(SigningStatus::Dropped(message.into()));
+                return Ok(false);
+            }
+            TxStatus::Invalid { message } => {
+                cb.emit(SigningStatus::Invalid(message.into()));
+                return Ok(false);
+            }
+            TxStatus::Error { message } => return Err(anyhow!(message)),
+        }
+    }
+    Err(anyhow!("Transaction progress ended before finalization"))
+}
+
+/// Fetch the scores minted by the account, sorted by network and level.
+async fn fetch_history(
+    api: &OnlineClient<PolkadotConfig>,
+    source: Rc<dyn AssetHubSource>,
+    account: &Account,
+) -> Result<Vec<ScoreRecord>, anyhow::Error> {
+    let mut scores = source.fetch_scores(api, account_id(account)?).await?;
+    scores.sort_by_key(|score| (score.network.to_string(), score.level));
+    Ok(scores)
+}
+
+fn account_id(account: &Account) -> Result<AccountId32, anyhow::Error> {
+    account
+        .address
+        .parse()
+        .map_err(|_| anyhow!("Invalid account address {}", account.address))
+}
+
+/// Locates the extrinsic within the block it has been included in.
+async fn tx_inclusion(
+    api: &OnlineClient<PolkadotConfig>,
+    in_block: &TxInBlock<PolkadotConfig, OnlineClient<PolkadotConfig>>,
+) -> Result<(TxInclusion, ExtrinsicEvents<PolkadotConfig>), subxt::Error> {
+    let events = in_block.fetch_events().await?;
+    let block = api.blocks().at(in_block.block_hash()).await?;
+    let inclusion = TxInclusion {
+        block_hash: format!("{:?}", in_block.block_hash()).into(),
+        block_number: block.number(),
+        extrinsic_index: events.extrinsic_index(),
+    };
+    Ok((inclusion, events))
+}
+
+/// Returns the dispatch error of a failed extrinsic.
+///
+/// NOTE: mint calls are batched with `batch_all`, so the extrinsic fails with the error of the
+/// call that failed.
+fn dispatch_error(
+    events: &ExtrinsicEvents<PolkadotConfig>,
+    metadata: Metadata,
+) -> Result<Option<DispatchError>, subxt::Error> {
+    for event in events.iter() {
+        let event = event?;
+        if (event.pallet_name(), event.variant_name()) == ("System", "ExtrinsicFailed") {
+            return DispatchError::decode_from(event.field_bytes(), metadata).map(Some);
+        }
+    }
+    Ok(None)
+}
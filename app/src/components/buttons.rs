@@ -1,25 +1,29 @@
 use crate::router::{Query, Routes};
-use corematch_common::runtimes::support::SupportedRelayRuntime;
-use yew::{classes, function_component, html, AttrValue, Callback, Children, Html, Properties};
+use corematch_common::runtimes::{replay::Recording, support::SupportedRelayRuntime};
+use std::{cell::RefCell, rc::Rc};
+use web_sys::HtmlElement;
+use yew::{
+    classes, function_component, html, use_node_ref, AttrValue, Callback, Children, Html,
+    Properties,
+};
 use yew_router::prelude::use_navigator;
 
 #[derive(Properties, PartialEq)]
-pub struct NetworkButtonProps {
-    pub switch_to_chain: SupportedRelayRuntime,
+pub struct QueryButtonProps {
+    /// Query params the index page is reloaded with.
+    pub query: Query,
     pub class: Option<AttrValue>,
     pub children: Children,
 }
 
-#[function_component(NetworkButton)]
-pub fn button(props: &NetworkButtonProps) -> Html {
+#[function_component(QueryButton)]
+pub fn query_button(props: &QueryButtonProps) -> Html {
     let optional_class = props.class.clone();
-    let chain = props.switch_to_chain.clone();
+    let query = props.query.clone();
     let navigator = use_navigator().unwrap();
 
     let onclick = Callback::from(move |_| {
-        navigator
-            .push_with_query(&Routes::Index, &Query { chain })
-            .unwrap();
+        navigator.push_with_query(&Routes::Index, &query).unwrap();
     });
 
     html! {
@@ -28,3 +32,40 @@ pub fn button(props: &NetworkButtonProps) -> Html {
         </button>
     }
 }
+
+#[derive(Properties, PartialEq)]
+pub struct RecordingButtonProps {
+    pub chain: SupportedRelayRuntime,
+    pub recording: Rc<RefCell<Recording>>,
+    pub class: Option<AttrValue>,
+    pub children: Children,
+}
+
+#[function_component(RecordingButton)]
+pub fn recording_button(props: &RecordingButtonProps) -> Html {
+    let optional_class = props.class.clone();
+    let recording = props.recording.clone();
+    let download = format!("corematch_{}.jsonl", props.chain.class());
+    let link_ref = use_node_ref();
+
+    let onclick = {
+        let link_ref = link_ref.clone();
+        Callback::from(move |_| {
+            // NOTE: the recording keeps growing, so the link is only built when clicked
+            if let Some(link) = link_ref.cast::<HtmlElement>() {
+                let json_lines = recording.borrow().to_json_lines();
+                let href = format!(
+                    "data:application/jsonl;charset=utf-8,{}",
+                    String::from(js_sys::encode_uri_component(&json_lines))
+                );
+                let _ = link.set_attribute("href", &href);
+            }
+        })
+    };
+
+    html! {
+        <a ref={link_ref} class={classes!("btn__icon", optional_class)} {download} {onclick} >
+            {props.children.clone()}
+        </a>
+    }
+}
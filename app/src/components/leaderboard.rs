@@ -0,0 +1,278 @@
+use crate::components::account_provider::asset_hub_source;
+use anyhow::anyhow;
+use corematch_common::components::buttons::{ActionButton, TextButton};
+use corematch_common::runtimes::identity::{Identity, IdentityService};
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+use corematch_common::types::account::Account;
+use corematch_common::types::game::GameLevel;
+use corematch_common::types::leaderboard::Leaderboard;
+use futures::FutureExt;
+use log::error;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use subxt::{utils::AccountId32, OnlineClient, PolkadotConfig};
+use yew::{classes, html, Component, Context, Html, Properties};
+
+const LEVELS: [GameLevel; 4] = [
+    GameLevel::Level0,
+    GameLevel::Level1,
+    GameLevel::Level2,
+    GameLevel::Level3,
+];
+
+pub enum Msg {
+    Error(anyhow::Error),
+    OnlineClientCreated(OnlineClient<PolkadotConfig>),
+    LeaderboardLoaded(Leaderboard),
+    IdentitiesResolved(BTreeMap<AccountId32, Identity>),
+    RefreshClicked,
+    LevelClicked(u8),
+    PageClicked(usize),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// The network the scores were played on.
+    pub runtime: SupportedRelayRuntime,
+    /// The account selected, highlighted in the ranking.
+    pub account: Option<Account>,
+    /// Resolves the players names from the People chain.
+    pub identity_service: Rc<IdentityService>,
+}
+
+/// LeaderboardBoard ranks the scores of the network minted in the Corematch collection, per
+/// level and page, reloading them on demand.
+pub struct LeaderboardBoard {
+    online_client: Option<OnlineClient<PolkadotConfig>>,
+    leaderboard: Option<Leaderboard>,
+    identities: BTreeMap<AccountId32, Identity>,
+    error: Option<String>,
+    level: u8,
+    page: usize,
+}
+
+impl Component for LeaderboardBoard {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let board = Self {
+            online_client: None,
+            leaderboard: None,
+            identities: BTreeMap::new(),
+            error: None,
+            level: GameLevel::Level1.number(),
+            page: 0,
+        };
+        board.connect(ctx);
+        board
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Error(err) => {
+                error!("{}", err);
+                self.error = Some(err.to_string());
+            }
+            Msg::OnlineClientCreated(online_client) => {
+                self.online_client = Some(online_client);
+                self.load(ctx);
+            }
+            Msg::LeaderboardLoaded(leaderboard) => {
+                self.leaderboard = Some(leaderboard);
+                self.error = None;
+                self.resolve_identities(ctx);
+            }
+            Msg::IdentitiesResolved(identities) => {
+                self.identities.extend(identities);
+            }
+            Msg::RefreshClicked => {
+                self.leaderboard = None;
+                self.error = None;
+                if self.online_client.is_some() {
+                    self.load(ctx);
+                } else {
+                    self.connect(ctx);
+                }
+            }
+            Msg::LevelClicked(level) => {
+                self.level = level;
+                self.page = 0;
+                self.resolve_identities(ctx);
+            }
+            Msg::PageClicked(page) => {
+                self.page = page;
+                self.resolve_identities(ctx);
+            }
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().runtime != old_props.runtime {
+            // NOTE: the scores of every network are minted in the same collection, the ones
+            // loaded are only ranked for the new network
+            self.identities.clear();
+            self.page = 0;
+            self.resolve_identities(ctx);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let runtime = ctx.props().runtime;
+        let refresh_onclick = ctx.link().callback(|_| Msg::RefreshClicked);
+
+        let ranking = match (&self.error, &self.leaderboard) {
+            (Some(err), _) => self.status_view("leaderboard unavailable", err),
+            (None, None) => self.status_view("loading...", &format!("Reading {runtime} scores")),
+            (None, Some(leaderboard)) => self.ranking_view(ctx, leaderboard),
+        };
+
+        html! {
+            <div class="leaderboard">
+                <h6>{format!("{runtime} Leaderboard")}</h6>
+                <div class="leaderboard__levels">
+                    { for LEVELS.iter().map(|level| {
+                        let number = level.number();
+                        let onclick = ctx.link().callback(move |_| Msg::LevelClicked(number));
+                        let selected_class = (number == self.level).then_some("selected");
+                        html! {
+                            <div class={classes!("leaderboard__level", selected_class)}>
+                                <TextButton label={level.to_string()} {onclick} />
+                            </div>
+                        }
+                    }) }
+                </div>
+                { ranking }
+                <div class="action">
+                    <ActionButton label={"refresh"} disable={self.leaderboard.is_none() && self.error.is_none()} onclick={refresh_onclick}>
+                        <img class="icon" src="/images/start_icon_white_clear.svg" alt="refresh_icon" />
+                    </ActionButton>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl LeaderboardBoard {
+    fn connect(&self, ctx: &Context<Self>) {
+        let runtime = asset_hub_source().runtime();
+        ctx.link().send_future(
+            OnlineClient::<PolkadotConfig>::from_url(runtime.default_rpc_url()).map(
+                move |result| match result {
+                    Ok(online_client) => Msg::OnlineClientCreated(online_client),
+                    Err(err) => Msg::Error(anyhow!(
+                        "RPC connection could not be established with {runtime}:\n{err}"
+                    )),
+                },
+            ),
+        );
+    }
+
+    fn load(&self, ctx: &Context<Self>) {
+        let Some(api) = self.online_client.clone() else {
+            return;
+        };
+        let source = asset_hub_source();
+        ctx.link().send_future(async move {
+            match source.fetch_leaderboard(&api).await {
+                Ok(leaderboard) => Msg::LeaderboardLoaded(leaderboard),
+                Err(err) => Msg::Error(anyhow!("Scores could not be loaded: {err}")),
+            }
+        });
+    }
+
+    /// Resolves the identities of the players shown in the current page.
+    fn resolve_identities(&self, ctx: &Context<Self>) {
+        let Some(leaderboard) = &self.leaderboard else {
+            return;
+        };
+        let accounts: Vec<AccountId32> = leaderboard
+            .page(ctx.props().runtime, self.level, self.page)
+            .into_iter()
+            .map(|(_, entry)| entry.account.clone())
+            .filter(|account| !self.identities.contains_key(account))
+            .collect();
+        if accounts.is_empty() {
+            return;
+        }
+        let identity_service = ctx.props().identity_service.clone();
+        ctx.link().send_future(async move {
+            match identity_service.resolve(&accounts).await {
+                Ok(identities) => Msg::IdentitiesResolved(identities),
+                Err(err) => {
+                    // NOTE: players are still shown by their address
+                    error!("Identities could not be resolved: {}", err);
+                    Msg::IdentitiesResolved(BTreeMap::new())
+                }
+            }
+        });
+    }
+
+    fn status_view(&self, title: &str, details: &str) -> Html {
+        html! {
+            <div class="status__msg">
+                <h4>{title.to_string()}</h4>
+                <p class="mint__details">{details.to_string()}</p>
+            </div>
+        }
+    }
+
+    fn ranking_view(&self, ctx: &Context<Self>, leaderboard: &Leaderboard) -> Html {
+        let runtime = ctx.props().runtime;
+        let entries = leaderboard.page(runtime, self.level, self.page);
+        if entries.is_empty() {
+            return self.status_view("no scores yet", "Be the first to mint one!");
+        }
+
+        let own_account: Option<AccountId32> = ctx
+            .props()
+            .account
+            .as_ref()
+            .and_then(|account| account.address.parse().ok());
+        let known_names: Vec<(AccountId32, String)> = self
+            .identities
+            .iter()
+            .map(|(account, identity)| (account.clone(), identity.label()))
+            .collect();
+        let pages = leaderboard.pages(runtime, self.level);
+
+        html! {
+            <>
+                <table class="leaderboard__ranking">
+                    { for entries.iter().map(|(rank, entry)| {
+                        let own_class = (own_account.as_ref() == Some(&entry.account)).then_some("own");
+                        html! {
+                            <tr class={classes!(own_class)}>
+                                <td class="rank">{format!("#{rank}")}</td>
+                                <td class="player">{entry.display_name(&known_names)}</td>
+                                <td class="points">{entry.score.points}</td>
+                                <td class="duration">{format!("{} blocks", entry.score.duration)}</td>
+                            </tr>
+                        }
+                    }) }
+                </table>
+                {
+                    if pages > 1 {
+                        html! {
+                            <div class="leaderboard__pages">
+                                { for (0..pages).map(|page| {
+                                    let onclick = ctx.link().callback(move |_| Msg::PageClicked(page));
+                                    let selected_class = (page == self.page).then_some("selected");
+                                    html! {
+                                        <div class={classes!("leaderboard__page", selected_class)}>
+                                            <TextButton label={(page + 1).to_string()} {onclick} />
+                                        </div>
+                                    }
+                                }) }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </>
+        }
+    }
+}
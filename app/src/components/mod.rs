@@ -1,2 +1,4 @@
+pub mod account_provider;
 pub mod buttons;
+pub mod leaderboard;
 pub mod subscription_provider;
@@ -1,26 +1,48 @@
 use anyhow::anyhow;
-use corematch_common::components::block::Block;
-use corematch_common::runtimes::support::SupportedRelayRuntime;
+use corematch_common::components::block::{Block, BlockNumber};
+use corematch_common::runtimes::{
+    daily::fetch_daily_challenge,
+    practice::{CorespaceGenerator, PracticeConfig},
+    replay::{Recording, RecordingSource, ReplaySource},
+    source::{connect_healthy_rpc, RelayChainSource, SubscriptionEvent},
+};
 use corematch_common::types::network::{
-    NetworkState, ParachainIds, SubscriptionId, CONTINUE_SIGNAL, STOP_SIGNAL,
+    ConnectionMode, NetworkState, NetworkStatus, ParachainIds, Replay, SubscriptionId, SyncState,
+    CONTINUE_SIGNAL, STOP_SIGNAL,
 };
+use futures::future::{select, Either};
 use futures::FutureExt;
-use log::{error, info};
-use std::rc::Rc;
-use subxt::{OnlineClient, PolkadotConfig};
+use gloo::net::http::Request;
+use log::{error, info, warn};
+use std::{rc::Rc, time::Duration};
+use subxt::{
+    backend::{legacy::LegacyRpcMethods, rpc::RpcClient},
+    lightclient::{LightClient, LightClientRpc},
+    OnlineClient, PolkadotConfig,
+};
 use yew::{
-    html, platform::pinned::mpsc::UnboundedSender, AttrValue, Callback, Children, Component,
-    Context, ContextHandle, Html, Properties,
+    html,
+    platform::{pinned::mpsc::UnboundedSender, time::sleep},
+    AttrValue, Callback, Children, Component, Context, ContextHandle, Html, Properties,
 };
 
-use corematch_kusama::kusama;
-use corematch_polkadot::polkadot;
+use corematch_relay_chains::relay_chain_source;
+
+const LIGHT_CLIENT_SYNC_TIMEOUT: Duration = Duration::from_secs(90);
+const LIGHT_CLIENT_SYNC_INTERVAL: Duration = Duration::from_secs(1);
+// Pause before trying the first endpoint again once all of them have failed.
+const RPC_RECONNECT_DELAY: Duration = Duration::from_secs(6);
 
 pub enum Msg {
     Error(anyhow::Error),
-    OnlineClientCreated(OnlineClient<PolkadotConfig>),
+    LightClientFailed((u32, anyhow::Error)),
+    LightClientSyncProgressed((u32, Option<SyncState>)),
+    RpcEndpointFailed((u32, anyhow::Error)),
+    ReplayLoaded((u32, Rc<ReplaySource>)),
+    OnlineClientCreated((u32, OnlineClient<PolkadotConfig>)),
     OnlineClientDataReceived((SubscriptionId, Block)),
     SubscriptionCreated((SubscriptionId, UnboundedSender<AttrValue>)),
+    SubscriptionEventReceived((SubscriptionId, SubscriptionEvent)),
     ParachainsCollected(ParachainIds),
     ContextChanged(Rc<NetworkState>),
 }
@@ -36,7 +58,15 @@ pub struct SubscriptionProvider {
     _listener: ContextHandle<Rc<NetworkState>>,
     online_client: Option<OnlineClient<PolkadotConfig>>,
     error: Option<AttrValue>,
+    subscription_id: Option<SubscriptionId>,
+    // Last block sent to the app, so that a new subscription carries on from it.
+    last_block_number: Option<BlockNumber>,
     subscription_channel: Option<UnboundedSender<AttrValue>>,
+    // Counter to discard messages from previous connections.
+    connection_id: u32,
+    // Index of the RPC endpoint currently in use.
+    rpc_index: usize,
+    light_client_rpc: Option<LightClientRpc>,
 }
 
 impl Component for SubscriptionProvider {
@@ -49,29 +79,21 @@ impl Component for SubscriptionProvider {
             .context::<Rc<NetworkState>>(ctx.link().callback(Msg::ContextChanged))
             .expect("context to be set");
 
-        // TODO: test light client support
-        // let (lightclient, polkadot_rpc) = LightClient::relay_chain(POLKADOT_SPEC).unwrap();
-        // ctx.link().send_future(OnlineClient::<PolkadotConfig>::from_rpc_client(polkadot_rpc).map(|result| {
-        //     match result {
-        //         Ok(online_client) => Msg::OnlineClientCreated(online_client),
-        //         Err(err) => Msg::Error(anyhow!("RPC connection could not be established, make sure RPC endpoint is valid:\n{err}")),
-        //     }
-        // }));
-
-        let runtime = SupportedRelayRuntime::from(state.runtime.clone());
-        ctx.link().send_future(OnlineClient::<PolkadotConfig>::from_url(runtime.default_rpc_url()).map(|result| {
-            match result {
-                Ok(online_client) => Msg::OnlineClientCreated(online_client),
-                Err(err) => Msg::Error(anyhow!("RPC connection could not be established, make sure RPC endpoint is valid:\n{err}")),
-            }
-        }));
+        let connection_id = 0;
+        let rpc_index = 0;
+        let light_client_rpc = Self::connect(ctx, &state, connection_id, rpc_index);
 
         Self {
             state,
             _listener,
             online_client: None,
             error: None,
+            subscription_id: None,
+            last_block_number: None,
             subscription_channel: None,
+            connection_id,
+            rpc_index,
+            light_client_rpc,
         }
     }
 
@@ -82,63 +104,133 @@ impl Component for SubscriptionProvider {
                 error!("{}", err);
                 true
             }
-            Msg::OnlineClientCreated(online_client) => {
+            Msg::LightClientFailed((connection_id, err)) => {
+                if connection_id != self.connection_id {
+                    return false;
+                }
+                error!("{}", err);
+                warn!("Light client is not available, falling back to RPC");
+                self.light_client_rpc = None;
+                self.state.status_callback.emit(NetworkStatus::Initializing);
+                let rpc_url = rpc_url(&self.state, self.rpc_index);
+                Self::connect_rpc(ctx, rpc_url, connection_id, None);
+                false
+            }
+            Msg::RpcEndpointFailed((connection_id, err)) => {
+                if connection_id != self.connection_id {
+                    return false;
+                }
+                error!("{}", err);
+                self.failover(ctx);
+                false
+            }
+            Msg::LightClientSyncProgressed((connection_id, sync_state)) => {
+                if connection_id != self.connection_id {
+                    return false;
+                }
+                if let Some(light_client_rpc) = &self.light_client_rpc {
+                    if let Some(sync_state) = sync_state {
+                        self.state
+                            .status_callback
+                            .emit(NetworkStatus::Syncing(sync_state));
+                    }
+                    Self::poll_sync_state(ctx, light_client_rpc.clone(), connection_id);
+                }
+                false
+            }
+            Msg::ReplayLoaded((connection_id, replay_source)) => {
+                if connection_id != self.connection_id {
+                    return false;
+                }
+                let recording = replay_source.recording();
+                info!("Replaying {} blocks", recording.len());
+                if let Some(runtime) = recording.runtime() {
+                    if runtime != self.state.runtime {
+                        warn!(
+                            "Replaying blocks recorded from {runtime} on {}",
+                            self.state.runtime
+                        );
+                    }
+                }
+
+                // send parachains to be processed by the app
+                self.state.parachains_callback.emit(recording.para_ids());
+
+                // Play recorded blocks
+                let cb: Callback<(SubscriptionId, Block)> =
+                    ctx.link().callback(Msg::OnlineClientDataReceived);
+                ctx.link()
+                    .send_message(Msg::SubscriptionCreated(replay_source.subscribe(cb)));
+                false
+            }
+            Msg::OnlineClientCreated((connection_id, online_client)) => {
+                if connection_id != self.connection_id {
+                    return false;
+                }
                 self.online_client = Some(online_client);
 
+                // Light client is synced
+                if self.light_client_rpc.take().is_some() {
+                    self.state.status_callback.emit(NetworkStatus::Initializing);
+                }
+
+                // Fetch the daily challenge, to be played as a replay
+                if let Some(daily) = self.state.daily {
+                    let api = self.online_client.as_ref().unwrap().clone();
+                    let source = relay_chain_source(self.state.runtime);
+                    let total_blocks = self.state.board_size.total_blocks();
+                    ctx.link().send_future(async move {
+                        info!("Loading daily challenge of {}", daily.day);
+                        match fetch_daily_challenge(source.as_ref(), &api, daily.day, total_blocks)
+                            .await
+                        {
+                            Ok(recording) => Msg::ReplayLoaded((
+                                connection_id,
+                                Rc::new(ReplaySource::new(recording, 1, total_blocks)),
+                            )),
+                            Err(err) => Msg::Error(anyhow!(
+                                "Daily challenge of {} could not be loaded:\n{err}",
+                                daily.day
+                            )),
+                        }
+                    });
+                    return true;
+                }
+
                 // Fetch parachains
                 let api = self.online_client.as_ref().unwrap().clone();
 
-                match self.state.runtime {
-                    SupportedRelayRuntime::Polkadot => {
-                        ctx.link()
-                            .send_future(polkadot::fetch_para_ids(api).map(|result| match result {
-                                Ok(para_ids) => Msg::ParachainsCollected(para_ids),
-                                Err(err) => Msg::Error(err.into()),
-                            }))
+                let source = relay_chain_source(self.state.runtime);
+                ctx.link().send_future(async move {
+                    match source.fetch_para_ids(&api).await {
+                        Ok(para_ids) => Msg::ParachainsCollected(para_ids),
+                        Err(err) => Msg::Error(err.into()),
                     }
-                    SupportedRelayRuntime::Kusama => ctx.link().send_future(
-                        kusama::fetch_para_ids(api).map(|result| match result {
-                            Ok(para_ids) => Msg::ParachainsCollected(para_ids),
-                            Err(err) => Msg::Error(err.into()),
-                        }),
-                    ),
-                    // _ => unimplemented!(),
-                }
+                });
 
                 // Subscribe blocks
                 let cb: Callback<(SubscriptionId, Block)> =
                     ctx.link().callback(Msg::OnlineClientDataReceived);
+                let on_event: Callback<(SubscriptionId, SubscriptionEvent)> =
+                    ctx.link().callback(Msg::SubscriptionEventReceived);
                 let api = self.online_client.as_ref().unwrap().clone();
 
-                match self.state.runtime {
-                    SupportedRelayRuntime::Polkadot => ctx.link().send_future(
-                        polkadot::subscribe_to_finalized_blocks(api, cb).map(
-                            |result| match result {
-                                Ok((subscription_id, subscription_channel)) => {
-                                    Msg::SubscriptionCreated((
-                                        subscription_id,
-                                        subscription_channel,
-                                    ))
-                                }
-                                Err(err) => Msg::Error(err.into()),
-                            },
-                        ),
-                    ),
-                    SupportedRelayRuntime::Kusama => {
-                        ctx.link()
-                            .send_future(kusama::subscribe_to_finalized_blocks(api, cb).map(
-                                |result| match result {
-                                    Ok((subscription_id, subscription_channel)) => {
-                                        Msg::SubscriptionCreated((
-                                            subscription_id,
-                                            subscription_channel,
-                                        ))
-                                    }
-                                    Err(err) => Msg::Error(err.into()),
-                                },
-                            ))
-                    } // _ => unimplemented!(),
-                };
+                ctx.link().send_future(
+                    self.subscription_source()
+                        .subscribe_finalized_corespace(
+                            api,
+                            self.last_block_number,
+                            self.state.board_size.total_blocks(),
+                            cb,
+                            on_event,
+                        )
+                        .map(|result| match result {
+                            Ok((subscription_id, subscription_channel)) => {
+                                Msg::SubscriptionCreated((subscription_id, subscription_channel))
+                            }
+                            Err(err) => Msg::Error(err.into()),
+                        }),
+                );
                 true
             }
             Msg::ParachainsCollected(para_ids) => {
@@ -147,24 +239,52 @@ impl Component for SubscriptionProvider {
                 true
             }
             Msg::SubscriptionCreated((subscription_id, subscription_channel)) => {
+                self.subscription_id = Some(subscription_id);
                 self.subscription_channel = Some(subscription_channel);
 
                 // send subscription_id to be updated by the app
                 self.state.subscription_callback.emit(subscription_id);
 
                 if let Some(subscription_channel) = &self.subscription_channel {
-                    subscription_channel
-                        .send_now(CONTINUE_SIGNAL.into())
-                        .expect("failed to send signal");
+                    // NOTE: the subscription task might have already ended
+                    let _ = subscription_channel.send_now(CONTINUE_SIGNAL.into());
                 }
 
                 true
             }
+            Msg::SubscriptionEventReceived((subscription_id, event)) => {
+                if self.subscription_id != Some(subscription_id) {
+                    return false;
+                }
+                match event {
+                    SubscriptionEvent::Reconnecting => {
+                        warn!("Reconnecting finalized blocks subscription");
+                        self.state.status_callback.emit(NetworkStatus::Reconnecting);
+                    }
+                    SubscriptionEvent::Reconnected => {
+                        info!("Finalized blocks subscription reconnected");
+                        self.state.status_callback.emit(NetworkStatus::Active);
+                    }
+                    SubscriptionEvent::Failed(err) => {
+                        error!("{}", err);
+                        self.subscription_id = None;
+                        self.subscription_channel = None;
+                        if self.last_block_number.is_some() {
+                            self.state.status_callback.emit(NetworkStatus::Reconnecting);
+                        }
+                        self.failover(ctx);
+                    }
+                }
+                false
+            }
             Msg::OnlineClientDataReceived((subscription_id, block)) => {
+                if self.subscription_id == Some(subscription_id) {
+                    self.last_block_number = Some(block.block_number);
+                }
+
                 if let Some(subscription_channel) = &self.subscription_channel {
-                    subscription_channel
-                        .send_now(CONTINUE_SIGNAL.into())
-                        .expect("failed to send signal");
+                    // NOTE: the subscription task might have already ended
+                    let _ = subscription_channel.send_now(CONTINUE_SIGNAL.into());
                 }
 
                 // send block to be processed by the app
@@ -174,22 +294,29 @@ impl Component for SubscriptionProvider {
             }
             Msg::ContextChanged(state) => {
                 info!("ContextChanged");
-                if state.runtime != self.state.runtime {
+                let has_changed = state.runtime != self.state.runtime
+                    || state.connection_mode != self.state.connection_mode
+                    || state.rpc_urls != self.state.rpc_urls
+                    || state.replay != self.state.replay
+                    || state.practice != self.state.practice
+                    || state.daily != self.state.daily
+                    || state.board_size != self.state.board_size
+                    || state.recording.is_some() != self.state.recording.is_some();
+                self.state = state;
+                if has_changed {
                     // Send a signal to the subscription task to drop subscription.
                     if let Some(subscription_channel) = &self.subscription_channel {
-                        subscription_channel
-                            .send_now(STOP_SIGNAL.into())
-                            .expect("failed to send signal");
+                        // NOTE: the subscription task might have already ended
+                        let _ = subscription_channel.send_now(STOP_SIGNAL.into());
                     }
                     // Create a new online client
-                    ctx.link().send_future(OnlineClient::<PolkadotConfig>::from_url(state.runtime.default_rpc_url()).map(|result| {
-                        match result {
-                            Ok(online_client) => Msg::OnlineClientCreated(online_client),
-                            Err(err) => Msg::Error(anyhow!("RPC connection could not be established, make sure RPC endpoint is valid:\n{err}")),
-                        }
-                    }));
+                    self.connection_id = self.connection_id.wrapping_add(1);
+                    self.rpc_index = 0;
+                    self.subscription_id = None;
+                    self.last_block_number = None;
+                    self.light_client_rpc =
+                        Self::connect(ctx, &self.state, self.connection_id, self.rpc_index);
                 }
-                self.state = state;
                 true
             }
         }
@@ -199,3 +326,198 @@ impl Component for SubscriptionProvider {
         html! {{ ctx.props().children.clone() }}
     }
 }
+
+impl SubscriptionProvider {
+    /// Creates a new online client for the runtime, returns the light client rpc if it is syncing.
+    fn connect(
+        ctx: &Context<Self>,
+        state: &NetworkState,
+        connection_id: u32,
+        rpc_index: usize,
+    ) -> Option<LightClientRpc> {
+        if let Some(replay) = &state.replay {
+            Self::load_replay(
+                ctx,
+                replay.clone(),
+                state.board_size.total_blocks(),
+                connection_id,
+            );
+            return None;
+        }
+
+        if let Some(config) = &state.practice {
+            info!("Practicing with synthetic {} blocks", state.runtime);
+            let config = PracticeConfig {
+                total_blocks: state.board_size.total_blocks(),
+                ..config.clone()
+            };
+            let generator = CorespaceGenerator::new(config, state.runtime);
+            // send parachains to be processed by the app
+            state.parachains_callback.emit(generator.para_ids());
+            let cb: Callback<(SubscriptionId, Block)> =
+                ctx.link().callback(Msg::OnlineClientDataReceived);
+            ctx.link()
+                .send_message(Msg::SubscriptionCreated(generator.subscribe(cb)));
+            return None;
+        }
+
+        let runtime = state.runtime;
+        // NOTE: the daily challenge is fetched at past blocks, which light clients do not serve
+        if state.connection_mode == ConnectionMode::Rpc || state.is_playing_daily() {
+            Self::connect_rpc(ctx, rpc_url(state, rpc_index), connection_id, None);
+            return None;
+        }
+
+        let Some(chain_specs) = runtime.chain_specs() else {
+            warn!("No chain specs bundled for {runtime}, connecting via RPC");
+            Self::connect_rpc(ctx, rpc_url(state, rpc_index), connection_id, None);
+            return None;
+        };
+
+        match LightClient::relay_chain(chain_specs) {
+            Ok((_light_client, light_client_rpc)) => {
+                info!("Syncing {runtime} light client");
+                state
+                    .status_callback
+                    .emit(NetworkStatus::Syncing(SyncState {
+                        peers: 0,
+                        best_block_number: 0,
+                    }));
+                Self::poll_sync_state(ctx, light_client_rpc.clone(), connection_id);
+
+                let rpc = light_client_rpc.clone();
+                ctx.link().send_future(async move {
+                    let online_client = OnlineClient::<PolkadotConfig>::from_rpc_client(rpc);
+                    let timeout = sleep(LIGHT_CLIENT_SYNC_TIMEOUT);
+                    match select(Box::pin(online_client), Box::pin(timeout)).await {
+                        Either::Left((Ok(online_client), _)) => {
+                            Msg::OnlineClientCreated((connection_id, online_client))
+                        }
+                        Either::Left((Err(err), _)) => Msg::LightClientFailed((
+                            connection_id,
+                            anyhow!("Light client could not be synced:\n{err}"),
+                        )),
+                        Either::Right(_) => Msg::LightClientFailed((
+                            connection_id,
+                            anyhow!(
+                                "Light client could not be synced within {} seconds",
+                                LIGHT_CLIENT_SYNC_TIMEOUT.as_secs()
+                            ),
+                        )),
+                    }
+                });
+                Some(light_client_rpc)
+            }
+            Err(err) => {
+                ctx.link().send_message(Msg::LightClientFailed((
+                    connection_id,
+                    anyhow!("Light client could not be started:\n{err}"),
+                )));
+                None
+            }
+        }
+    }
+
+    /// Creates a new online client from the RPC endpoint, once the node is verified to be healthy.
+    fn connect_rpc(
+        ctx: &Context<Self>,
+        rpc_url: String,
+        connection_id: u32,
+        delay: Option<Duration>,
+    ) {
+        ctx.link().send_future(async move {
+            if let Some(delay) = delay {
+                sleep(delay).await;
+            }
+            info!("Connecting to {rpc_url}");
+            match connect_healthy_rpc(&rpc_url).await {
+                Ok(online_client) => Msg::OnlineClientCreated((connection_id, online_client)),
+                Err(err) => Msg::RpcEndpointFailed((connection_id, anyhow!("RPC connection could not be established, make sure RPC endpoint {rpc_url} is valid:\n{err}"))),
+            }
+        });
+    }
+
+    /// Fetches the recording to be replayed instead of connecting to the runtime.
+    fn load_replay(ctx: &Context<Self>, replay: Replay, total_blocks: u32, connection_id: u32) {
+        ctx.link().send_future(async move {
+            info!("Loading recording from {}", replay.url);
+            match fetch_recording(&replay.url).await {
+                Ok(recording) => Msg::ReplayLoaded((
+                    connection_id,
+                    Rc::new(ReplaySource::new(recording, replay.speed, total_blocks)),
+                )),
+                Err(err) => Msg::Error(anyhow!(
+                    "Recording could not be loaded from {}:\n{err}",
+                    replay.url
+                )),
+            }
+        });
+    }
+
+    /// Returns the corespace source of the runtime, recording every block fetched if enabled.
+    fn subscription_source(&self) -> Rc<dyn RelayChainSource> {
+        let source = relay_chain_source(self.state.runtime);
+        match &self.state.recording {
+            Some(recording) => Rc::new(RecordingSource::new(source, recording.clone())),
+            None => source,
+        }
+    }
+
+    /// Drops the current connection and connects to the next RPC endpoint.
+    fn failover(&mut self, ctx: &Context<Self>) {
+        self.connection_id = self.connection_id.wrapping_add(1);
+        self.light_client_rpc = None;
+        self.rpc_index = (self.rpc_index + 1) % self.state.rpc_urls.len().max(1);
+        // NOTE: all endpoints have been tried, pause before starting over
+        let delay = (self.rpc_index == 0).then_some(RPC_RECONNECT_DELAY);
+        let rpc_url = rpc_url(&self.state, self.rpc_index);
+        warn!("Failing over to {rpc_url}");
+        Self::connect_rpc(ctx, rpc_url, self.connection_id, delay);
+    }
+
+    fn poll_sync_state(ctx: &Context<Self>, rpc: LightClientRpc, connection_id: u32) {
+        ctx.link().send_future(async move {
+            sleep(LIGHT_CLIENT_SYNC_INTERVAL).await;
+            match fetch_sync_state(rpc).await {
+                Ok(sync_state) => Msg::LightClientSyncProgressed((connection_id, Some(sync_state))),
+                Err(err) => {
+                    warn!("{}", err);
+                    Msg::LightClientSyncProgressed((connection_id, None))
+                }
+            }
+        });
+    }
+}
+
+/// Returns the RPC endpoint at the given index, or the runtime default if none is configured.
+fn rpc_url(state: &NetworkState, rpc_index: usize) -> String {
+    state
+        .rpc_urls
+        .get(rpc_index)
+        .cloned()
+        .unwrap_or_else(|| state.runtime.default_rpc_url().to_string())
+}
+
+/// Fetch and parse a JSON lines recording.
+async fn fetch_recording(url: &str) -> Result<Recording, anyhow::Error> {
+    let response = Request::get(url).send().await?;
+    if !response.ok() {
+        return Err(anyhow!("{} {}", response.status(), response.status_text()));
+    }
+    Ok(Recording::from_json_lines(&response.text().await?)?)
+}
+
+/// Fetch the number of peers and the best block known by the light client.
+async fn fetch_sync_state(rpc: LightClientRpc) -> Result<SyncState, subxt::Error> {
+    let rpc_methods = LegacyRpcMethods::<PolkadotConfig>::new(RpcClient::new(rpc));
+    let health = rpc_methods.system_health().await?;
+    let best_block_number = rpc_methods
+        .chain_get_header(None)
+        .await?
+        .map(|header| header.number)
+        .unwrap_or_default();
+    Ok(SyncState {
+        peers: health.peers,
+        best_block_number,
+    })
+}
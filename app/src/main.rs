@@ -2,6 +2,7 @@ mod app;
 mod components;
 mod pages;
 mod router;
+mod settings;
 use crate::router::Router;
 
 fn main() {
@@ -1,7 +1,9 @@
 use crate::app::App;
 use crate::pages::page_not_found::PageNotFound;
 use corematch_common::runtimes::support::SupportedRelayRuntime;
-use serde::{Deserialize, Serialize};
+use corematch_common::types::game::{BoardSize, GameMode};
+use corematch_common::types::network::ConnectionMode;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 use yew::{html, Component, Context, Html};
 use yew_router::{BrowserRouter, Routable, Switch};
 
@@ -14,9 +16,92 @@ pub enum Routes {
     NotFound,
 }
 
-#[derive(Serialize, Deserialize)]
+/// Query params of the index page, a param with a value that can't be parsed, e.g. `?board=`
+/// or `?speed=fast`, falls back to its default without discarding the others.
+#[derive(Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Query {
+    #[serde(deserialize_with = "or_default")]
     pub chain: SupportedRelayRuntime,
+    #[serde(
+        deserialize_with = "or_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mode: Option<ConnectionMode>,
+    #[serde(
+        deserialize_with = "or_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rpc: Option<String>,
+    /// Location of a JSON lines recording to be replayed instead of connecting to the chain.
+    #[serde(
+        deserialize_with = "or_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub replay: Option<String>,
+    /// Replay speed, e.g. `?speed=6` plays a block every second.
+    #[serde(
+        deserialize_with = "or_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub speed: Option<u32>,
+    /// Play synthetic blocks offline instead of connecting to the chain.
+    #[serde(
+        deserialize_with = "or_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub practice: Option<bool>,
+    /// Number of cores of each practice block, e.g. `?cores=16`.
+    #[serde(
+        deserialize_with = "or_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cores: Option<u32>,
+    /// Share of occupied cores of each practice block, fixed or drawn from a range, e.g.
+    /// `?occupancy=0.5` or `?occupancy=0.2-0.8`.
+    #[serde(
+        deserialize_with = "or_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub occupancy: Option<String>,
+    /// Probability of a practice block repeating the corespace of a block on the board, e.g.
+    /// `?repeat=0.3`.
+    #[serde(
+        deserialize_with = "or_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub repeat: Option<f64>,
+    /// Record the blocks received so that they can be downloaded and replayed.
+    #[serde(
+        deserialize_with = "or_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub record: Option<bool>,
+    /// Size of the board, e.g. `?board=4x4`.
+    #[serde(
+        deserialize_with = "or_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub board: Option<BoardSize>,
+    /// Game mode, e.g. `?game=time-attack` or `?game=zen`.
+    #[serde(
+        deserialize_with = "or_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub game: Option<GameMode>,
+}
+
+/// Parses a query param value, as a JSON literal for numbers and booleans or as a string
+/// otherwise, falling back to the default if neither matches.
+fn or_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + Default,
+{
+    let value = String::deserialize(deserializer)?;
+    Ok(serde_json::from_str(&value)
+        .or_else(|_| serde_json::from_value(serde_json::Value::String(value)))
+        .unwrap_or_default())
 }
 
 pub struct Router;
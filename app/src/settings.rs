@@ -0,0 +1,201 @@
+use crate::router::Query;
+use corematch_common::runtimes::{
+    practice::PracticeConfig, replay::Recording, support::SupportedRelayRuntime,
+};
+use corematch_common::types::daily::{DailyHistory, DailyResult, Day};
+use corematch_common::types::game::{BoardSize, GameMode};
+use corematch_common::types::network::{ConnectionMode, Daily, ParachainIds, Replay};
+use gloo::storage::{LocalStorage, Storage};
+use log::warn;
+use std::{cell::RefCell, rc::Rc};
+
+const CONNECTION_MODE_KEY: &str = "corematch:connection_mode";
+const RPC_URLS_KEY: &str = "corematch:rpc_urls";
+const PARA_IDS_KEY: &str = "corematch:para_ids";
+const BOARD_SIZE_KEY: &str = "corematch:board_size";
+const GAME_MODE_KEY: &str = "corematch:game_mode";
+const DAILY_HISTORY_KEY: &str = "corematch:daily_history";
+
+/// Returns the connection mode, the one given via query params takes precedence and is remembered
+/// for the next visits.
+pub fn connection_mode(from_query: Option<ConnectionMode>) -> ConnectionMode {
+    match from_query {
+        Some(mode) => {
+            // NOTE: not being able to persist the setting is not critical
+            let _ = LocalStorage::set(CONNECTION_MODE_KEY, mode);
+            mode
+        }
+        None => LocalStorage::get(CONNECTION_MODE_KEY).unwrap_or_default(),
+    }
+}
+
+/// Returns the board size, the one given via query params takes precedence and is remembered for
+/// the next visits.
+pub fn board_size(from_query: Option<BoardSize>) -> BoardSize {
+    match from_query {
+        Some(board_size) => {
+            // NOTE: not being able to persist the setting is not critical
+            let _ = LocalStorage::set(BOARD_SIZE_KEY, board_size);
+            board_size
+        }
+        None => LocalStorage::get(BOARD_SIZE_KEY).unwrap_or_default(),
+    }
+}
+
+/// Returns the game mode, the one given via query params takes precedence and is remembered for
+/// the next visits.
+pub fn game_mode(from_query: Option<GameMode>) -> GameMode {
+    match from_query {
+        Some(game_mode) => {
+            remember_game_mode(game_mode);
+            game_mode
+        }
+        None => LocalStorage::get(GAME_MODE_KEY).unwrap_or_default(),
+    }
+}
+
+/// Remembers the game mode selected on the board.
+pub fn remember_game_mode(game_mode: GameMode) {
+    // NOTE: not being able to persist the setting is not critical
+    let _ = LocalStorage::set(GAME_MODE_KEY, game_mode);
+}
+
+/// Returns today's daily challenge if it is to be played, e.g. `?game=daily-challenge`.
+///
+/// Recordings and synthetic blocks take precedence, the challenge is only played live.
+pub fn daily(game_mode: GameMode, query: &Query) -> Option<Daily> {
+    (game_mode == GameMode::DailyChallenge
+        && query.replay.is_none()
+        && !query.practice.unwrap_or_default())
+    .then(|| Daily::new(today()))
+}
+
+/// The current day, as per the browser clock.
+pub fn today() -> Day {
+    Day::from_unix_millis(js_sys::Date::now() as u64)
+}
+
+/// Returns the results of the daily challenges played on the runtime.
+pub fn daily_history(runtime: SupportedRelayRuntime) -> DailyHistory {
+    LocalStorage::get(daily_history_key(runtime)).unwrap_or_default()
+}
+
+/// Remembers the result of a daily challenge, returns the updated history.
+pub fn remember_daily_result(runtime: SupportedRelayRuntime, result: DailyResult) -> DailyHistory {
+    let mut history = daily_history(runtime);
+    history.record(result);
+    // NOTE: not being able to persist the history is not critical
+    let _ = LocalStorage::set(daily_history_key(runtime), &history);
+    history
+}
+
+fn daily_history_key(runtime: SupportedRelayRuntime) -> String {
+    format!("{DAILY_HISTORY_KEY}:{}", runtime.class())
+}
+
+/// Returns the ordered list of RPC endpoints for the runtime.
+///
+/// A comma separated list given via query params (e.g. `?rpc=wss://a,wss://b`) takes precedence and
+/// is remembered for the next visits, otherwise the runtime defaults are used.
+pub fn rpc_urls(runtime: SupportedRelayRuntime, from_query: Option<String>) -> Vec<String> {
+    let key = format!("{RPC_URLS_KEY}:{}", runtime.class());
+
+    if let Some(rpc_urls) = from_query.map(parse_rpc_urls) {
+        if rpc_urls.is_empty() {
+            // NOTE: an empty list resets the setting to the runtime defaults
+            LocalStorage::delete(&key);
+        } else {
+            let _ = LocalStorage::set(&key, &rpc_urls);
+            return rpc_urls;
+        }
+    }
+
+    LocalStorage::get::<Vec<String>>(&key)
+        .ok()
+        .filter(|rpc_urls| !rpc_urls.is_empty())
+        .unwrap_or_else(|| {
+            runtime
+                .default_rpc_urls()
+                .iter()
+                .map(|url| url.to_string())
+                .collect()
+        })
+}
+
+/// Returns the recording to be replayed, e.g. `?replay=/recordings/polkadot.jsonl&speed=6`.
+///
+/// Replays are only driven by query params and never remembered.
+pub fn replay(query: &Query) -> Option<Replay> {
+    query.replay.as_ref().map(|url| Replay {
+        url: url.clone(),
+        speed: query.speed.unwrap_or(1).max(1),
+    })
+}
+
+/// Returns the practice settings if synthetic blocks are to be played, e.g. `?practice=true`,
+/// tuned via `?cores=16&occupancy=0.2-0.8&repeat=0.3`.
+///
+/// Occupied cores are assigned to the parachains last collected from the runtime, if any.
+pub fn practice(runtime: SupportedRelayRuntime, query: &Query) -> Option<PracticeConfig> {
+    if !query.practice.unwrap_or_default() || query.replay.is_some() {
+        return None;
+    }
+    let mut config = PracticeConfig::default();
+    if let Ok(para_ids) = LocalStorage::get::<ParachainIds>(para_ids_key(runtime)) {
+        if !para_ids.is_empty() {
+            config.para_ids = para_ids;
+        }
+    }
+    if let Some(cores) = query.cores {
+        config.total_cores = cores;
+    }
+    if let Some(occupancy) = &query.occupancy {
+        match occupancy.parse() {
+            Ok(occupancy) => config.occupancy = occupancy,
+            Err(err) => warn!("Ignoring practice occupancy, {err}"),
+        }
+    }
+    if let Some(repeat) = query.repeat {
+        if (0.0..=1.0).contains(&repeat) {
+            config.repeat_probability = repeat;
+        } else {
+            warn!("Ignoring repeat probability {repeat}, it must be between 0 and 1");
+        }
+    }
+    Some(config)
+}
+
+/// Remembers the parachains collected from the runtime, so that they can be used offline.
+pub fn remember_para_ids(runtime: SupportedRelayRuntime, para_ids: &ParachainIds) {
+    // NOTE: not being able to persist the setting is not critical
+    let _ = LocalStorage::set(para_ids_key(runtime), para_ids);
+}
+
+fn para_ids_key(runtime: SupportedRelayRuntime) -> String {
+    format!("{PARA_IDS_KEY}:{}", runtime.class())
+}
+
+/// Returns an empty recording if the blocks received are to be recorded, e.g. `?record=true`.
+pub fn recording(query: &Query) -> Option<Rc<RefCell<Recording>>> {
+    // NOTE: there is nothing to be recorded while replaying
+    (query.record.unwrap_or_default()
+        && query.replay.is_none()
+        && !query.practice.unwrap_or_default())
+    .then(|| Rc::new(RefCell::new(Recording::new())))
+}
+
+fn parse_rpc_urls(value: String) -> Vec<String> {
+    value
+        .split(',')
+        .map(|url| url.trim())
+        .filter(|url| !url.is_empty())
+        .filter(|url| {
+            let is_valid = url.starts_with("wss://") || url.starts_with("ws://");
+            if !is_valid {
+                warn!("Ignoring RPC endpoint {url}, only ws:// or wss:// urls are supported");
+            }
+            is_valid
+        })
+        .map(|url| url.to_string())
+        .collect()
+}
@@ -1,34 +1,28 @@
-use crate::block::{Block, Corespace};
-use crate::core::Core;
-use crate::runtimes::{support::SupportedRelayRuntime, utils::get_nft_id_from_storage_key};
-use anyhow::anyhow;
-use futures::StreamExt;
-use log::{error, info};
-use node_runtime::{
-    runtime_types::{
-        bounded_collections::bounded_vec::BoundedVec,
-        pallet_nfts::types::AttributeNamespace::Account,
+use corematch_common::errors::CorematchError;
+use corematch_common::runtimes::{
+    asset_hub::{nft_id_candidates, AssetHubSource, NftId},
+    source::SourceFuture,
+    support::SupportedParachainRuntime,
+    utils::{
+        decode_blake2_128_concat_key, get_last_key_from_storage_key, get_nft_id_from_storage_key,
     },
-    utility::calls::types::Batch,
 };
-use rand::Rng;
-use std::str::FromStr;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
-use subxt::{
-    tx::Payload,
-    utils::{AccountId32, MultiAddress, H256},
-    OnlineClient, PolkadotConfig,
+use corematch_common::types::{
+    leaderboard::{Leaderboard, LeaderboardEntry},
+    score::ScoreRecord,
 };
-use yew::{
-    platform::{pinned::mpsc::UnboundedSender, spawn_local, time::sleep},
-    AttrValue, Callback,
+use futures::FutureExt;
+use node_runtime::runtime_types::{
+    bounded_collections::{bounded_btree_set::BoundedBTreeSet, bounded_vec::BoundedVec},
+    pallet_nfts::types::AttributeNamespace::Account,
 };
+use subxt::{tx::Payload, utils::AccountId32, Metadata, OnlineClient, PolkadotConfig};
 
 type Call = node_runtime::runtime_types::asset_hub_westend_runtime::RuntimeCall;
 type NftsCall = node_runtime::runtime_types::pallet_nfts::pallet::Call;
 
-type NftId = u32;
-
+// NOTE: only the code generated from the metadata is not linted
+#[allow(clippy::all)]
 #[subxt::subxt(
     runtime_metadata_path = "artifacts/metadata/asset_hub_westend_metadata.scale",
     derive_for_all_types = "PartialEq, Clone"
@@ -37,105 +31,226 @@ pub mod node_runtime {}
 
 const COLLECTION_ID: u32 = 38;
 
-pub async fn prepare_payload(
-    api: OnlineClient<PolkadotConfig>,
-    account: AccountId32,
-    results: AttrValue,
-) -> Result<Payload<Batch>, subxt::Error> {
-    //
-    let (nft_id, mint) = fetch_or_generate_nft_id(&api, account.clone()).await?;
+/// AssetHubWestendSource encodes the Corematch collection calls for AssetHub Westend.
+pub struct AssetHubWestendSource;
 
-    // create a batch call to mint and store results as an item attribute
-    let mut calls: Vec<Call> = vec![];
+impl AssetHubSource for AssetHubWestendSource {
+    fn runtime(&self) -> SupportedParachainRuntime {
+        SupportedParachainRuntime::AssetHubWestend
+    }
 
-    if mint {
-        let call = Call::Nfts(NftsCall::mint {
-            collection: COLLECTION_ID,
-            item: nft_id,
-            mint_to: account.clone().into(),
-            witness_data: None,
-        });
-        calls.push(call);
+    fn collection_id(&self) -> u32 {
+        COLLECTION_ID
     }
 
-    if let Some((value, key)) = results.rsplit_once('/') {
-        let call = Call::Nfts(NftsCall::set_attribute {
-            collection: COLLECTION_ID,
-            maybe_item: Some(nft_id),
-            namespace: Account(account.clone()),
-            key: BoundedVec(key.as_bytes().to_vec()),
-            value: BoundedVec(value.as_bytes().to_vec()),
-        });
-        calls.push(call);
+    fn prepare_mint_call<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        account: AccountId32,
+        score: ScoreRecord,
+    ) -> SourceFuture<'a, Vec<u8>> {
+        async move {
+            let (nft_id, mint) = fetch_or_generate_nft_id(api, account.clone()).await?;
+            // NOTE: a new item has no approvals yet
+            let approved = !mint && is_approved(api, nft_id, account.clone()).await?;
+            encode_mint_call(&api.metadata(), account, nft_id, mint, approved, &score)
+        }
+        .boxed_local()
     }
 
-    if calls.len() > 0 {
-        Ok(node_runtime::tx().utility().batch(calls))
-    } else {
-        Err(subxt::Error::Other("No calls to encode".to_string()))
+    fn fetch_scores<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        account: AccountId32,
+    ) -> SourceFuture<'a, Vec<ScoreRecord>> {
+        async move {
+            let storage = api.storage().at_latest().await?;
+
+            let Some(nft_id) = fetch_nft_id(&storage, account.clone()).await? else {
+                return Ok(vec![]);
+            };
+
+            // iterate over the attributes set by the account in its item
+            let attributes_addr = node_runtime::storage().nfts().attribute_iter3(
+                COLLECTION_ID,
+                Some(nft_id),
+                Account(account),
+            );
+            let prefix_length = api.storage().address_bytes(&attributes_addr)?.len();
+            let mut iter = storage.iter(attributes_addr).await?;
+
+            let mut scores = vec![];
+            while let Some(attribute) = iter.next().await {
+                let attribute = attribute?;
+                let Some(key) =
+                    get_last_key_from_storage_key::<Vec<u8>>(&attribute.key_bytes, prefix_length)
+                else {
+                    continue;
+                };
+                let (BoundedVec(value), _) = attribute.value;
+                if let Some(score) = ScoreRecord::decode(&key, &value) {
+                    scores.push(score);
+                }
+            }
+            Ok(scores)
+        }
+        .boxed_local()
+    }
+
+    fn fetch_leaderboard<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+    ) -> SourceFuture<'a, Leaderboard> {
+        async move {
+            // iterate over the attributes of all items in the collection
+            let attributes_addr = node_runtime::storage()
+                .nfts()
+                .attribute_iter1(COLLECTION_ID);
+            let prefix_length = api.storage().address_bytes(&attributes_addr)?.len();
+            let mut iter = api
+                .storage()
+                .at_latest()
+                .await?
+                .iter(attributes_addr)
+                .await?;
+
+            let mut entries = vec![];
+            while let Some(attribute) = iter.next().await {
+                let attribute = attribute?;
+                let Some(mut encoded) = attribute.key_bytes.get(prefix_length..) else {
+                    continue;
+                };
+                // skip collection attributes, scores are set on items
+                let Some(Some(_)) = decode_blake2_128_concat_key::<Option<NftId>>(&mut encoded)
+                else {
+                    continue;
+                };
+                // NOTE: scores are set by the item owner, under its account namespace
+                let Some(Account(account)) = decode_blake2_128_concat_key(&mut encoded) else {
+                    continue;
+                };
+                let Some(key) = decode_blake2_128_concat_key::<Vec<u8>>(&mut encoded) else {
+                    continue;
+                };
+                let (BoundedVec(value), _) = attribute.value;
+                if let Some(score) = ScoreRecord::decode(&key, &value) {
+                    entries.push(LeaderboardEntry { account, score });
+                }
+            }
+            Ok(Leaderboard::new(entries))
+        }
+        .boxed_local()
     }
 }
 
-pub async fn prepare_call(
-    api: OnlineClient<PolkadotConfig>,
+/// Encodes the `batch_all` call that mints the account item, if `mint`, approves the account to
+/// set attributes in it, unless already `approved`, and stores the score record as an attribute.
+pub fn encode_mint_call(
+    metadata: &Metadata,
     account: AccountId32,
-    results: AttrValue,
-) -> Result<Vec<u8>, subxt::Error> {
-    //
-    let (nft_id, mint) = fetch_or_generate_nft_id(&api, account.clone()).await?;
-
+    nft_id: NftId,
+    mint: bool,
+    approved: bool,
+    score: &ScoreRecord,
+) -> Result<Vec<u8>, CorematchError> {
     // create a batch call to mint and store results as an item attribute
     let mut calls: Vec<Call> = vec![];
 
     if mint {
-        let call = Call::Nfts(NftsCall::mint {
+        calls.push(Call::Nfts(NftsCall::mint {
             collection: COLLECTION_ID,
             item: nft_id,
             mint_to: account.clone().into(),
             witness_data: None,
-        });
-        calls.push(call);
+        }));
     }
 
-    if let Some((value, key)) = results.rsplit_once('/') {
-        let call = Call::Nfts(NftsCall::set_attribute {
+    // NOTE: attributes are only set under the account namespace once the item owner,
+    // the account itself, has approved it
+    if !approved {
+        calls.push(Call::Nfts(NftsCall::approve_item_attributes {
             collection: COLLECTION_ID,
-            maybe_item: Some(nft_id),
-            namespace: Account(account.clone()),
-            key: BoundedVec(key.as_bytes().to_vec()),
-            value: BoundedVec(value.as_bytes().to_vec()),
-        });
-        calls.push(call);
+            item: nft_id,
+            delegate: account.clone().into(),
+        }));
     }
 
-    if calls.len() > 0 {
-        let call_payload = node_runtime::tx().utility().batch(calls);
-        api.tx().call_data(&call_payload)
-    } else {
-        Err(subxt::Error::Other("No calls to encode".to_string()))
+    calls.push(Call::Nfts(NftsCall::set_attribute {
+        collection: COLLECTION_ID,
+        maybe_item: Some(nft_id),
+        namespace: Account(account),
+        key: BoundedVec(score.attribute_key().into_bytes()),
+        value: BoundedVec(score.encode()?),
+    }));
+
+    // NOTE: the item is only minted if the score is stored, and the other way around
+    let call_payload = node_runtime::tx().utility().batch_all(calls);
+    Ok(call_payload.encode_call_data(metadata)?)
+}
+
+type Storage = subxt::storage::Storage<PolkadotConfig, OnlineClient<PolkadotConfig>>;
+
+/// Returns the NFT owned by the account in the collection, if already minted.
+async fn fetch_nft_id(
+    storage: &Storage,
+    account: AccountId32,
+) -> Result<Option<NftId>, CorematchError> {
+    let nfts_account_addr = node_runtime::storage()
+        .nfts()
+        .account_iter2(account, COLLECTION_ID);
+    let mut iter = storage.iter(nfts_account_addr).await?;
+
+    match iter.next().await {
+        Some(storage) => Ok(Some(get_nft_id_from_storage_key(storage?.key_bytes))),
+        None => Ok(None),
     }
 }
 
-pub async fn fetch_or_generate_nft_id(
+/// Returns true if the account may set attributes in the item under its own namespace.
+async fn is_approved(
     api: &OnlineClient<PolkadotConfig>,
+    nft_id: NftId,
     account: AccountId32,
-) -> Result<(NftId, bool), subxt::Error> {
-    // check in storage, if the account has already an NFT in the collection:
-    let nfts_account_addr = node_runtime::storage()
+) -> Result<bool, CorematchError> {
+    let approvals_addr = node_runtime::storage()
         .nfts()
-        .account_iter2(&account, COLLECTION_ID);
-    let mut iter = api
+        .item_attributes_approvals_of(COLLECTION_ID, nft_id);
+    let approvals = api
         .storage()
         .at_latest()
         .await?
-        .iter(nfts_account_addr)
+        .fetch(&approvals_addr)
         .await?;
+    Ok(approvals.is_some_and(|BoundedBTreeSet(accounts)| accounts.contains(&account)))
+}
+
+/// Returns the NFT already owned by the account in the collection, or a new one to be minted.
+///
+/// New item ids are derived from the account and checked on-chain, probing the next ones
+/// if already taken by another account.
+async fn fetch_or_generate_nft_id(
+    api: &OnlineClient<PolkadotConfig>,
+    account: AccountId32,
+) -> Result<(NftId, bool), CorematchError> {
+    // NOTE: all checks are done against the same block
+    let storage = api.storage().at_latest().await?;
 
-    // return nft id or generate a new one
-    if let Some(Ok((key, _))) = iter.next().await {
-        Ok((get_nft_id_from_storage_key(key), false))
-    } else {
-        let mut rng = rand::thread_rng();
-        Ok((rng.gen_range(100000..200000), true))
+    // check in storage, if the account has already an NFT in the collection:
+    if let Some(nft_id) = fetch_nft_id(&storage, account.clone()).await? {
+        return Ok((nft_id, false));
+    }
+
+    // look for the first candidate id not taken yet
+    for nft_id in nft_id_candidates(&account) {
+        let item_addr = node_runtime::storage().nfts().item(COLLECTION_ID, nft_id);
+        match storage.fetch(&item_addr).await? {
+            None => return Ok((nft_id, true)),
+            Some(item) if item.owner == account => return Ok((nft_id, false)),
+            Some(_) => continue,
+        }
     }
+
+    Err(CorematchError::Other(format!(
+        "No free NFT id found in collection {COLLECTION_ID} for account {account}"
+    )))
 }
@@ -0,0 +1,98 @@
+use corematch_asset_hub_westend::asset_hub_westend::{
+    encode_mint_call,
+    node_runtime::runtime_types::{
+        asset_hub_westend_runtime::RuntimeCall, bounded_collections::bounded_vec::BoundedVec,
+        pallet_nfts::pallet::Call as NftsCall, pallet_nfts::types::AttributeNamespace,
+        pallet_utility::pallet::Call as UtilityCall,
+    },
+    AssetHubWestendSource,
+};
+use corematch_common::runtimes::{asset_hub::AssetHubSource, support::SupportedRelayRuntime};
+use corematch_common::types::score::{ScoreRecord, SCORE_RECORD_VERSION};
+use subxt::ext::codec::Decode;
+use subxt::utils::{AccountId32, MultiAddress, H256};
+use subxt::Metadata;
+
+const NFT_ID: u32 = 123_456;
+
+fn metadata() -> Metadata {
+    let bytes = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/artifacts/metadata/asset_hub_westend_metadata.scale"
+    ))
+    .unwrap();
+    Metadata::decode(&mut &bytes[..]).unwrap()
+}
+
+fn alice() -> AccountId32 {
+    AccountId32([1u8; 32])
+}
+
+fn score() -> ScoreRecord {
+    ScoreRecord {
+        version: SCORE_RECORD_VERSION,
+        network: SupportedRelayRuntime::Polkadot,
+        level: 1,
+        points: 64,
+        duration: 120,
+        block_number: 23_456_789,
+        corespace_hash: H256::repeat_byte(0xcd),
+        app_version: "0.6.0".to_string(),
+    }
+}
+
+/// Decodes the call data back into the calls batched with `batch_all`.
+fn batched_calls(call_data: &[u8]) -> Vec<RuntimeCall> {
+    match RuntimeCall::decode(&mut &call_data[..]).unwrap() {
+        RuntimeCall::Utility(UtilityCall::batch_all { calls }) => calls,
+        call => panic!("Expected a batch_all call, got {call:?}"),
+    }
+}
+
+fn mint_call() -> RuntimeCall {
+    RuntimeCall::Nfts(NftsCall::mint {
+        collection: AssetHubWestendSource.collection_id(),
+        item: NFT_ID,
+        mint_to: MultiAddress::Id(alice()),
+        witness_data: None,
+    })
+}
+
+fn approve_call() -> RuntimeCall {
+    RuntimeCall::Nfts(NftsCall::approve_item_attributes {
+        collection: AssetHubWestendSource.collection_id(),
+        item: NFT_ID,
+        delegate: MultiAddress::Id(alice()),
+    })
+}
+
+fn set_attribute_call() -> RuntimeCall {
+    RuntimeCall::Nfts(NftsCall::set_attribute {
+        collection: AssetHubWestendSource.collection_id(),
+        maybe_item: Some(NFT_ID),
+        namespace: AttributeNamespace::Account(alice()),
+        key: BoundedVec(b"corematch/score/polkadot/1".to_vec()),
+        value: BoundedVec(score().encode().unwrap()),
+    })
+}
+
+#[test]
+fn new_items_are_minted_approved_and_scored_in_one_batch() {
+    let call_data = encode_mint_call(&metadata(), alice(), NFT_ID, true, false, &score()).unwrap();
+    assert_eq!(
+        batched_calls(&call_data),
+        vec![mint_call(), approve_call(), set_attribute_call()]
+    );
+}
+
+#[test]
+fn owned_items_are_only_approved_once() {
+    let call_data = encode_mint_call(&metadata(), alice(), NFT_ID, false, false, &score()).unwrap();
+    assert_eq!(
+        batched_calls(&call_data),
+        vec![approve_call(), set_attribute_call()]
+    );
+
+    let call_data = encode_mint_call(&metadata(), alice(), NFT_ID, false, true, &score()).unwrap();
+    assert_eq!(batched_calls(&call_data), vec![set_attribute_call()]);
+}
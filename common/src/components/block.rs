@@ -10,6 +10,9 @@ pub type Corespace = Vec<Core>;
 pub type Index = usize;
 pub type BlockNumber = u32;
 
+/// Width of the core usage buckets, in percentage points, matched in Level 0.
+pub const USAGE_BUCKET_SIZE: usize = 25;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Status {
     Matched,
@@ -145,6 +148,14 @@ impl Block {
                 "background-color: rgba(0, 0, 0, {});",
                 self.corespace_usage() as f32 / 100.0
             ),
+            SupportedRelayRuntime::Westend => format!(
+                "background-color: rgba(218, 104, 167, {});",
+                self.corespace_usage() as f32 / 100.0
+            ),
+            SupportedRelayRuntime::Paseo => format!(
+                "background-color: rgba(0, 177, 138, {});",
+                self.corespace_usage() as f32 / 100.0
+            ),
         }
     }
 
@@ -175,7 +186,9 @@ impl Block {
 
     pub fn corespace_hash(&self, game_level: GameLevel) -> H256 {
         let data: Vec<u8> = match game_level {
-            // GameLevel::Level0 => (self.corespace_usage() as u32).to_le_bytes().to_vec(),
+            GameLevel::Level0 => (self.corespace_usage_bucket() as u32)
+                .to_le_bytes()
+                .to_vec(),
             GameLevel::Level1 => self
                 .corespace
                 .iter()
@@ -190,21 +203,38 @@ impl Block {
             GameLevel::Level2 => self
                 .corespace
                 .iter()
-                .map(|core| {
+                .flat_map(|core| {
                     if let Some(para_id) = core.para_id {
                         para_id.to_le_bytes()
                     } else {
                         0x00u32.to_le_bytes()
                     }
                 })
-                .flatten()
+                .collect::<Vec<u8>>(),
+            // NOTE: only the multiset of scheduled para IDs matters, not the cores they are on
+            GameLevel::Level3 => self
+                .corespace_pattern()
+                .iter()
+                .filter_map(|core| core.para_id)
+                .flat_map(|para_id| para_id.to_le_bytes())
                 .collect::<Vec<u8>>(),
         };
         let hash = sp_core_hashing::blake2_256(&data[..]);
         H256::from(&hash)
     }
 
+    /// The cores sorted by para ID, idle cores last, as shown in Level 3.
+    pub fn corespace_pattern(&self) -> Corespace {
+        let mut corespace = self.corespace.clone();
+        corespace.sort_by_key(|core| (core.para_id.is_none(), core.para_id));
+        corespace
+    }
+
+    /// Percentage of occupied cores, 0 if there are no cores at all.
     pub fn corespace_usage(&self) -> usize {
+        if self.corespace.is_empty() {
+            return 0;
+        }
         let filled = self
             .corespace
             .iter()
@@ -214,6 +244,13 @@ impl Block {
         filled * 100 / self.corespace.len()
     }
 
+    /// The bucket the core usage falls in, e.g. 0 for 0-24% and 4 for 100% with 25 points wide
+    /// buckets.
+    pub fn corespace_usage_bucket(&self) -> usize {
+        self.corespace_usage() / USAGE_BUCKET_SIZE
+    }
+
+    #[allow(clippy::manual_is_multiple_of)]
     pub fn corespace_ascii(&self) -> String {
         self.corespace
             .iter()
@@ -225,15 +262,17 @@ impl Block {
                     "◻️".to_string()
                 };
                 if (i as u32 + 1) % self.runtime.columns_size() == 0 {
-                    char.push_str("\n");
+                    char.push('\n');
                 }
                 char
             })
             .collect::<String>()
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         &self,
+        block_view: BlockView,
         core_view: CoreView,
         onclick: Callback<()>,
         ondblclick: Callback<()>,
@@ -241,7 +280,7 @@ impl Block {
         ontouchend: Callback<()>,
         onanimationend: Callback<BlockNumber>,
     ) -> Html {
-        html! { <BlockComponent block={self.clone()} {core_view} {onclick} {ondblclick}
+        html! { <BlockComponent block={self.clone()} {block_view} {core_view} {onclick} {ondblclick}
         {ontouchstart} {ontouchend} {onanimationend} /> }
     }
 }
@@ -249,6 +288,7 @@ impl Block {
 #[derive(Properties, PartialEq)]
 pub struct Props {
     pub block: Block,
+    pub block_view: BlockView,
     pub core_view: CoreView,
     pub onclick: Callback<()>,
     pub ondblclick: Callback<()>,
@@ -263,22 +303,33 @@ pub fn block(props: &Props) -> Html {
     let ondblclick = props.ondblclick.reform(move |_| ());
     let ontouchstart = props.ontouchstart.reform(move |_| ());
     let ontouchend = props.ontouchend.reform(move |_| ());
-    let block_number = props.block.block_number.clone();
-    let onanimationend = props.onanimationend.reform(move |_| block_number.clone());
+    let block_number = props.block.block_number;
+    let onanimationend = props.onanimationend.reform(move |_| block_number);
 
     let not_available_cores_counter = (props.block.runtime.columns_size()
         * props.block.runtime.columns_size())
         - props.block.corespace.len() as u32;
     let not_available_vec = vec![0; not_available_cores_counter.try_into().unwrap()];
+    let corespace = if props.core_view.is_pattern() {
+        props.block.corespace_pattern()
+    } else {
+        props.block.corespace.clone()
+    };
 
     html! {
         <div class={classes!("corespace", props.block.classes())}
             {onclick} {ondblclick} {ontouchstart} {ontouchend} {onanimationend}>
             {
-                if !props.block.is_flipped {
+                if !props.block.is_flipped && props.block_view == BlockView::Palette {
+                    html! {
+                        <div class={classes!("palette", "usage")} style={props.block.inline_style()}>
+                            <span class="details">{ format!("{}%", props.block.corespace_usage()) }</span>
+                        </div>
+                    }
+                } else if !props.block.is_flipped {
                     html! {
                         <div class={classes!("cores")}>
-                            { for props.block.corespace.iter().map(|c| c.render(props.core_view.clone())) }
+                            { for corespace.iter().map(|c| c.render(props.core_view.clone())) }
                             { for not_available_vec.iter().map(|_| html! { <NaCoreComponent /> } ) }
                         </div>
                     }
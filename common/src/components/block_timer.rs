@@ -17,7 +17,7 @@ pub struct Props {
 pub struct BlockTimer {
     seconds: u32,
     milliseconds: u32,
-    interval: Option<Interval>,
+    _interval: Option<Interval>,
 }
 
 impl BlockTimer {
@@ -40,7 +40,7 @@ impl Component for BlockTimer {
         Self {
             seconds: SIX_SECS_TARGET,
             milliseconds: 0,
-            interval: Some(interval_handle),
+            _interval: Some(interval_handle),
         }
     }
 
@@ -94,6 +94,7 @@ pub fn button(props: &ShareButtonProps) -> Html {
 #[derive(Properties, PartialEq)]
 pub struct MintButtonProps {
     pub label: AttrValue,
+    pub disable: bool,
     pub children: Children,
     pub onclick: Callback<()>,
 }
@@ -102,9 +103,8 @@ pub struct MintButtonProps {
 pub fn button(props: &MintButtonProps) -> Html {
     let onclick = props.onclick.reform(move |_| ());
 
-    // TODO: MintButton is disabled for the moment
     html! {
-        <ActionButton label={props.label.clone()} disable={true} {onclick} >{props.children.clone()}</ActionButton>
+        <ActionButton label={props.label.clone()} disable={props.disable} {onclick} >{props.children.clone()}</ActionButton>
     }
 }
 
@@ -122,7 +122,7 @@ pub fn button(props: &BlockViewProps) -> Html {
     let onclick = props.onclick.reform(move |_| view.clone());
 
     html! {
-        <IconButton disable={props.disable.clone()} {onclick}>
+        <IconButton disable={props.disable} {onclick}>
             {props.children.clone()}
         </IconButton>
     }
@@ -142,7 +142,7 @@ pub fn button(props: &LevelProps) -> Html {
     let onclick = props.onclick.reform(move |_| level.clone());
 
     html! {
-        <IconButton disable={props.disable.clone()} {onclick}>
+        <IconButton disable={props.disable} {onclick}>
             {props.children.clone()}
         </IconButton>
     }
@@ -1,4 +1,5 @@
 use crate::types::network::{ParaId, ParachainColors};
+use serde::{Deserialize, Serialize};
 use yew::{classes, function_component, html, Html, Properties};
 pub type Index = usize;
 
@@ -7,9 +8,16 @@ pub enum CoreView {
     NotApplicable,
     Binary,
     Multi(ParachainColors),
+    // Para IDs are shown sorted, regardless of the core they are assigned to
+    Pattern(ParachainColors),
 }
 
 impl CoreView {
+    /// Whether cores are shown in the order of their para IDs rather than by core index.
+    pub fn is_pattern(&self) -> bool {
+        matches!(self, Self::Pattern(_))
+    }
+
     fn class(&self, para_id: Option<ParaId>) -> Option<String> {
         match self {
             Self::Binary => {
@@ -19,7 +27,7 @@ impl CoreView {
                     Some("core__0".to_string())
                 }
             }
-            Self::Multi(_) => {
+            Self::Multi(_) | Self::Pattern(_) => {
                 if let Some(para_id) = para_id {
                     Some(format!("para__{0}", para_id))
                 } else {
@@ -33,7 +41,7 @@ impl CoreView {
     fn style(&self, para_id: Option<ParaId>) -> Option<String> {
         match self {
             Self::Binary => None,
-            Self::Multi(parachain_colors) => {
+            Self::Multi(parachain_colors) | Self::Pattern(parachain_colors) => {
                 if let Some(para_id) = para_id {
                     if let Some(color) = parachain_colors.get(&para_id) {
                         return Some(format!(
@@ -49,7 +57,7 @@ impl CoreView {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct Core {
     pub index: Index,
     pub para_id: Option<ParaId>,
@@ -61,7 +69,7 @@ impl Core {
     }
 
     pub fn render(&self, view: CoreView) -> Html {
-        html! { <CoreComponent class={view.class(self.para_id.clone())} style={view.style(self.para_id.clone())} /> }
+        html! { <CoreComponent class={view.class(self.para_id)} style={view.style(self.para_id)} /> }
     }
 }
 
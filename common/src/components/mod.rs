@@ -5,4 +5,3 @@ pub mod core;
 pub mod help;
 pub mod keyboard;
 pub mod views;
-// pub mod account_provider;
@@ -16,8 +16,8 @@ pub fn view(props: &ColumnInfoProps) -> Html {
     let position_class = props.position_class.clone();
     let title = format!("{} {}", props.value.clone(), props.title.clone());
     let mut attempts = Vec::new();
-    let available = props.value.clone();
-    let gone = props.max.clone() - props.value.clone();
+    let available = props.value;
+    let gone = props.max - props.value;
     for _i in 0..available {
         attempts.push(true);
     }
@@ -0,0 +1,803 @@
+use crate::components::block::{Block, BlockNumber};
+use crate::types::game::{BoardSize, GameHelpStatus, GameLevel, GameMode, GameStatus};
+use crate::types::score::{ScoreRecord, SCORE_RECORD_VERSION};
+use std::collections::BTreeMap;
+use subxt::utils::H256;
+
+pub const DEFAULT_INITIAL_POINTS: u32 = 0;
+pub const DEFAULT_BASE_POINTS: u32 = 4;
+pub const DEFAULT_INITIAL_DURATION: u32 = 0;
+pub const DEFAULT_INITIAL_TRIES: u32 = 4;
+pub const DEFAULT_INITIAL_HELPS: u32 = 8;
+pub const DEFAULT_TOTAL_BLOCKS: u32 = 9;
+pub const DEFAULT_BOARD_COLUMNS: u8 = 3;
+pub const TIME_ATTACK_BLOCKS: u32 = 50;
+pub const DAILY_CHALLENGE_BLOCKS: u32 = 50;
+
+type X = u8;
+type Y = u8;
+pub type Position = (X, Y);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// GameCommand represents every action the engine knows how to handle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameCommand {
+    // Clear the board and reset game stats (e.g. a new subscription was created)
+    FullReset,
+    // Network is being switched, game must be reloaded
+    Reload,
+    // Latest finalized block to be added into the first cell of the board
+    PushBlock(Block),
+    // Start a new game
+    Start,
+    // Move the cursor one cell in the given direction
+    MoveCursor(Direction),
+    // Move the cursor to the cell at the given index
+    SelectCell(usize),
+    // First cell pressed is the one to be matched, the following ones are verified against it
+    PressCell(usize),
+    // Highlight matches
+    RequestHelp,
+    // Flip cell under the cursor and show block details
+    FlipCell,
+    // Switch game level
+    ChangeLevel(GameLevel),
+    // Transition to the next level is finished and game continues
+    EnterLevel(GameLevel),
+    // Cell animation for the block number has ended
+    AnimationEnded(BlockNumber),
+    // Change the number of cells, the board is cleared and the game reset
+    ResizeBoard(BoardSize),
+    // Switch game mode, only while the game is not on
+    ChangeMode(GameMode),
+    // End an endless game
+    Stop,
+}
+
+/// GameEvent represents every outcome emitted by the engine after handling a command.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    Started,
+    BlockPushed(BlockNumber),
+    MatchSelected(usize),
+    Matched(usize),
+    Missed(usize),
+    HelpHighlighted(u32),
+    // Minimum points reached, the game is in transit to the next level
+    NextLevel(GameLevel),
+    LevelChanged(GameLevel),
+    ModeChanged(GameMode),
+    Over,
+}
+
+/// GameEngine holds all the game rules and state, independently of how the board is rendered.
+#[derive(Clone, PartialEq)]
+pub struct GameEngine {
+    board_size: BoardSize,
+    game_mode: GameMode,
+    blocks: Vec<Option<Block>>,
+    match_position: Option<Position>,
+    match_counter: u32,
+    matches: BTreeMap<H256, u32>,
+    previous_match_block: Option<Block>,
+    game_status: GameStatus,
+    game_level: GameLevel,
+    // Level a new game starts at, Level 0 unless Level 1 is picked before starting
+    start_level: GameLevel,
+    duration: u32,
+    points: u32,
+    previous_points: u32,
+    matched: u32,
+    tries: u32,
+    helps: u32,
+    game_help_status: GameHelpStatus,
+    cursor_position: Position,
+}
+
+impl Default for GameEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameEngine {
+    pub fn new() -> Self {
+        Self {
+            board_size: BoardSize::default(),
+            game_mode: GameMode::default(),
+            blocks: vec![None; DEFAULT_TOTAL_BLOCKS.try_into().unwrap()],
+            match_position: None,
+            match_counter: 0,
+            matches: BTreeMap::new(),
+            previous_match_block: None,
+            game_status: GameStatus::Init,
+            game_level: GameLevel::Level0,
+            start_level: GameLevel::Level0,
+            duration: DEFAULT_INITIAL_DURATION,
+            points: DEFAULT_INITIAL_POINTS,
+            previous_points: DEFAULT_INITIAL_POINTS,
+            matched: 0,
+            tries: DEFAULT_INITIAL_TRIES,
+            helps: DEFAULT_INITIAL_HELPS,
+            game_help_status: GameHelpStatus::Available,
+            cursor_position: (0, 0),
+        }
+    }
+
+    pub fn handle(&mut self, command: GameCommand) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+        match command {
+            GameCommand::FullReset => {
+                self.full_reset();
+            }
+            GameCommand::Reload => {
+                self.game_status = GameStatus::Reload;
+            }
+            GameCommand::PushBlock(block) => {
+                self.push_block(block, &mut events);
+            }
+            GameCommand::Start => {
+                // NOTE: the daily challenge starts by itself once its first blocks are on the board
+                if self.game_mode != GameMode::DailyChallenge && self.start() {
+                    events.push(GameEvent::Started);
+                }
+            }
+            GameCommand::MoveCursor(direction) => {
+                let (x, y) = self.cursor_position;
+                let last = self.board_size.columns() - 1;
+                let new_position = match direction {
+                    Direction::Up => (x, if y == 0 { last } else { y - 1 }),
+                    Direction::Down => (x, if y == last { 0 } else { y + 1 }),
+                    Direction::Left => (if x == 0 { last } else { x - 1 }, y),
+                    Direction::Right => (if x == last { 0 } else { x + 1 }, y),
+                };
+                self.move_cursor(new_position);
+            }
+            GameCommand::SelectCell(i) => {
+                if self.is_game_on() {
+                    let cursor_index = self.cursor_index();
+                    if cursor_index != i {
+                        self.unselect_block(cursor_index);
+                    }
+                    self.set_cursor_position(i);
+                    self.select_block(i);
+                }
+            }
+            GameCommand::PressCell(i) => {
+                self.press_cell(i, &mut events);
+            }
+            GameCommand::RequestHelp => {
+                self.rebuild_matches();
+                self.start_help();
+            }
+            GameCommand::FlipCell => {
+                self.show_details();
+            }
+            GameCommand::ChangeLevel(game_level) => {
+                if self.is_game_on() {
+                    self.game_level = game_level.clone();
+                    self.rebuild_matches();
+                    events.push(GameEvent::LevelChanged(game_level));
+                } else if matches!(game_level, GameLevel::Level0 | GameLevel::Level1) {
+                    // NOTE: the next game starts at the level picked, the upper ones have to be
+                    // unlocked by collecting points
+                    self.start_level = game_level.clone();
+                    self.game_level = game_level.clone();
+                    self.rebuild_matches();
+                    events.push(GameEvent::LevelChanged(game_level));
+                }
+            }
+            GameCommand::EnterLevel(game_level) => {
+                // NOTE: the game might have ended while in transit to the next level
+                if matches!(self.game_status, GameStatus::MoveTo(_)) {
+                    self.game_level = game_level.clone();
+                    self.game_status = GameStatus::On;
+                    self.rebuild_matches();
+                    events.push(GameEvent::LevelChanged(game_level));
+                }
+            }
+            GameCommand::AnimationEnded(block_number) => {
+                self.animation_ended(block_number, &mut events);
+            }
+            GameCommand::ResizeBoard(board_size) => {
+                self.board_size = board_size;
+                self.full_reset();
+            }
+            GameCommand::ChangeMode(game_mode) => {
+                if !self.is_game_on() {
+                    self.game_mode = game_mode;
+                    events.push(GameEvent::ModeChanged(game_mode));
+                }
+            }
+            GameCommand::Stop => {
+                if self.is_game_on() && self.game_mode.is_endless() {
+                    self.finish();
+                    events.push(GameEvent::Over);
+                }
+            }
+        }
+        events
+    }
+
+    fn push_block(&mut self, block: Block, events: &mut Vec<GameEvent>) {
+        // reset match block
+        self.reset_match_block();
+        // add latest block into the first position
+        let block_number = block.block_number;
+        let block_hash = block.corespace_hash(self.game_level.clone());
+        self.blocks.insert(0, Some(block));
+        // add match counter for block_hash_key
+        self.matches
+            .entry(block_hash)
+            .and_modify(|m| *m += 1)
+            .or_insert(1);
+        // oldest block gets removed
+        if self.blocks.len() > self.total_blocks() {
+            if let Some(Some(block)) = self.blocks.pop() {
+                let block_hash = block.corespace_hash(self.game_level.clone());
+                // subtract counter from block_hash_key
+                self.matches.entry(block_hash).and_modify(|m| {
+                    if *m >= 1 {
+                        *m -= 1
+                    }
+                });
+                // remove if counter is zero
+                if let Some(counter) = self.matches.get(&block_hash) {
+                    if *counter == 0 {
+                        self.matches.remove(&block_hash);
+                    }
+                }
+            }
+        }
+        events.push(GameEvent::BlockPushed(block_number));
+
+        if self.is_game_on() {
+            // guarantee that only the current cursor position is selected
+            let cursor_index = self.cursor_index();
+            for (i, opt) in self.blocks.iter_mut().enumerate() {
+                if let Some(block) = opt {
+                    if self.game_status == GameStatus::On && cursor_index == i {
+                        block.selected();
+                    } else {
+                        block.unselected();
+                        block.cleared();
+                    }
+                }
+            }
+
+            // highlight matches if help is on
+            if self.game_help_status.is_on() {
+                let matches: Vec<H256> = self
+                    .matches
+                    .iter()
+                    .filter(|(_, counter)| **counter > 1)
+                    .map(|(hash, _)| *hash)
+                    .collect();
+
+                // highlight only the same pattern at a time
+                if matches.len() > 1 {
+                    let mut help_matches_counter = 0;
+                    if let Some(block_hash) = matches.last() {
+                        for block in self.blocks.iter_mut().flatten() {
+                            if *block_hash == block.corespace_hash(self.game_level.clone())
+                                && block.is_help_available()
+                                && !block.is_disabled()
+                            {
+                                block.help();
+                                help_matches_counter += 1;
+                            }
+                        }
+                        self.decr_help_matches(help_matches_counter);
+                        events.push(GameEvent::HelpHighlighted(help_matches_counter));
+                    }
+                }
+            }
+        }
+
+        // update game stats if game is on
+        self.incr_duration();
+
+        // start the daily challenge as soon as the board is filled up
+        if self.game_mode == GameMode::DailyChallenge
+            && matches!(self.game_status, GameStatus::Init | GameStatus::Ready)
+            && self.blocks.iter().all(|block| block.is_some())
+            && self.start()
+        {
+            events.push(GameEvent::Started);
+        }
+
+        // terminate game when the blocks of a time limited game have been played
+        if let Some(limit) = self.game_mode.duration_limit() {
+            if self.is_game_on() && self.duration >= limit {
+                self.finish();
+                events.push(GameEvent::Over);
+            }
+        }
+    }
+
+    fn press_cell(&mut self, i: usize, events: &mut Vec<GameEvent>) {
+        if !self.is_game_on() {
+            return;
+        }
+        let Some(Some(block)) = self.blocks.get(i) else {
+            return;
+        };
+        if block.is_matched() || block.is_disabled() {
+            return;
+        }
+        let corespace_hash = block.corespace_hash(self.game_level.clone());
+        let block_number = block.block_number;
+
+        if let Some(match_block) = self.match_block() {
+            if match_block.block_number == block_number {
+                // unselect previous match block
+                self.match_position = None;
+            } else if match_block.corespace_hash(self.game_level.clone()) == corespace_hash {
+                self.block_matched(i);
+                events.push(GameEvent::Matched(i));
+            } else {
+                self.block_missed(i);
+                events.push(GameEvent::Missed(i));
+                // verify if game is over
+                if self.is_game_over() {
+                    events.push(GameEvent::Over);
+                }
+                // reset match block
+                self.reset_match_block();
+            }
+        } else {
+            // first block pressed is the one to be matched
+            let cursor_index = self.cursor_index();
+            self.set_match_position(cursor_index);
+            events.push(GameEvent::MatchSelected(cursor_index));
+        }
+    }
+
+    fn block_matched(&mut self, i: usize) {
+        if let Some(Some(block)) = self.blocks.get_mut(i) {
+            // highlight block matched
+            block.matched();
+        }
+        if let Some(i) = self.match_index() {
+            if let Some(Some(block)) = self.blocks.get_mut(i) {
+                // highlight block matched
+                block.matched();
+                // remove from matches
+                let block_hash = block.corespace_hash(self.game_level.clone());
+                // subtract counter from block_hash_key
+                self.matches.entry(block_hash).and_modify(|m| {
+                    if *m >= 2 {
+                        *m -= 2
+                    }
+                });
+            }
+        }
+        // increase points
+        self.match_succeed();
+    }
+
+    fn block_missed(&mut self, i: usize) {
+        if let Some(Some(block)) = self.blocks.get_mut(i) {
+            block.missed();
+        }
+        if let Some(i) = self.match_index() {
+            if let Some(Some(block)) = self.blocks.get_mut(i) {
+                block.missed();
+            }
+        }
+        // decrease attempts
+        self.match_failed();
+
+        if self.is_game_over() {
+            // keep a copy of the last match block
+            if let Some(match_block) = self.match_block() {
+                self.previous_match_block.replace(match_block);
+                // clear selected block
+                let i = self.cursor_index();
+                self.unselect_block(i);
+            }
+        }
+    }
+
+    fn animation_ended(&mut self, block_number: BlockNumber, events: &mut Vec<GameEvent>) {
+        let Some(i) = self
+            .blocks
+            .iter()
+            .position(|opt| matches!(opt, Some(block) if block.block_number == block_number))
+        else {
+            return;
+        };
+        let mut next_level = None;
+        if let Some(Some(block)) = self.blocks.get_mut(i) {
+            if block.is_matched() {
+                // disable block
+                block.disabled();
+                // check if is time to move to next level
+                if self.is_next_level_available(self.game_level.clone()) {
+                    next_level = self.game_level.next();
+                }
+            } else {
+                block.cleared();
+            }
+        }
+        if let Some(next_level) = next_level {
+            self.game_status = GameStatus::MoveTo(next_level.clone());
+            // restore helps at each new level
+            self.helps = DEFAULT_INITIAL_HELPS;
+            self.game_help_status = GameHelpStatus::Available;
+            events.push(GameEvent::NextLevel(next_level));
+        }
+    }
+
+    pub fn board_size(&self) -> BoardSize {
+        self.board_size
+    }
+
+    pub fn game_mode(&self) -> GameMode {
+        self.game_mode
+    }
+
+    fn total_blocks(&self) -> usize {
+        self.board_size.total_blocks().try_into().unwrap()
+    }
+
+    pub fn blocks(&self) -> &Vec<Option<Block>> {
+        &self.blocks
+    }
+
+    pub fn matches(&self) -> &BTreeMap<H256, u32> {
+        &self.matches
+    }
+
+    pub fn game_status(&self) -> &GameStatus {
+        &self.game_status
+    }
+
+    pub fn game_level(&self) -> &GameLevel {
+        &self.game_level
+    }
+
+    pub fn points(&self) -> u32 {
+        self.points
+    }
+
+    pub fn duration(&self) -> u32 {
+        self.duration
+    }
+
+    /// Number of pairs matched in the current game.
+    pub fn matched(&self) -> u32 {
+        self.matched
+    }
+
+    pub fn tries(&self) -> u32 {
+        self.tries
+    }
+
+    pub fn helps(&self) -> u32 {
+        self.helps
+    }
+
+    pub fn game_help_status(&self) -> &GameHelpStatus {
+        &self.game_help_status
+    }
+
+    pub fn cursor_position(&self) -> Position {
+        self.cursor_position
+    }
+
+    pub fn previous_match_block(&self) -> Option<&Block> {
+        self.previous_match_block.as_ref()
+    }
+
+    pub fn is_game_on(&self) -> bool {
+        matches!(&self.game_status, GameStatus::On | GameStatus::MoveTo(_))
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        self.game_status == GameStatus::Over
+    }
+
+    pub fn is_help_on(&self) -> bool {
+        self.game_help_status.is_on()
+    }
+
+    pub fn is_next_level_available(&self, current_level: GameLevel) -> bool {
+        self.game_level == current_level
+            && self.previous_points < self.game_level.collected_points_per_level_minimum()
+            && self.points >= self.game_level.collected_points_per_level_minimum()
+    }
+
+    /// In Zen there are no points to collect, so every level is open.
+    pub fn is_level_x_completed(&self, game_level: GameLevel) -> bool {
+        !self.game_mode.has_points()
+            || self.points >= game_level.collected_points_per_level_minimum()
+    }
+
+    pub fn match_index(&self) -> Option<usize> {
+        if let Some(position) = self.match_position {
+            return Some((position.1 * self.board_size.columns() + position.0).into());
+        }
+        None
+    }
+
+    pub fn match_class(&self) -> String {
+        if let Some(index) = self.match_index() {
+            return format!("match__{}", index);
+        }
+        "".to_string()
+    }
+
+    pub fn cursor_index(&self) -> usize {
+        (self.cursor_position.1 * self.board_size.columns() + self.cursor_position.0).into()
+    }
+
+    pub fn last_finalized_block_number(&self) -> Option<BlockNumber> {
+        if self.is_game_on() {
+            if let Some(Some(block)) = self.blocks.first() {
+                return Some(block.block_number);
+            }
+        }
+        None
+    }
+
+    pub fn share_message(&self) -> Option<String> {
+        let game_results = self.game_results().unwrap_or_default();
+        if let Some(block) = &self.previous_match_block {
+            let headline = match self.game_mode {
+                GameMode::Classic => format!("corematch.xyz {} 👀\n", game_results),
+                GameMode::TimeAttack => format!("corematch.xyz time attack {} ⏱\n", game_results),
+                GameMode::Zen => format!("corematch.xyz zen {} 🧘\n", game_results),
+                GameMode::DailyChallenge => {
+                    format!("corematch.xyz daily challenge {} 📅\n", game_results)
+                }
+            };
+            let data = [headline, block.runtime.hashtag()];
+            Some(data.join("\n"))
+        } else {
+            None
+        }
+    }
+
+    pub fn game_results(&self) -> Option<String> {
+        self.previous_match_block
+            .as_ref()
+            .map(|block| match self.game_mode {
+                GameMode::Classic => {
+                    format!("{}/{}/{}", self.points, self.duration, block.block_number)
+                }
+                GameMode::TimeAttack | GameMode::DailyChallenge => {
+                    format!("{} points in {} blocks", self.points, self.duration)
+                }
+                GameMode::Zen => format!("{} matches in {} blocks", self.matched, self.duration),
+            })
+    }
+
+    /// The results of the game, as they are minted on-chain.
+    ///
+    /// Only Classic games are minted, scores of the other modes are not comparable.
+    pub fn score_record(&self) -> Option<ScoreRecord> {
+        if self.game_mode != GameMode::Classic {
+            return None;
+        }
+        self.previous_match_block.as_ref().map(|block| ScoreRecord {
+            version: SCORE_RECORD_VERSION,
+            network: block.runtime,
+            level: self.game_level.number(),
+            points: self.points,
+            duration: self.duration,
+            block_number: block.block_number,
+            corespace_hash: block.corespace_hash(self.game_level.clone()),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+        })
+    }
+
+    fn full_reset(&mut self) {
+        self.reset();
+        self.blocks = vec![None; self.total_blocks()];
+        self.matches.clear();
+    }
+
+    fn reset(&mut self) {
+        self.reset_blocks();
+        self.game_status = GameStatus::Ready;
+        self.game_level = self.start_level.clone();
+        self.duration = DEFAULT_INITIAL_DURATION;
+        self.points = DEFAULT_INITIAL_POINTS;
+        self.matched = 0;
+        self.tries = DEFAULT_INITIAL_TRIES;
+        self.helps = DEFAULT_INITIAL_HELPS;
+        self.game_help_status = GameHelpStatus::Available;
+        self.cursor_position = (0, 0);
+    }
+
+    fn reset_blocks(&mut self) {
+        for block in self.blocks.iter_mut().flatten() {
+            block.reset();
+        }
+    }
+
+    fn start(&mut self) -> bool {
+        if !self.is_game_on() {
+            self.reset();
+            self.game_status = GameStatus::On;
+            // NOTE: blocks pushed before the start were counted at the previous level
+            self.rebuild_matches();
+            // matches are always highlighted in Zen
+            if self.game_mode == GameMode::Zen {
+                self.game_help_status = GameHelpStatus::On;
+            }
+            return true;
+        }
+        false
+    }
+
+    fn finish(&mut self) {
+        // keep a copy of the match block, or the latest block if none is selected
+        let block = self
+            .match_block()
+            .or_else(|| self.blocks.first().cloned().flatten());
+        if let Some(block) = block {
+            self.previous_match_block.replace(block);
+        }
+        // clear selected block
+        let i = self.cursor_index();
+        self.unselect_block(i);
+        self.reset_match_block();
+        self.game_status = GameStatus::Over;
+    }
+
+    fn reset_match_block(&mut self) {
+        self.match_counter = 0;
+        self.match_position = None;
+    }
+
+    fn match_block(&self) -> Option<Block> {
+        if let Some(index) = self.match_index() {
+            if let Some(Some(match_block)) = self.blocks.get(index) {
+                return Some(match_block.clone());
+            }
+        }
+        None
+    }
+
+    fn set_match_position(&mut self, i: usize) {
+        let columns = usize::from(self.board_size.columns());
+        self.match_position = Some((
+            (i % columns)
+                .try_into()
+                .expect("usize with incorrect value"),
+            (i / columns)
+                .try_into()
+                .expect("usize with incorrect value"),
+        ));
+    }
+
+    fn unselect_block(&mut self, i: usize) {
+        if let Some(Some(block)) = self.blocks.get_mut(i) {
+            block.unselected();
+        }
+    }
+
+    fn select_block(&mut self, i: usize) {
+        if self.is_game_on() {
+            if let Some(Some(block)) = self.blocks.get_mut(i) {
+                block.selected();
+            }
+        }
+    }
+
+    fn move_cursor(&mut self, new_position: Position) {
+        if self.is_game_on() && new_position != self.cursor_position {
+            // clear previous selection
+            let i = self.cursor_index();
+            self.unselect_block(i);
+            // set new position
+            self.cursor_position = new_position;
+            // highlight the new block
+            let i = self.cursor_index();
+            self.select_block(i);
+        }
+    }
+
+    fn set_cursor_position(&mut self, i: usize) {
+        let columns = usize::from(self.board_size.columns());
+        self.cursor_position = (
+            (i % columns)
+                .try_into()
+                .expect("usize with incorrect value"),
+            (i / columns)
+                .try_into()
+                .expect("usize with incorrect value"),
+        );
+    }
+
+    fn match_succeed(&mut self) {
+        if self.is_game_on() {
+            self.incr_points();
+            self.match_counter += 1;
+            self.matched += 1;
+        }
+    }
+
+    fn match_failed(&mut self) {
+        if self.is_game_on() && self.game_mode.has_tries() {
+            self.decr_tries();
+        }
+    }
+
+    fn incr_points(&mut self) {
+        if self.is_game_on() && self.game_mode.has_points() {
+            let base: u32 = 2;
+            self.previous_points = self.points;
+            self.points += self.board_size.base_points() * base.pow(self.match_counter);
+        }
+    }
+
+    fn incr_duration(&mut self) {
+        if self.is_game_on() {
+            self.duration += 1;
+        }
+    }
+
+    fn decr_tries(&mut self) {
+        if self.is_game_on() && self.tries > 0 {
+            self.tries -= 1;
+            // terminate game when no tries left to be played
+            if self.tries == 0 {
+                self.game_status = GameStatus::Over;
+            }
+        }
+    }
+
+    fn rebuild_matches(&mut self) {
+        // reset matches map
+        let mut matches: BTreeMap<H256, u32> = BTreeMap::new();
+        for block in self.blocks.iter().flatten() {
+            let block_hash = block.corespace_hash(self.game_level.clone());
+            matches
+                .entry(block_hash)
+                .and_modify(|m| *m += 1)
+                .or_insert(1);
+        }
+        self.matches = matches;
+    }
+
+    fn start_help(&mut self) {
+        if self.is_game_on() && self.game_help_status.is_available() {
+            self.game_help_status = GameHelpStatus::On;
+        }
+    }
+
+    fn show_details(&mut self) {
+        if self.is_game_on() {
+            let i = self.cursor_index();
+            if let Some(Some(block)) = self.blocks.get_mut(i) {
+                // Note: only flip if an animation is not undergoing
+                if !block.is_anim_live() {
+                    block.flipped();
+                }
+            }
+        }
+    }
+
+    fn decr_help_matches(&mut self, v: u32) {
+        // NOTE: helps are unlimited in Zen
+        if self.is_help_on() && self.helps > 0 && self.game_mode != GameMode::Zen {
+            for _n in 0..v {
+                self.helps -= 1;
+                if self.helps == 0 {
+                    self.game_help_status = GameHelpStatus::NotAvailable;
+                    break;
+                }
+            }
+        }
+    }
+}
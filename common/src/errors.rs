@@ -21,6 +21,8 @@ pub enum CorematchError {
     DispatchError(#[from] DispatchError),
     #[error("{0}")]
     RpcError(#[from] RpcError),
+    #[error("Insufficient funds: {0}")]
+    InsufficientFunds(String),
     #[error("Other error: {0}")]
     Other(String),
 }
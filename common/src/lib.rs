@@ -1,4 +1,5 @@
 pub mod components;
+pub mod engine;
 pub mod errors;
 pub mod runtimes;
 pub mod types;
@@ -0,0 +1,162 @@
+use crate::errors::CorematchError;
+use crate::runtimes::{
+    source::SourceFuture,
+    support::{SupportedParachainRuntime, SupportedRelayRuntime},
+};
+use crate::types::{leaderboard::Leaderboard, score::ScoreRecord};
+use sp_core_hashing::blake2_256;
+use std::ops::Range;
+use subxt::{
+    dynamic::{At, Value},
+    ext::subxt_core::Error,
+    tx::{Payload, SubmittableExtrinsic},
+    utils::AccountId32,
+    Metadata, OnlineClient, PolkadotConfig,
+};
+
+/// NftId identifies an item of the Corematch collection.
+pub type NftId = u32;
+
+/// Range of item ids given to players, the ones outside it are left for the collection owner.
+pub const NFT_ID_SPACE: Range<NftId> = 100_000..200_000;
+/// Number of item ids tried for an account before giving up.
+pub const MAX_NFT_ID_PROBES: u32 = 64;
+
+/// Item ids of an account, in the order they are tried until one is free.
+///
+/// The first candidate is derived from the hashed account, so that the same player always
+/// maps to the same item; the next ones probe linearly, wrapping within the id space.
+pub fn nft_id_candidates(account: &AccountId32) -> impl Iterator<Item = NftId> {
+    let hash = blake2_256(account.as_ref());
+    let seed = u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]]);
+    let span = NFT_ID_SPACE.end - NFT_ID_SPACE.start;
+    (0..MAX_NFT_ID_PROBES).map(move |probe| NFT_ID_SPACE.start + (seed % span + probe) % span)
+}
+
+/// EncodedCall is a call already SCALE encoded by an AssetHubSource, so that it can be
+/// signed and submitted without knowing the runtime types it was built from.
+pub struct EncodedCall(pub Vec<u8>);
+
+impl Payload for EncodedCall {
+    fn encode_call_data_to(&self, _metadata: &Metadata, out: &mut Vec<u8>) -> Result<(), Error> {
+        out.extend_from_slice(&self.0);
+        Ok(())
+    }
+}
+
+/// AssetHubSource holds the runtime specific calls needed to mint Corematch results as NFTs.
+///
+/// Each supported AssetHub implements it against its own metadata.
+pub trait AssetHubSource: 'static {
+    /// The AssetHub runtime this source encodes calls for.
+    fn runtime(&self) -> SupportedParachainRuntime;
+
+    /// The NFT collection that holds Corematch items.
+    fn collection_id(&self) -> u32;
+
+    /// Prepare the call data that mints the account item, if not minted yet, and stores the
+    /// score record as an item attribute.
+    fn prepare_mint_call<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        account: AccountId32,
+        score: ScoreRecord,
+    ) -> SourceFuture<'a, Vec<u8>>;
+
+    /// Fetch the score records stored in the account item, empty if the item is not minted yet.
+    fn fetch_scores<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        account: AccountId32,
+    ) -> SourceFuture<'a, Vec<ScoreRecord>>;
+
+    /// Fetch the score records stored in all items of the collection.
+    fn fetch_leaderboard<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+    ) -> SourceFuture<'a, Leaderboard>;
+}
+
+/// MintCosts holds what a mint extrinsic costs and what the signer account can afford.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MintCosts {
+    /// Estimated fee, as reported by the `TransactionPaymentApi`.
+    pub partial_fee: u128,
+    pub free_balance: u128,
+    pub existential_deposit: u128,
+}
+
+impl MintCosts {
+    /// Fetch the fee of the extrinsic and the free balance of the account paying it.
+    ///
+    /// NOTE: the fee does not depend on the signature, so the extrinsic may be signed with a
+    /// dummy one before the user is asked to sign it.
+    pub async fn fetch(
+        api: &OnlineClient<PolkadotConfig>,
+        account_id: &AccountId32,
+        extrinsic: &SubmittableExtrinsic<PolkadotConfig, OnlineClient<PolkadotConfig>>,
+    ) -> Result<Self, CorematchError> {
+        Ok(Self {
+            partial_fee: extrinsic.partial_fee_estimate().await?,
+            free_balance: fetch_free_balance(api, account_id).await?,
+            existential_deposit: fetch_existential_deposit(api)?,
+        })
+    }
+
+    /// Verifies that the account can pay the fee and still hold the existential deposit.
+    pub fn check(&self, runtime: SupportedRelayRuntime) -> Result<(), CorematchError> {
+        if self.free_balance < self.partial_fee {
+            return Err(CorematchError::InsufficientFunds(format!(
+                "minting costs ~{} but the account only has {}",
+                runtime.format_amount(self.partial_fee),
+                runtime.format_amount(self.free_balance)
+            )));
+        }
+        if self.free_balance - self.partial_fee < self.existential_deposit {
+            return Err(CorematchError::InsufficientFunds(format!(
+                "after paying ~{} the account must still hold the existential deposit of {}",
+                runtime.format_amount(self.partial_fee),
+                runtime.format_amount(self.existential_deposit)
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn summary(&self, runtime: SupportedRelayRuntime) -> String {
+        format!(
+            "Fee ~{} · Balance {}",
+            runtime.format_amount(self.partial_fee),
+            runtime.format_amount(self.free_balance)
+        )
+    }
+}
+
+/// Fetch the free balance of the account, zero if the account does not exist.
+pub async fn fetch_free_balance(
+    api: &OnlineClient<PolkadotConfig>,
+    account_id: &AccountId32,
+) -> Result<u128, CorematchError> {
+    let address = subxt::dynamic::storage("System", "Account", vec![Value::from_bytes(account_id)]);
+    let Some(account_info) = api.storage().at_latest().await?.fetch(&address).await? else {
+        return Ok(0);
+    };
+    let account_info = account_info.to_value().map_err(subxt::Error::from)?;
+    account_info
+        .at("data")
+        .at("free")
+        .and_then(|free| free.as_u128())
+        .ok_or_else(|| "Free balance could not be decoded".into())
+}
+
+/// Fetch the minimum balance an account must hold to exist.
+pub fn fetch_existential_deposit(
+    api: &OnlineClient<PolkadotConfig>,
+) -> Result<u128, CorematchError> {
+    let address = subxt::dynamic::constant("Balances", "ExistentialDeposit");
+    api.constants()
+        .at(&address)?
+        .to_value()
+        .map_err(subxt::Error::from)?
+        .as_u128()
+        .ok_or_else(|| "Existential deposit could not be decoded".into())
+}
@@ -0,0 +1,151 @@
+use crate::components::block::BlockNumber;
+use crate::engine::DAILY_CHALLENGE_BLOCKS;
+use crate::errors::CorematchError;
+use crate::runtimes::{replay::Recording, source::RelayChainSource};
+use crate::types::daily::Day;
+use subxt::{utils::H256, OnlineClient, PolkadotConfig};
+
+/// Minimum time between two blocks, in milliseconds.
+const MIN_BLOCK_TIME: u64 = 6_000;
+/// Number of blocks looked at before giving up on finding the first block of a day.
+const MAX_PROBES: u32 = 64;
+/// Number of blocks found by interpolation, before falling back to bisection should the blocks be
+/// far from evenly spaced.
+const MAX_INTERPOLATIONS: u32 = 8;
+
+/// Number of blocks of a daily challenge, the ones filling up the board first followed by the
+/// ones played.
+pub fn daily_challenge_length(total_blocks: u32) -> u32 {
+    total_blocks + DAILY_CHALLENGE_BLOCKS
+}
+
+/// DayStartSearch narrows down the first block finalized on a day, from the timestamps of the
+/// blocks probed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayStartSearch {
+    day_start: u64,
+    /// Latest block probed that was finalized before the day started, with its timestamp.
+    before: Option<(BlockNumber, u64)>,
+    /// Earliest block probed that was finalized once the day started, with its timestamp.
+    after: (BlockNumber, u64),
+    probes: u32,
+}
+
+impl DayStartSearch {
+    /// Starts the search from a block finalized once the day started, e.g. the latest one.
+    pub fn new(day_start: u64, after: (BlockNumber, u64)) -> Self {
+        Self {
+            day_start,
+            before: None,
+            after,
+            probes: 0,
+        }
+    }
+
+    /// The first block of the day, once found.
+    pub fn first_block(&self) -> Option<BlockNumber> {
+        match self.before {
+            Some((before, _)) if before + 1 == self.after.0 => Some(self.after.0),
+            _ if self.after.0 == 0 => Some(0),
+            _ => None,
+        }
+    }
+
+    /// The block to be probed next.
+    pub fn probe(&self) -> BlockNumber {
+        let (after, after_timestamp) = self.after;
+        let Some((before, before_timestamp)) = self.before else {
+            // NOTE: blocks are at least six seconds apart, so stepping back by the time elapsed
+            // since the day started always lands on a block finalized before
+            let elapsed = (after_timestamp - self.day_start) / MIN_BLOCK_TIME;
+            return after.saturating_sub(1 + elapsed as u32);
+        };
+        let probe = if self.probes < MAX_INTERPOLATIONS {
+            // interpolate between both blocks, exact if blocks are evenly spaced
+            let elapsed = u128::from(self.day_start - before_timestamp);
+            let blocks = u128::from(after - before);
+            let duration = u128::from(after_timestamp - before_timestamp).max(1);
+            before + (elapsed * blocks).div_ceil(duration) as u32
+        } else {
+            before + (after - before) / 2
+        };
+        probe.clamp(before + 1, after - 1)
+    }
+
+    /// Narrow down the search with the timestamp of a block probed.
+    pub fn narrow(&mut self, block_number: BlockNumber, timestamp: u64) {
+        self.probes += 1;
+        if timestamp < self.day_start {
+            if self.before.is_none_or(|(before, _)| block_number > before) {
+                self.before = Some((block_number, timestamp));
+            }
+        } else if block_number < self.after.0 {
+            self.after = (block_number, timestamp);
+        }
+    }
+}
+
+/// Finds the first block finalized on the given day, looking back from the latest finalized one.
+pub async fn fetch_first_block_of_day(
+    source: &dyn RelayChainSource,
+    api: &OnlineClient<PolkadotConfig>,
+    day: Day,
+    latest: (BlockNumber, H256),
+) -> Result<BlockNumber, CorematchError> {
+    let day_start = day.start_millis();
+    let latest_timestamp = source.fetch_timestamp(api, latest.1).await?;
+    if latest_timestamp < day_start {
+        return Err(CorematchError::Other(format!("{day} has not started yet")));
+    }
+
+    let mut search = DayStartSearch::new(day_start, (latest.0, latest_timestamp));
+    for _ in 0..MAX_PROBES {
+        if let Some(block_number) = search.first_block() {
+            return Ok(block_number);
+        }
+        let block_number = search.probe();
+        let block_hash = source
+            .fetch_past_block_hash(api, block_number, latest)
+            .await?;
+        let timestamp = source.fetch_timestamp(api, block_hash).await?;
+        search.narrow(block_number, timestamp);
+    }
+    Err(CorematchError::Other(format!(
+        "First block of {day} could not be found after {MAX_PROBES} attempts"
+    )))
+}
+
+/// Fetch the corespace of the daily challenge of the given day, the blocks finalized right
+/// after the day started, so that every player faces the same board.
+pub async fn fetch_daily_challenge(
+    source: &dyn RelayChainSource,
+    api: &OnlineClient<PolkadotConfig>,
+    day: Day,
+    total_blocks: u32,
+) -> Result<Recording, CorematchError> {
+    let latest = api.blocks().at_latest().await?;
+    let latest = (latest.number(), latest.hash());
+
+    let first_block_number = fetch_first_block_of_day(source, api, day, latest).await?;
+    let last_block_number = first_block_number + daily_challenge_length(total_blocks) - 1;
+    if last_block_number >= latest.0 {
+        return Err(CorematchError::Other(format!(
+            "Daily challenge of {day} is not finalized yet, {} blocks to go",
+            last_block_number + 1 - latest.0
+        )));
+    }
+
+    // NOTE: the hashes of all the blocks of the challenge are kept at the block right after it
+    let at = source
+        .fetch_past_block_hash(api, last_block_number + 1, latest)
+        .await?;
+    let mut recording = Recording::new();
+    for block_number in first_block_number..=last_block_number {
+        let block_hash = source.fetch_block_hash_at(api, block_number, at).await?;
+        let block = source
+            .fetch_corespace(api, block_number, block_hash)
+            .await?;
+        recording.push(&block);
+    }
+    Ok(recording)
+}
@@ -0,0 +1,172 @@
+use crate::components::block::{Block, BlockNumber, Corespace};
+use crate::components::core::Core;
+use crate::errors::CorematchError;
+use crate::runtimes::{
+    source::{RelayChainSource, SourceFuture, DEFAULT_TOTAL_CORES},
+    support::SupportedRelayRuntime,
+    utils::get_para_id_from_storage_key,
+};
+use crate::types::network::{ParaId, ParachainIds};
+use futures::FutureExt;
+use subxt::{
+    dynamic::Value,
+    ext::{
+        scale_value::{At, ValueDef},
+        subxt_core,
+    },
+    utils::H256,
+    OnlineClient, PolkadotConfig,
+};
+
+/// DynamicSource decodes corespace data from the runtime metadata served by the node itself.
+///
+/// Testnet runtimes are upgraded far more often than Polkadot or Kusama, so rather than pinning
+/// a static metadata file per testnet, storage is decoded into `scale_value::Value`s against the
+/// metadata fetched when the online client is created.
+pub struct DynamicSource {
+    runtime: SupportedRelayRuntime,
+}
+
+impl DynamicSource {
+    pub fn new(runtime: SupportedRelayRuntime) -> Self {
+        Self { runtime }
+    }
+}
+
+impl RelayChainSource for DynamicSource {
+    fn runtime(&self) -> SupportedRelayRuntime {
+        self.runtime
+    }
+
+    fn fetch_para_ids<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+    ) -> SourceFuture<'a, ParachainIds> {
+        async move {
+            let mut para_ids: ParachainIds = Vec::new();
+            let address = subxt::dynamic::storage("Paras", "ParaLifecycles", ());
+            let mut iter = api.storage().at_latest().await?.iter(address).await?;
+
+            while let Some(Ok(storage)) = iter.next().await {
+                para_ids.push(get_para_id_from_storage_key(storage.key_bytes));
+            }
+            para_ids.sort();
+            Ok(para_ids)
+        }
+        .boxed_local()
+    }
+
+    fn fetch_block_hash_at<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_number: BlockNumber,
+        at: H256,
+    ) -> SourceFuture<'a, H256> {
+        async move {
+            let block_hash_addr = subxt::dynamic::storage(
+                "System",
+                "BlockHash",
+                vec![Value::u128(block_number as u128)],
+            );
+            match api.storage().at(at).fetch(&block_hash_addr).await? {
+                Some(thunk) => Ok(thunk.as_type::<H256>().map_err(subxt_core::Error::Decode)?),
+                None => Err(CorematchError::Other(format!(
+                    "Failed to fetch block_hash for block_number: {block_number}"
+                ))),
+            }
+        }
+        .boxed_local()
+    }
+
+    fn fetch_timestamp<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_hash: H256,
+    ) -> SourceFuture<'a, u64> {
+        async move {
+            let timestamp_addr = subxt::dynamic::storage("Timestamp", "Now", ());
+            match api.storage().at(block_hash).fetch(&timestamp_addr).await? {
+                Some(thunk) => Ok(thunk.as_type::<u64>().map_err(subxt_core::Error::Decode)?),
+                None => Err(CorematchError::Other(format!(
+                    "Failed to fetch timestamp for block_hash: {block_hash}"
+                ))),
+            }
+        }
+        .boxed_local()
+    }
+
+    fn fetch_corespace<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_number: BlockNumber,
+        block_hash: H256,
+    ) -> SourceFuture<'a, Block> {
+        async move {
+            // Fetch availability_cores
+            let availability_cores_addr =
+                subxt::dynamic::storage("ParaScheduler", "AvailabilityCores", ());
+
+            let availability_cores_option = api
+                .storage()
+                .at(block_hash)
+                .fetch(&availability_cores_addr)
+                .await?;
+
+            if let Some(availability_cores) = availability_cores_option {
+                let availability_cores = availability_cores
+                    .to_value()
+                    .map_err(subxt_core::Error::Decode)?;
+
+                let mut corespace = corespace_from_value(&availability_cores);
+
+                // Note: keep only the predefined number of cores
+                corespace.truncate(DEFAULT_TOTAL_CORES as usize);
+
+                return Ok(Block::new(block_number, corespace, self.runtime));
+            }
+            Err(CorematchError::Other(format!(
+                "Failed to fetch availability_cores for block_hash: {block_hash}"
+            )))
+        }
+        .boxed_local()
+    }
+}
+
+/// Build a corespace from a decoded `ParaScheduler::AvailabilityCores` value.
+///
+/// Each core is either `Free` or `Paras(ParasEntry { assignment, .. })`, where the assignment is
+/// `Pool { para_id, core_index }` or `Bulk(para_id)`. Unknown shapes are shown as free cores.
+pub fn corespace_from_value<T>(availability_cores: &Value<T>) -> Corespace {
+    let ValueDef::Composite(cores) = &availability_cores.value else {
+        return Vec::new();
+    };
+
+    cores
+        .values()
+        .enumerate()
+        .map(|(i, core_occupied)| Core::new(i, para_id_from_core_occupied(core_occupied)))
+        .collect::<Corespace>()
+}
+
+fn para_id_from_core_occupied<T>(core_occupied: &Value<T>) -> Option<ParaId> {
+    let ValueDef::Variant(variant) = &core_occupied.value else {
+        return None;
+    };
+    if variant.name != "Paras" {
+        return None;
+    }
+    let assignment = variant.at(0).and_then(|entry| entry.at("assignment"))?;
+    let ValueDef::Variant(assignment) = &assignment.value else {
+        return None;
+    };
+    let para_id = match assignment.name.as_str() {
+        "Pool" => assignment.at("para_id"),
+        "Bulk" => assignment.at(0),
+        _ => None,
+    };
+    // Note: para_id is wrapped in the `Id` newtype
+    para_id
+        .at(0)
+        .and_then(|id| id.as_u128())
+        .and_then(|id| ParaId::try_from(id).ok())
+}
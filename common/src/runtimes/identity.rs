@@ -0,0 +1,220 @@
+use crate::errors::CorematchError;
+use crate::runtimes::support::SupportedRelayRuntime;
+use futures::future::join_all;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use subxt::{
+    dynamic::Value,
+    ext::scale_value::{At, Composite, ValueDef},
+    utils::AccountId32,
+    OnlineClient, PolkadotConfig,
+};
+
+/// Identity is the on-chain identity of an account, as registered in the People chain.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Identity {
+    /// Display name of the identity, or of the parent identity for sub-accounts.
+    pub display: String,
+    /// Name given to the sub-account by its parent identity.
+    pub sub: Option<String>,
+    /// Whether a registrar has judged the identity as `Reasonable` or `KnownGood`.
+    pub is_verified: bool,
+}
+
+impl Identity {
+    /// The name of the account, e.g. `turboflakes/corematch` for a sub-account.
+    pub fn name(&self) -> String {
+        match &self.sub {
+            Some(sub) => format!("{}/{}", self.display, sub),
+            None => self.display.clone(),
+        }
+    }
+
+    /// The name of the account, marked with a check if verified.
+    pub fn label(&self) -> String {
+        if self.is_verified {
+            format!("{} ✓", self.name())
+        } else {
+            self.name()
+        }
+    }
+}
+
+/// IdentityService resolves account identities from the People chain of a relay chain.
+///
+/// The People chain is only connected once the first identity is requested, and resolved
+/// identities (or their absence) are cached for the lifetime of the service.
+pub struct IdentityService {
+    runtime: SupportedRelayRuntime,
+    online_client: RefCell<Option<OnlineClient<PolkadotConfig>>>,
+    cache: RefCell<BTreeMap<AccountId32, Option<Identity>>>,
+}
+
+impl IdentityService {
+    pub fn new(runtime: SupportedRelayRuntime) -> Self {
+        Self {
+            runtime,
+            online_client: RefCell::new(None),
+            cache: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn runtime(&self) -> SupportedRelayRuntime {
+        self.runtime
+    }
+
+    /// Returns the cached identity of the account, None if not resolved or without identity.
+    pub fn cached(&self, account: &AccountId32) -> Option<Identity> {
+        self.cache.borrow().get(account).cloned().flatten()
+    }
+
+    /// Resolve the identities of the accounts, only the ones not cached yet are fetched.
+    pub async fn resolve(
+        &self,
+        accounts: &[AccountId32],
+    ) -> Result<BTreeMap<AccountId32, Identity>, CorematchError> {
+        let missing: Vec<AccountId32> = {
+            let cache = self.cache.borrow();
+            accounts
+                .iter()
+                .filter(|account| !cache.contains_key(account))
+                .cloned()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect()
+        };
+
+        if !missing.is_empty() {
+            let api = self.online_client().await?;
+            let identities = join_all(missing.iter().map(|account| fetch_identity(&api, account)))
+                .await
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()?;
+            self.cache
+                .borrow_mut()
+                .extend(missing.into_iter().zip(identities));
+        }
+
+        let cache = self.cache.borrow();
+        Ok(accounts
+            .iter()
+            .filter_map(|account| {
+                let identity = cache.get(account).cloned().flatten()?;
+                Some((account.clone(), identity))
+            })
+            .collect())
+    }
+
+    async fn online_client(&self) -> Result<OnlineClient<PolkadotConfig>, CorematchError> {
+        if let Some(api) = self.online_client.borrow().as_ref() {
+            return Ok(api.clone());
+        }
+        let api =
+            OnlineClient::<PolkadotConfig>::from_url(self.runtime.default_people_rpc_url()).await?;
+        *self.online_client.borrow_mut() = Some(api.clone());
+        Ok(api)
+    }
+}
+
+impl PartialEq for IdentityService {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+/// Fetch the identity of the account, or the one of its parent if it is a sub-account.
+pub async fn fetch_identity(
+    api: &OnlineClient<PolkadotConfig>,
+    account: &AccountId32,
+) -> Result<Option<Identity>, CorematchError> {
+    let storage = api.storage().at_latest().await?;
+
+    let super_of_addr =
+        subxt::dynamic::storage("Identity", "SuperOf", vec![Value::from_bytes(account)]);
+    let (identity_account, sub) = match storage.fetch(&super_of_addr).await? {
+        Some(super_of) => {
+            let super_of = super_of.to_value().map_err(subxt::Error::from)?;
+            match super_from_value(&super_of) {
+                Some((parent, sub)) => (parent, sub),
+                None => (account.clone(), None),
+            }
+        }
+        None => (account.clone(), None),
+    };
+
+    let identity_of_addr = subxt::dynamic::storage(
+        "Identity",
+        "IdentityOf",
+        vec![Value::from_bytes(&identity_account)],
+    );
+    let Some(registration) = storage.fetch(&identity_of_addr).await? else {
+        return Ok(None);
+    };
+    let registration = registration.to_value().map_err(subxt::Error::from)?;
+    Ok(identity_from_value(&registration).map(|identity| Identity { sub, ..identity }))
+}
+
+/// Build an identity from a decoded `Identity::IdentityOf` value.
+///
+/// The value is a `Registration { judgements, deposit, info }`, wrapped in a tuple along with
+/// the username in older runtimes. Identities without a raw display name are ignored.
+pub fn identity_from_value<T>(registration: &Value<T>) -> Option<Identity> {
+    let registration = match registration.at("info") {
+        Some(_) => registration,
+        None => registration.at(0)?,
+    };
+    let display = data_to_string(registration.at("info")?.at("display")?)?;
+    let is_verified = match &registration.at("judgements")?.value {
+        ValueDef::Composite(judgements) => judgements.values().any(|judgement| {
+            matches!(
+                &judgement.at(1).map(|j| &j.value),
+                Some(ValueDef::Variant(variant)) if variant.name == "Reasonable" || variant.name == "KnownGood"
+            )
+        }),
+        _ => false,
+    };
+    Some(Identity {
+        display,
+        sub: None,
+        is_verified,
+    })
+}
+
+/// Returns the parent account and the sub name from a decoded `Identity::SuperOf` value.
+pub fn super_from_value<T>(super_of: &Value<T>) -> Option<(AccountId32, Option<String>)> {
+    let parent = bytes_from_value(super_of.at(0)?)?;
+    let parent = AccountId32(parent.try_into().ok()?);
+    Some((parent, super_of.at(1).and_then(data_to_string)))
+}
+
+/// Decode an identity `Data` value, only `Raw` data holds a readable string.
+fn data_to_string<T>(data: &Value<T>) -> Option<String> {
+    let ValueDef::Variant(variant) = &data.value else {
+        return None;
+    };
+    if !variant.name.starts_with("Raw") {
+        return None;
+    }
+    let bytes = match &variant.values {
+        Composite::Unnamed(values) => bytes_from_value(values.first()?)?,
+        _ => return None,
+    };
+    String::from_utf8(bytes).ok().filter(|s| !s.is_empty())
+}
+
+/// Collect the bytes of a value encoded as a (possibly newtype wrapped) sequence of u8.
+fn bytes_from_value<T>(value: &Value<T>) -> Option<Vec<u8>> {
+    let ValueDef::Composite(composite) = &value.value else {
+        return None;
+    };
+    let values: Vec<&Value<T>> = composite.values().collect();
+    if let [inner] = values[..] {
+        if matches!(inner.value, ValueDef::Composite(_)) {
+            return bytes_from_value(inner);
+        }
+    }
+    values
+        .into_iter()
+        .map(|byte| byte.as_u128().and_then(|byte| u8::try_from(byte).ok()))
+        .collect()
+}
@@ -0,0 +1,168 @@
+use crate::errors::CorematchError;
+use crate::runtimes::support::SupportedParachainRuntime;
+use merkleized_metadata::{generate_metadata_digest, ExtraInfo};
+use subxt::{
+    client::ClientState,
+    config::{
+        signed_extensions::{
+            self, ChargeAssetTxPayment, ChargeTransactionPayment, CheckGenesis, CheckMortality,
+            CheckNonce, CheckSpecVersion, CheckTxVersion,
+        },
+        Config, ExtrinsicParams, ExtrinsicParamsEncoder, PolkadotConfig, RefineParams,
+        SignedExtension,
+    },
+    error::ExtrinsicParamsError,
+    ext::{
+        codec::{Decode, Encode},
+        frame_metadata::RuntimeMetadataPrefixed,
+        scale_value::scale::PortableRegistry,
+    },
+    utils::H256,
+    OnlineClient,
+};
+
+/// Version of the runtime metadata the hash is computed from, the first one merkleized.
+const METADATA_VERSION: u32 = 15;
+
+/// AssetHubConfig matches PolkadotConfig, except that extrinsics are checked against the
+/// metadata hash of RFC-78, so that wallets can show what is being signed without holding
+/// the whole metadata.
+pub enum AssetHubConfig {}
+
+impl Config for AssetHubConfig {
+    type Hash = <PolkadotConfig as Config>::Hash;
+    type AccountId = <PolkadotConfig as Config>::AccountId;
+    type Address = <PolkadotConfig as Config>::Address;
+    type Signature = <PolkadotConfig as Config>::Signature;
+    type Hasher = <PolkadotConfig as Config>::Hasher;
+    type Header = <PolkadotConfig as Config>::Header;
+    type ExtrinsicParams = AssetHubExtrinsicParams;
+    type AssetId = <PolkadotConfig as Config>::AssetId;
+}
+
+/// The signed extensions of [`subxt::config::DefaultExtrinsicParams`], with
+/// [`CheckMetadataHash`] enabled.
+pub type AssetHubExtrinsicParams = signed_extensions::AnyOf<
+    AssetHubConfig,
+    (
+        CheckSpecVersion,
+        CheckTxVersion,
+        CheckNonce,
+        CheckGenesis<AssetHubConfig>,
+        CheckMortality<AssetHubConfig>,
+        ChargeAssetTxPayment<AssetHubConfig>,
+        ChargeTransactionPayment,
+        CheckMetadataHash,
+    ),
+>;
+
+/// The `CheckMetadataHash` signed extension, enabled when the metadata hash is given.
+///
+/// NOTE: subxt always disables it, as it does not compute the metadata hash.
+pub struct CheckMetadataHash(Option<H256>);
+
+/// The metadata hash the extrinsic is checked against, `None` disables the check.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CheckMetadataHashParams(pub Option<H256>);
+
+impl<T: Config> RefineParams<T> for CheckMetadataHashParams {}
+
+impl<T: Config> ExtrinsicParams<T> for CheckMetadataHash {
+    type Params = CheckMetadataHashParams;
+
+    fn new(_client: &ClientState<T>, params: Self::Params) -> Result<Self, ExtrinsicParamsError> {
+        Ok(Self(params.0))
+    }
+}
+
+impl ExtrinsicParamsEncoder for CheckMetadataHash {
+    fn encode_extra_to(&self, v: &mut Vec<u8>) {
+        // the mode, 0 if disabled and 1 if enabled
+        u8::from(self.0.is_some()).encode_to(v);
+    }
+
+    fn encode_additional_to(&self, v: &mut Vec<u8>) {
+        self.0.map(|hash| hash.0).encode_to(v);
+    }
+}
+
+impl<T: Config> SignedExtension<T> for CheckMetadataHash {
+    type Decoded = signed_extensions::CheckMetadataHashMode;
+
+    fn matches(identifier: &str, _type_id: u32, _types: &PortableRegistry) -> bool {
+        identifier == "CheckMetadataHash"
+    }
+}
+
+/// The fields of the runtime version the metadata hash depends on.
+#[derive(Decode)]
+#[codec(crate = subxt::ext::codec)]
+struct RuntimeVersionPrefix {
+    spec_name: String,
+    _impl_name: String,
+    _authoring_version: u32,
+    spec_version: u32,
+}
+
+/// Fetch the latest runtime metadata and compute its hash, as checked by `CheckMetadataHash`.
+pub async fn fetch_metadata_hash(
+    api: &OnlineClient<PolkadotConfig>,
+    runtime: SupportedParachainRuntime,
+) -> Result<H256, CorematchError> {
+    let runtime_api = api.runtime_api().at_latest().await?;
+    let version: RuntimeVersionPrefix = runtime_api.call_raw("Core_version", None).await?;
+    let metadata: Option<Vec<u8>> = runtime_api
+        .call_raw(
+            "Metadata_metadata_at_version",
+            Some(&METADATA_VERSION.encode()),
+        )
+        .await?;
+    let metadata = metadata.ok_or_else(|| {
+        CorematchError::Other(format!(
+            "Metadata V{METADATA_VERSION} is not available on {runtime}"
+        ))
+    })?;
+    let base58_prefix = ss58_prefix(api)?;
+    metadata_hash(
+        &metadata,
+        version.spec_name,
+        version.spec_version,
+        base58_prefix,
+        runtime,
+    )
+}
+
+/// Compute the metadata hash of the SCALE encoded runtime metadata.
+///
+/// NOTE: the token decimals and symbol are the ones of the relay chain the AssetHub is
+/// connected to.
+pub fn metadata_hash(
+    metadata: &[u8],
+    spec_name: String,
+    spec_version: u32,
+    base58_prefix: u16,
+    runtime: SupportedParachainRuntime,
+) -> Result<H256, CorematchError> {
+    let metadata = RuntimeMetadataPrefixed::decode(&mut &metadata[..])
+        .map_err(|err| CorematchError::Other(format!("Metadata could not be decoded: {err}")))?;
+    let relay_runtime = runtime.relay_runtime();
+    let extra_info = ExtraInfo {
+        spec_version,
+        spec_name,
+        base58_prefix,
+        decimals: relay_runtime.decimals() as u8,
+        token_symbol: relay_runtime.unit().to_string(),
+    };
+    let digest = generate_metadata_digest(&metadata.1, extra_info).map_err(|err| {
+        CorematchError::Other(format!("Metadata hash could not be computed: {err}"))
+    })?;
+    Ok(H256(digest.hash()))
+}
+
+/// Fetch the SS58 prefix of the chain addresses.
+fn ss58_prefix(api: &OnlineClient<PolkadotConfig>) -> Result<u16, CorematchError> {
+    let address = subxt::dynamic::constant("System", "SS58Prefix");
+    let value = api.constants().at(&address)?;
+    u16::decode(&mut value.encoded())
+        .map_err(|err| CorematchError::Other(format!("SS58 prefix could not be decoded: {err}")))
+}
@@ -1,4 +1,10 @@
-#![allow(clippy::all)]
-
+pub mod asset_hub;
+pub mod daily;
+pub mod dynamic;
+pub mod identity;
+pub mod metadata_hash;
+pub mod practice;
+pub mod replay;
+pub mod source;
 pub mod support;
 pub mod utils;
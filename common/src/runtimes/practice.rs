@@ -0,0 +1,197 @@
+use crate::components::block::{Block, BlockNumber, Corespace};
+use crate::components::core::Core;
+use crate::engine::DEFAULT_TOTAL_BLOCKS;
+use crate::errors::CorematchError;
+use crate::runtimes::{
+    source::{subscribe_ticks, DEFAULT_TOTAL_CORES, SIX_SECS},
+    support::SupportedRelayRuntime,
+};
+use crate::types::network::{ParaId, ParachainIds, SubscriptionId};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{collections::VecDeque, str::FromStr};
+use yew::{platform::pinned::mpsc::UnboundedSender, AttrValue, Callback};
+
+/// A few system and long-lived parachains, used when no para ids are known for the runtime.
+pub const DEFAULT_PRACTICE_PARA_IDS: [ParaId; 8] = [1000, 1001, 1002, 1004, 1005, 2000, 2004, 2034];
+
+/// Occupancy defines the share of cores assigned to a parachain in each synthetic block.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Occupancy {
+    /// Every block has the same share of occupied cores.
+    Fixed(f64),
+    /// The share of occupied cores is drawn for each block between `min` and `max`.
+    Uniform { min: f64, max: f64 },
+}
+
+/// Parses a share of occupied cores between 0 and 1, either fixed (e.g. `0.5`) or drawn from a
+/// range (e.g. `0.2-0.8`).
+impl FromStr for Occupancy {
+    type Err = CorematchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_share = |share: &str| {
+            share
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|share| (0.0..=1.0).contains(share))
+                .ok_or_else(|| {
+                    CorematchError::Other(format!("Occupancy {s} is not a share between 0 and 1"))
+                })
+        };
+        match s.split_once('-') {
+            Some((min, max)) => {
+                let (min, max) = (parse_share(min)?, parse_share(max)?);
+                if min > max {
+                    return Err(CorematchError::Other(format!(
+                        "Occupancy {s} is not an increasing range"
+                    )));
+                }
+                Ok(Self::Uniform { min, max })
+            }
+            None => Ok(Self::Fixed(parse_share(s)?)),
+        }
+    }
+}
+
+impl Occupancy {
+    fn sample<R: Rng>(&self, rng: &mut R) -> f64 {
+        let share = match *self {
+            Self::Fixed(share) => share,
+            Self::Uniform { min, max } if min < max => rng.gen_range(min..max),
+            Self::Uniform { min, .. } => min,
+        };
+        share.clamp(0.0, 1.0)
+    }
+}
+
+/// PracticeConfig tunes the synthetic corespace played in practice mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PracticeConfig {
+    /// Number of cores in each block.
+    pub total_cores: u32,
+    /// Share of cores assigned to a parachain.
+    pub occupancy: Occupancy,
+    /// Parachains the occupied cores are assigned to.
+    pub para_ids: ParachainIds,
+    /// Probability of a block repeating the corespace of one of the blocks still on the board.
+    pub repeat_probability: f64,
+    /// Number of cells on the board.
+    pub total_blocks: u32,
+}
+
+impl Default for PracticeConfig {
+    fn default() -> Self {
+        Self {
+            total_cores: DEFAULT_TOTAL_CORES,
+            occupancy: Occupancy::Uniform { min: 0.2, max: 0.8 },
+            para_ids: DEFAULT_PRACTICE_PARA_IDS.to_vec(),
+            repeat_probability: 0.3,
+            total_blocks: DEFAULT_TOTAL_BLOCKS,
+        }
+    }
+}
+
+/// CorespaceGenerator produces plausible blocks offline, so that the game can be practiced
+/// without a connection to the relay chain.
+pub struct CorespaceGenerator {
+    config: PracticeConfig,
+    runtime: SupportedRelayRuntime,
+    rng: StdRng,
+    block_number: BlockNumber,
+    // corespace of the blocks that are still on the board
+    recent: VecDeque<Corespace>,
+}
+
+impl CorespaceGenerator {
+    pub fn new(config: PracticeConfig, runtime: SupportedRelayRuntime) -> Self {
+        Self::from_rng(config, runtime, StdRng::from_entropy())
+    }
+
+    /// Creates a generator that always produces the same sequence of blocks for the same seed.
+    pub fn with_seed(config: PracticeConfig, runtime: SupportedRelayRuntime, seed: u64) -> Self {
+        Self::from_rng(config, runtime, StdRng::seed_from_u64(seed))
+    }
+
+    fn from_rng(config: PracticeConfig, runtime: SupportedRelayRuntime, rng: StdRng) -> Self {
+        Self {
+            config,
+            runtime,
+            rng,
+            block_number: 0,
+            recent: VecDeque::new(),
+        }
+    }
+
+    pub fn para_ids(&self) -> ParachainIds {
+        let mut para_ids = self.config.para_ids.clone();
+        para_ids.sort();
+        para_ids.dedup();
+        para_ids
+    }
+
+    pub fn next_block(&mut self) -> Block {
+        let corespace = if !self.recent.is_empty()
+            && self
+                .rng
+                .gen_bool(self.config.repeat_probability.clamp(0.0, 1.0))
+        {
+            let i = self.rng.gen_range(0..self.recent.len());
+            self.recent[i].clone()
+        } else {
+            self.random_corespace()
+        };
+
+        // NOTE: a repeated pattern must match a block still on the board
+        if self.recent.len() >= self.config.total_blocks.saturating_sub(1) as usize {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(corespace.clone());
+
+        self.block_number += 1;
+        Block::new(self.block_number, corespace, self.runtime)
+    }
+
+    fn random_corespace(&mut self) -> Corespace {
+        let share = self.config.occupancy.sample(&mut self.rng);
+        (0..self.config.total_cores as usize)
+            .map(|i| {
+                let para_id = if !self.config.para_ids.is_empty() && self.rng.gen_bool(share) {
+                    let j = self.rng.gen_range(0..self.config.para_ids.len());
+                    Some(self.config.para_ids[j])
+                } else {
+                    None
+                };
+                Core::new(i, para_id)
+            })
+            .collect()
+    }
+
+    /// Plays synthetic blocks into the callback at the network pace, in the same way as a live
+    /// subscription, the first tick fills up the board.
+    pub fn subscribe(
+        mut self,
+        cb: Callback<(SubscriptionId, Block)>,
+    ) -> (SubscriptionId, UnboundedSender<AttrValue>) {
+        let mut first_tick = true;
+        let total_blocks = self.config.total_blocks;
+        let ticks = std::iter::from_fn(move || {
+            let total = if first_tick {
+                first_tick = false;
+                total_blocks + 1
+            } else {
+                1
+            };
+            Some((0..total).map(|_| self.next_block()).collect())
+        });
+        subscribe_ticks(ticks, SIX_SECS, cb)
+    }
+}
+
+impl Iterator for CorespaceGenerator {
+    type Item = Block;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_block())
+    }
+}
@@ -0,0 +1,225 @@
+use crate::components::block::{Block, BlockNumber, Corespace};
+use crate::errors::CorematchError;
+use crate::runtimes::{
+    source::{subscribe_ticks, RelayChainSource, SourceFuture, SIX_SECS},
+    support::SupportedRelayRuntime,
+};
+use crate::types::network::{ParachainIds, SubscriptionId};
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::BTreeSet, rc::Rc, time::Duration};
+use subxt::{utils::H256, OnlineClient, PolkadotConfig};
+use yew::{platform::pinned::mpsc::UnboundedSender, AttrValue, Callback};
+
+/// BlockRecord is a single line of a recording, the corespace of a block as it was fetched.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct BlockRecord {
+    pub block_number: BlockNumber,
+    pub corespace: Corespace,
+    pub runtime: SupportedRelayRuntime,
+}
+
+impl From<&Block> for BlockRecord {
+    fn from(block: &Block) -> Self {
+        Self {
+            block_number: block.block_number,
+            corespace: block.corespace.clone(),
+            runtime: block.runtime,
+        }
+    }
+}
+
+impl From<&BlockRecord> for Block {
+    fn from(record: &BlockRecord) -> Self {
+        Block::new(
+            record.block_number,
+            record.corespace.clone(),
+            record.runtime,
+        )
+    }
+}
+
+/// Recording holds a sequence of blocks in the order they were received, serialized as JSON lines
+/// (one `BlockRecord` per line).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Recording {
+    records: Vec<BlockRecord>,
+}
+
+impl Recording {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a recording from JSON lines, empty lines are ignored.
+    pub fn from_json_lines(json_lines: &str) -> Result<Self, CorematchError> {
+        let mut recording = Self::new();
+        for (i, line) in json_lines.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: BlockRecord = serde_json::from_str(line).map_err(|err| {
+                CorematchError::Other(format!("Invalid block record at line {}: {err}", i + 1))
+            })?;
+            recording.records.push(record);
+        }
+        Ok(recording)
+    }
+
+    pub fn to_json_lines(&self) -> String {
+        self.records
+            .iter()
+            .filter_map(|record| serde_json::to_string(record).ok())
+            .map(|line| format!("{line}\n"))
+            .collect()
+    }
+
+    /// Append a block to the recording, blocks already recorded are ignored.
+    pub fn push(&mut self, block: &Block) {
+        if self
+            .records
+            .last()
+            .is_some_and(|record| block.block_number <= record.block_number)
+        {
+            return;
+        }
+        self.records.push(block.into());
+    }
+
+    pub fn records(&self) -> &[BlockRecord] {
+        &self.records
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// The runtime the blocks were recorded from.
+    pub fn runtime(&self) -> Option<SupportedRelayRuntime> {
+        self.records.first().map(|record| record.runtime)
+    }
+
+    /// All para ids found in the recorded corespace, sorted.
+    pub fn para_ids(&self) -> ParachainIds {
+        self.records
+            .iter()
+            .flat_map(|record| record.corespace.iter().filter_map(|core| core.para_id))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the blocks to be sent at each tick of a replay. As with a live subscription, the
+    /// first tick fills up a board of `total_blocks` cells and every following tick sends a
+    /// single block.
+    pub fn ticks(&self, total_blocks: u32) -> impl Iterator<Item = &[BlockRecord]> {
+        let first_tick = self.records.len().min(total_blocks as usize + 1);
+        let (first, others) = self.records.split_at(first_tick);
+        std::iter::once(first)
+            .filter(|records| !records.is_empty())
+            .chain(others.chunks(1))
+    }
+}
+
+/// RecordingSource wraps a relay chain source and records every corespace fetched from it.
+pub struct RecordingSource {
+    source: Rc<dyn RelayChainSource>,
+    recording: Rc<RefCell<Recording>>,
+}
+
+impl RecordingSource {
+    pub fn new(source: Rc<dyn RelayChainSource>, recording: Rc<RefCell<Recording>>) -> Self {
+        Self { source, recording }
+    }
+}
+
+impl RelayChainSource for RecordingSource {
+    fn runtime(&self) -> SupportedRelayRuntime {
+        self.source.runtime()
+    }
+
+    fn fetch_para_ids<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+    ) -> SourceFuture<'a, ParachainIds> {
+        self.source.fetch_para_ids(api)
+    }
+
+    fn fetch_block_hash_at<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_number: BlockNumber,
+        at: H256,
+    ) -> SourceFuture<'a, H256> {
+        self.source.fetch_block_hash_at(api, block_number, at)
+    }
+
+    fn fetch_timestamp<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_hash: H256,
+    ) -> SourceFuture<'a, u64> {
+        self.source.fetch_timestamp(api, block_hash)
+    }
+
+    fn fetch_corespace<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_number: BlockNumber,
+        block_hash: H256,
+    ) -> SourceFuture<'a, Block> {
+        async move {
+            let block = self
+                .source
+                .fetch_corespace(api, block_number, block_hash)
+                .await?;
+            self.recording.borrow_mut().push(&block);
+            Ok(block)
+        }
+        .boxed_local()
+    }
+}
+
+/// ReplaySource plays a recording back as if the blocks were being finalized, either at the
+/// network pace of one block every six seconds or accelerated by the given speed.
+pub struct ReplaySource {
+    recording: Recording,
+    speed: u32,
+    total_blocks: u32,
+}
+
+impl ReplaySource {
+    pub fn new(recording: Recording, speed: u32, total_blocks: u32) -> Self {
+        Self {
+            recording,
+            speed: speed.max(1),
+            total_blocks,
+        }
+    }
+
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    /// Time between two consecutive blocks.
+    pub fn interval(&self) -> Duration {
+        SIX_SECS / self.speed
+    }
+
+    /// Plays the recording into the callback, in the same way as a live subscription.
+    pub fn subscribe(
+        self: Rc<Self>,
+        cb: Callback<(SubscriptionId, Block)>,
+    ) -> (SubscriptionId, UnboundedSender<AttrValue>) {
+        let ticks: Vec<Vec<Block>> = self
+            .recording
+            .ticks(self.total_blocks)
+            .map(|records| records.iter().map(Block::from).collect())
+            .collect();
+        subscribe_ticks(ticks.into_iter(), self.interval(), cb)
+    }
+}
@@ -0,0 +1,465 @@
+use crate::components::block::{Block, BlockNumber};
+use crate::errors::CorematchError;
+use crate::runtimes::support::SupportedRelayRuntime;
+use crate::types::network::{ParachainIds, SubscriptionId, STOP_SIGNAL};
+use futures::{
+    future::{select, Either, LocalBoxFuture},
+    FutureExt, StreamExt,
+};
+use log::{error, warn};
+use rand::Rng;
+use std::{ops::Range, rc::Rc, time::Duration};
+use subxt::{
+    backend::{legacy::LegacyRpcMethods, rpc::RpcClient},
+    utils::H256,
+    OnlineClient, PolkadotConfig,
+};
+use yew::{
+    platform::{pinned::mpsc::UnboundedSender, spawn_local, time::sleep},
+    AttrValue, Callback,
+};
+
+/// Time between two finalized blocks.
+pub(crate) const SIX_SECS: Duration = Duration::from_secs(6);
+/// Time without any finalized block after which the subscription is considered stalled.
+const STALL_TIMEOUT: Duration = Duration::from_secs(60);
+/// Number of times the finalized blocks stream is resubscribed before giving up.
+const MAX_RESUBSCRIBE_ATTEMPTS: u32 = 3;
+/// Maximum number of cores displayed in a Cell.
+pub const DEFAULT_TOTAL_CORES: u32 = 64;
+/// Number of past block hashes kept in `System::BlockHash` storage (`BlockHashCount`).
+pub const BLOCK_HASH_COUNT: u32 = 4096;
+
+pub type SourceFuture<'a, T> = LocalBoxFuture<'a, Result<T, CorematchError>>;
+
+/// SubscriptionEvent reports the health of a finalized corespace subscription.
+#[derive(Debug)]
+pub enum SubscriptionEvent {
+    /// The finalized blocks stream ended, errored or stalled and is being resubscribed.
+    Reconnecting,
+    /// The finalized blocks stream is back, missed blocks are being back-filled.
+    Reconnected,
+    /// The finalized blocks stream could not be recovered, the subscription is over.
+    Failed(CorematchError),
+}
+
+/// RelayChainSource holds the runtime specific queries needed to play Corematch on a relay chain.
+///
+/// Each supported relay chain implements it against its own metadata; the finalized corespace
+/// subscription is shared by all of them.
+pub trait RelayChainSource: 'static {
+    /// The relay chain runtime this source decodes.
+    fn runtime(&self) -> SupportedRelayRuntime;
+
+    /// Fetch all para ids registered on the relay chain.
+    fn fetch_para_ids<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+    ) -> SourceFuture<'a, ParachainIds>;
+
+    /// Fetch the block hash of a finalized block number, as kept in storage at the given block hash.
+    ///
+    /// Only the hashes of the `BLOCK_HASH_COUNT` blocks preceding `at` are kept.
+    fn fetch_block_hash_at<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_number: BlockNumber,
+        at: H256,
+    ) -> SourceFuture<'a, H256>;
+
+    /// Fetch the time the block with the given hash was produced, in milliseconds since the unix epoch.
+    fn fetch_timestamp<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_hash: H256,
+    ) -> SourceFuture<'a, u64>;
+
+    /// Fetch the block hash of a recent finalized block number.
+    fn fetch_block_hash<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_number: BlockNumber,
+    ) -> SourceFuture<'a, H256> {
+        async move {
+            let at = api.blocks().at_latest().await?.hash();
+            self.fetch_block_hash_at(api, block_number, at).await
+        }
+        .boxed_local()
+    }
+
+    /// Fetch the block hash of a finalized block number older than the ones kept in storage at
+    /// `latest`, by hopping back through the block hashes kept at past blocks.
+    fn fetch_past_block_hash<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_number: BlockNumber,
+        latest: (BlockNumber, H256),
+    ) -> SourceFuture<'a, H256> {
+        async move {
+            let (latest_block_number, mut at) = latest;
+            if block_number > latest_block_number {
+                return Err(CorematchError::Other(format!(
+                    "Block number {block_number} is not finalized yet"
+                )));
+            }
+            for hop in block_hash_hops(block_number, latest_block_number) {
+                at = self.fetch_block_hash_at(api, hop, at).await?;
+            }
+            Ok(at)
+        }
+        .boxed_local()
+    }
+
+    /// Fetch the corespace of the block with the given hash.
+    fn fetch_corespace<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_number: BlockNumber,
+        block_hash: H256,
+    ) -> SourceFuture<'a, Block>;
+
+    /// Fetch the corespace of a past finalized block number.
+    fn fetch_historical_block<'a>(
+        &'a self,
+        api: &'a OnlineClient<PolkadotConfig>,
+        block_number: BlockNumber,
+    ) -> SourceFuture<'a, Block> {
+        async move {
+            let block_hash = self.fetch_block_hash(api, block_number).await?;
+            self.fetch_corespace(api, block_number, block_hash).await
+        }
+        .boxed_local()
+    }
+
+    /// subscribes to finalized blocks, when a block is received, fetch storage for the block hash and send it via the callback.
+    ///
+    /// If the finalized blocks stream ends, errors or stalls it is resubscribed and the blocks missed in between are
+    /// back-filled, so that blocks are always sent in sequence. Blocks are sent from `last_block_number` onwards if
+    /// given, otherwise the latest `total_blocks` are sent first to fill up the board.
+    fn subscribe_finalized_corespace(
+        self: Rc<Self>,
+        api: OnlineClient<PolkadotConfig>,
+        last_block_number: Option<BlockNumber>,
+        total_blocks: u32,
+        cb: Callback<(SubscriptionId, Block)>,
+        on_event: Callback<(SubscriptionId, SubscriptionEvent)>,
+    ) -> SourceFuture<'static, (SubscriptionId, UnboundedSender<AttrValue>)> {
+        async move {
+            // Create channel so that an unsubscribe signal could be received.
+            let (tx, mut rx) = yew::platform::pinned::mpsc::unbounded::<AttrValue>();
+            // Generate a unique subscription_id
+            let mut rng = rand::thread_rng();
+            let subscription_id = rng.gen::<u32>();
+
+            spawn_local(async move {
+                let mut last_block_number = last_block_number;
+                // number of failed attempts since the last block received
+                let mut attempts: u32 = 0;
+
+                'subscription: loop {
+                    if attempts > 0 {
+                        if attempts > MAX_RESUBSCRIBE_ATTEMPTS {
+                            on_event.emit((
+                                subscription_id,
+                                SubscriptionEvent::Failed(CorematchError::Other(format!(
+                                    "Finalized blocks subscription could not be recovered after {MAX_RESUBSCRIBE_ATTEMPTS} attempts"
+                                ))),
+                            ));
+                            break;
+                        }
+                        sleep(SIX_SECS).await;
+                    }
+
+                    let mut blocks_sub = match api.blocks().subscribe_finalized().await {
+                        Ok(blocks_sub) => blocks_sub,
+                        Err(e) => {
+                            error!("{}", e);
+                            attempts += 1;
+                            continue;
+                        }
+                    };
+
+                    loop {
+                        // 0. wait for the next finalized block, unless the stream stalls
+                        let next = select(
+                            Box::pin(blocks_sub.next()),
+                            Box::pin(sleep(STALL_TIMEOUT)),
+                        )
+                        .await;
+                        let block = match next {
+                            Either::Left((Some(Ok(block)), _)) => block,
+                            Either::Left((Some(Err(e)), _)) => {
+                                error!("{}", e);
+                                break;
+                            }
+                            Either::Left((None, _)) => {
+                                warn!("Finalized blocks subscription has ended");
+                                break;
+                            }
+                            Either::Right(_) => {
+                                warn!(
+                                    "No finalized blocks received in the last {} seconds",
+                                    STALL_TIMEOUT.as_secs()
+                                );
+                                break;
+                            }
+                        };
+
+                        // NOTE: a resubscribed stream might start with blocks already sent
+                        if last_block_number.is_some_and(|n| block.number() <= n) {
+                            continue;
+                        }
+
+                        // 1. verify if there is an unsubscribe signal pending to be processed
+                        if let Some(signal) = rx.next().await {
+                            if signal == STOP_SIGNAL {
+                                break 'subscription;
+                            }
+                        }
+
+                        if attempts > 0 {
+                            attempts = 0;
+                            on_event.emit((subscription_id, SubscriptionEvent::Reconnected));
+                        }
+
+                        // 2. back-fill blocks that have not been processed first
+                        for block_number in blocks_to_backfill(last_block_number, block.number(), total_blocks) {
+                            match self.fetch_historical_block(&api, block_number).await {
+                                Ok(block) => cb.emit((subscription_id, block)),
+                                Err(e) => error!("{}", e),
+                            }
+                        }
+
+                        // 3. process latest block
+                        match self
+                            .fetch_corespace(&api, block.number(), block.hash())
+                            .await
+                        {
+                            Ok(block) => cb.emit((subscription_id, block)),
+                            Err(e) => error!("{}", e),
+                        }
+                        last_block_number = Some(block.number());
+
+                        // NOTE: pause task for six seconds to ensure that data is processed always at the same pace
+                        sleep(SIX_SECS).await;
+                    }
+
+                    // the stream is gone, resubscribe
+                    if attempts == 0 && last_block_number.is_some() {
+                        on_event.emit((subscription_id, SubscriptionEvent::Reconnecting));
+                    }
+                    attempts += 1;
+                }
+            });
+            Ok((subscription_id, tx))
+        }
+        .boxed_local()
+    }
+}
+
+/// Connects to the RPC endpoint and checks that the node is synced and has peers.
+pub async fn connect_healthy_rpc(
+    rpc_url: &str,
+) -> Result<OnlineClient<PolkadotConfig>, CorematchError> {
+    let rpc_client = RpcClient::from_url(rpc_url).await?;
+    let health = LegacyRpcMethods::<PolkadotConfig>::new(rpc_client.clone())
+        .system_health()
+        .await?;
+    if health.is_syncing || (health.should_have_peers && health.peers == 0) {
+        return Err(CorematchError::Other(format!(
+            "Node is not healthy, syncing: {}, peers: {}",
+            health.is_syncing, health.peers
+        )));
+    }
+    Ok(OnlineClient::<PolkadotConfig>::from_rpc_client(rpc_client).await?)
+}
+
+/// Implements `RelayChainSource` for a relay chain source, decoding storage with the code
+/// generated by `#[subxt::subxt]` from the relay chain metadata into the `$node_runtime` module.
+///
+/// Relay chains share the pallets Corematch reads from, so only the generated module and the
+/// runtime tag differ between them. The calling crate must depend on `subxt` and `futures`.
+#[macro_export]
+macro_rules! impl_relay_chain_source {
+    ($source:ident, $node_runtime:ident, $runtime:expr) => {
+        impl $crate::runtimes::source::RelayChainSource for $source {
+            fn runtime(&self) -> $crate::runtimes::support::SupportedRelayRuntime {
+                $runtime
+            }
+
+            fn fetch_para_ids<'a>(
+                &'a self,
+                api: &'a ::subxt::OnlineClient<::subxt::PolkadotConfig>,
+            ) -> $crate::runtimes::source::SourceFuture<'a, $crate::types::network::ParachainIds>
+            {
+                ::futures::FutureExt::boxed_local(async move {
+                    let mut para_ids: $crate::types::network::ParachainIds = Vec::new();
+                    let address = $node_runtime::storage().paras().para_lifecycles_iter();
+                    let mut iter = api.storage().at_latest().await?.iter(address).await?;
+
+                    while let Some(Ok(storage)) = iter.next().await {
+                        para_ids.push($crate::runtimes::utils::get_para_id_from_storage_key(
+                            storage.key_bytes,
+                        ));
+                    }
+                    para_ids.sort();
+                    Ok(para_ids)
+                })
+            }
+
+            fn fetch_block_hash_at<'a>(
+                &'a self,
+                api: &'a ::subxt::OnlineClient<::subxt::PolkadotConfig>,
+                block_number: $crate::components::block::BlockNumber,
+                at: ::subxt::utils::H256,
+            ) -> $crate::runtimes::source::SourceFuture<'a, ::subxt::utils::H256> {
+                ::futures::FutureExt::boxed_local(async move {
+                    let block_hash_addr =
+                        $node_runtime::storage().system().block_hash(block_number);
+                    api.storage().at(at).fetch(&block_hash_addr).await?.ok_or(
+                        $crate::errors::CorematchError::Other(format!(
+                            "Failed to fetch block_hash for block_number: {block_number}"
+                        )),
+                    )
+                })
+            }
+
+            fn fetch_timestamp<'a>(
+                &'a self,
+                api: &'a ::subxt::OnlineClient<::subxt::PolkadotConfig>,
+                block_hash: ::subxt::utils::H256,
+            ) -> $crate::runtimes::source::SourceFuture<'a, u64> {
+                ::futures::FutureExt::boxed_local(async move {
+                    let timestamp_addr = $node_runtime::storage().timestamp().now();
+                    api.storage()
+                        .at(block_hash)
+                        .fetch(&timestamp_addr)
+                        .await?
+                        .ok_or($crate::errors::CorematchError::Other(format!(
+                            "Failed to fetch timestamp for block_hash: {block_hash}"
+                        )))
+                })
+            }
+
+            fn fetch_corespace<'a>(
+                &'a self,
+                api: &'a ::subxt::OnlineClient<::subxt::PolkadotConfig>,
+                block_number: $crate::components::block::BlockNumber,
+                block_hash: ::subxt::utils::H256,
+            ) -> $crate::runtimes::source::SourceFuture<'a, $crate::components::block::Block> {
+                use $crate::components::core::Core;
+                use $node_runtime::runtime_types::{
+                    polkadot_parachain_primitives::primitives::Id,
+                    polkadot_runtime_parachains::scheduler::common::Assignment,
+                    polkadot_runtime_parachains::scheduler::pallet::CoreOccupied,
+                };
+
+                ::futures::FutureExt::boxed_local(async move {
+                    // Fetch availability_cores
+                    let availability_cores_addr = $node_runtime::storage()
+                        .para_scheduler()
+                        .availability_cores();
+
+                    let availability_cores_option = api
+                        .storage()
+                        .at(block_hash)
+                        .fetch(&availability_cores_addr)
+                        .await?;
+
+                    if let Some(availability_cores) = availability_cores_option {
+                        let mut corespace = availability_cores
+                            .iter()
+                            .enumerate()
+                            .map(|(i, core_occupied)| match core_occupied {
+                                CoreOccupied::Free => Core::new(i, None),
+                                CoreOccupied::Paras(paras_entry) => match &paras_entry.assignment {
+                                    Assignment::Pool {
+                                        para_id: Id(para_id),
+                                        core_index: _,
+                                    } => Core::new(i, Some(*para_id)),
+                                    Assignment::Bulk(Id(para_id)) => Core::new(i, Some(*para_id)),
+                                },
+                            })
+                            .collect::<$crate::components::block::Corespace>();
+
+                        // Note: keep only the predefined number of cores
+                        corespace.truncate($crate::runtimes::source::DEFAULT_TOTAL_CORES as usize);
+
+                        return Ok($crate::components::block::Block::new(
+                            block_number,
+                            corespace,
+                            self.runtime(),
+                        ));
+                    }
+                    Err($crate::errors::CorematchError::Other(format!(
+                        "Failed to fetch availability_cores for block_hash: {block_hash}"
+                    )))
+                })
+            }
+        }
+    };
+}
+
+/// Sends the blocks of each tick to the callback, as an offline alternative to a finalized corespace
+/// subscription. It follows the same handshake, a signal is expected before every tick and
+/// `STOP_SIGNAL` ends it.
+pub fn subscribe_ticks<I>(
+    ticks: I,
+    interval: Duration,
+    cb: Callback<(SubscriptionId, Block)>,
+) -> (SubscriptionId, UnboundedSender<AttrValue>)
+where
+    I: Iterator<Item = Vec<Block>> + 'static,
+{
+    // Create channel so that an unsubscribe signal could be received.
+    let (tx, mut rx) = yew::platform::pinned::mpsc::unbounded::<AttrValue>();
+    // Generate a unique subscription_id
+    let mut rng = rand::thread_rng();
+    let subscription_id = rng.gen::<u32>();
+
+    spawn_local(async move {
+        for blocks in ticks {
+            match rx.next().await {
+                Some(signal) if signal == STOP_SIGNAL => return,
+                None => return,
+                _ => (),
+            }
+            for block in blocks {
+                cb.emit((subscription_id, block));
+            }
+            sleep(interval).await;
+        }
+    });
+
+    (subscription_id, tx)
+}
+
+/// Returns the block numbers missing between the last block sent and the latest finalized block,
+/// only the ones that still fit in the board are relevant.
+pub fn blocks_to_backfill(
+    last_block_number: Option<BlockNumber>,
+    latest_block_number: BlockNumber,
+    total_blocks: u32,
+) -> Range<BlockNumber> {
+    let oldest_block_number = latest_block_number.saturating_sub(total_blocks);
+    let first_block_number = last_block_number
+        .map(|n| n + 1)
+        .unwrap_or(oldest_block_number)
+        .max(oldest_block_number);
+    first_block_number..latest_block_number
+}
+
+/// Returns the block numbers whose hashes are fetched in turn to reach `block_number` from
+/// `latest_block_number`, each one kept in storage at the block of the previous hop.
+pub fn block_hash_hops(
+    block_number: BlockNumber,
+    latest_block_number: BlockNumber,
+) -> Vec<BlockNumber> {
+    let mut hops = Vec::new();
+    let mut at = latest_block_number;
+    while at > block_number {
+        at = at.saturating_sub(BLOCK_HASH_COUNT).max(block_number);
+        hops.push(at);
+    }
+    hops
+}
@@ -1,4 +1,7 @@
+use crate::errors::CorematchError;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use strum_macros::EnumIter;
 use yew::AttrValue;
 
 pub type ChainPrefix = u16;
@@ -9,10 +12,17 @@ pub const POLKADOT_PEOPLE_SPEC: &str =
 pub const KUSAMA_SPEC: &str = include_str!("../../artifacts/chain_specs/kusama.json");
 pub const KUSAMA_PEOPLE_SPEC: &str = include_str!("../../artifacts/chain_specs/kusama_people.json");
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, EnumIter)]
 pub enum SupportedRelayRuntime {
+    #[default]
+    #[serde(alias = "polkadot")]
     Polkadot,
+    #[serde(alias = "kusama")]
     Kusama,
+    #[serde(alias = "westend")]
+    Westend,
+    #[serde(alias = "paseo")]
+    Paseo,
 }
 
 impl SupportedRelayRuntime {
@@ -20,13 +30,37 @@ impl SupportedRelayRuntime {
         match &self {
             Self::Polkadot => 0,
             Self::Kusama => 2,
+            Self::Westend => 42,
+            Self::Paseo => 0,
         }
     }
 
     pub fn default_rpc_url(&self) -> &'static str {
+        self.default_rpc_urls()[0]
+    }
+
+    /// Ordered list of RPC endpoints, the next one is used whenever the current one fails.
+    pub fn default_rpc_urls(&self) -> &'static [&'static str] {
         match &self {
-            Self::Polkadot => "wss://rpc.ibp.network:443/polkadot",
-            Self::Kusama => "wss://rpc.ibp.network:443/kusama",
+            Self::Polkadot => &[
+                "wss://rpc.ibp.network:443/polkadot",
+                "wss://polkadot.dotters.network:443",
+                "wss://rpc.polkadot.io:443",
+            ],
+            Self::Kusama => &[
+                "wss://rpc.ibp.network:443/kusama",
+                "wss://kusama.dotters.network:443",
+                "wss://kusama-rpc.polkadot.io:443",
+            ],
+            Self::Westend => &[
+                "wss://rpc.ibp.network:443/westend",
+                "wss://westend.dotters.network:443",
+                "wss://westend-rpc.polkadot.io:443",
+            ],
+            Self::Paseo => &[
+                "wss://rpc.ibp.network:443/paseo",
+                "wss://paseo.dotters.network:443",
+            ],
         }
     }
 
@@ -34,20 +68,29 @@ impl SupportedRelayRuntime {
         match &self {
             Self::Polkadot => "wss://sys.ibp.network:443/people-polkadot",
             Self::Kusama => "wss://sys.ibp.network:443/people-kusama",
+            Self::Westend => "wss://sys.ibp.network:443/people-westend",
+            Self::Paseo => "wss://sys.ibp.network:443/people-paseo",
         }
     }
 
-    pub fn chain_specs(&self) -> &str {
+    pub fn is_testnet(&self) -> bool {
+        matches!(self, Self::Westend | Self::Paseo)
+    }
+
+    /// Chain specs bundled with the App, testnets are only reachable via RPC.
+    pub fn chain_specs(&self) -> Option<&'static str> {
         match &self {
-            Self::Polkadot => POLKADOT_SPEC,
-            Self::Kusama => KUSAMA_SPEC,
+            Self::Polkadot => Some(POLKADOT_SPEC),
+            Self::Kusama => Some(KUSAMA_SPEC),
+            Self::Westend | Self::Paseo => None,
         }
     }
 
-    pub fn chain_specs_people(&self) -> &str {
+    pub fn chain_specs_people(&self) -> Option<&'static str> {
         match &self {
-            Self::Polkadot => POLKADOT_PEOPLE_SPEC,
-            Self::Kusama => KUSAMA_PEOPLE_SPEC,
+            Self::Polkadot => Some(POLKADOT_PEOPLE_SPEC),
+            Self::Kusama => Some(KUSAMA_PEOPLE_SPEC),
+            Self::Westend | Self::Paseo => None,
         }
     }
 
@@ -55,6 +98,8 @@ impl SupportedRelayRuntime {
         match &self {
             Self::Polkadot => "DOT",
             Self::Kusama => "KSM",
+            Self::Westend => "WND",
+            Self::Paseo => "PAS",
         }
     }
 
@@ -62,6 +107,8 @@ impl SupportedRelayRuntime {
         match &self {
             Self::Polkadot => 10,
             Self::Kusama => 12,
+            Self::Westend => 12,
+            Self::Paseo => 10,
         }
     }
 
@@ -69,10 +116,27 @@ impl SupportedRelayRuntime {
         self.to_string().to_lowercase()
     }
 
+    /// Formats an amount in planck into the runtime unit, e.g. `0.0157 WND`.
+    pub fn format_amount(&self, amount: u128) -> String {
+        // NOTE: amounts are shown with up to 4 decimal places
+        let precision = 4;
+        let decimals = u32::from(self.decimals());
+        let base = 10u128.pow(decimals);
+        let fraction = format!("{:0width$}", amount % base, width = decimals as usize);
+        let fraction = fraction[..precision.min(fraction.len())].trim_end_matches('0');
+        match (amount / base, fraction) {
+            (0, "") if amount > 0 => format!("<0.{}1 {}", "0".repeat(precision - 1), self.unit()),
+            (units, "") => format!("{units} {}", self.unit()),
+            (units, fraction) => format!("{units}.{fraction} {}", self.unit()),
+        }
+    }
+
     pub fn columns_size(&self) -> u32 {
         match &self {
             Self::Polkadot => 8,
             Self::Kusama => 8,
+            Self::Westend => 8,
+            Self::Paseo => 8,
         }
     }
 
@@ -80,44 +144,55 @@ impl SupportedRelayRuntime {
         match &self {
             Self::Polkadot => "@Polkadot #BuildOnPolkadot".to_string(),
             Self::Kusama => "@kusamanetwork #BuildOnKusama".to_string(),
+            Self::Westend => "@Polkadot #Westend #BuildOnPolkadot".to_string(),
+            Self::Paseo => "@PaseoNetwork #Paseo #BuildOnPolkadot".to_string(),
         }
     }
 }
 
-impl From<AttrValue> for SupportedRelayRuntime {
-    fn from(v: AttrValue) -> Self {
-        match v.as_str() {
-            "Polkadot" => Self::Polkadot,
-            "polkadot" => Self::Polkadot,
-            "DOT" => Self::Polkadot,
-            "Kusama" => Self::Kusama,
-            "kusama" => Self::Kusama,
-            "KSM" => Self::Kusama,
-            _ => unimplemented!("Chain prefix not supported"),
+/// Parses the chain name or its token symbol, in any case, e.g. `polkadot`, `Kusama` or `WND`.
+impl FromStr for SupportedRelayRuntime {
+    type Err = CorematchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "polkadot" | "dot" => Ok(Self::Polkadot),
+            "kusama" | "ksm" => Ok(Self::Kusama),
+            "westend" | "wnd" => Ok(Self::Westend),
+            "paseo" | "pas" => Ok(Self::Paseo),
+            _ => Err(CorematchError::Other(format!("Chain {s} not supported"))),
         }
     }
 }
 
-impl From<String> for SupportedRelayRuntime {
-    fn from(v: String) -> Self {
-        match v.as_str() {
-            "Polkadot" => Self::Polkadot,
-            "polkadot" => Self::Polkadot,
-            "DOT" => Self::Polkadot,
-            "Kusama" => Self::Kusama,
-            "kusama" => Self::Kusama,
-            "KSM" => Self::Kusama,
-            _ => unimplemented!("Chain prefix not supported"),
-        }
+impl TryFrom<AttrValue> for SupportedRelayRuntime {
+    type Error = CorematchError;
+
+    fn try_from(v: AttrValue) -> Result<Self, Self::Error> {
+        v.as_str().parse()
     }
 }
 
-impl From<ChainPrefix> for SupportedRelayRuntime {
-    fn from(v: ChainPrefix) -> Self {
+impl TryFrom<String> for SupportedRelayRuntime {
+    type Error = CorematchError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        v.as_str().parse()
+    }
+}
+
+/// NOTE: Paseo shares the Polkadot prefix, so it is never resolved from a chain prefix.
+impl TryFrom<ChainPrefix> for SupportedRelayRuntime {
+    type Error = CorematchError;
+
+    fn try_from(v: ChainPrefix) -> Result<Self, Self::Error> {
         match v {
-            0 => Self::Polkadot,
-            2 => Self::Kusama,
-            _ => unimplemented!("Chain prefix not supported"),
+            0 => Ok(Self::Polkadot),
+            2 => Ok(Self::Kusama),
+            42 => Ok(Self::Westend),
+            _ => Err(CorematchError::Other(format!(
+                "Chain prefix {v} not supported"
+            ))),
         }
     }
 }
@@ -127,29 +202,36 @@ impl std::fmt::Display for SupportedRelayRuntime {
         match self {
             Self::Polkadot => write!(f, "Polkadot"),
             Self::Kusama => write!(f, "Kusama"),
+            Self::Westend => write!(f, "Westend"),
+            Self::Paseo => write!(f, "Paseo"),
         }
     }
 }
 
+/// AssetHub runtimes the Corematch results are minted on.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum SupportedParachainRuntime {
-    AssetHubPolkadot,
-    AssetHubKusama,
+    AssetHubWestend,
 }
 
 impl SupportedParachainRuntime {
+    /// The relay chain the AssetHub is connected to, which also defines its native unit.
+    pub fn relay_runtime(&self) -> SupportedRelayRuntime {
+        match &self {
+            Self::AssetHubWestend => SupportedRelayRuntime::Westend,
+        }
+    }
+
     pub fn default_rpc_url(&self) -> String {
         match &self {
-            Self::AssetHubPolkadot => "wss://sys.ibp.network/westmint".to_string(),
-            Self::AssetHubKusama => "wss://sys.ibp.network/westmint".to_string(),
+            Self::AssetHubWestend => "wss://sys.ibp.network/westmint".to_string(),
         }
     }
 }
 impl std::fmt::Display for SupportedParachainRuntime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::AssetHubPolkadot => write!(f, "AssetHub Polkadot"),
-            Self::AssetHubKusama => write!(f, "AssetHub Polkadot"),
+            Self::AssetHubWestend => write!(f, "AssetHub Westend"),
         }
     }
 }
@@ -1,5 +1,6 @@
 use subxt::backend::legacy::rpc_methods::StorageKey;
 use subxt::config::substrate::AccountId32;
+use subxt::ext::codec::Decode;
 
 pub fn get_para_id_from_storage_key(key: StorageKey) -> u32 {
     let s = &key[key.len() - 4..];
@@ -13,8 +14,22 @@ pub fn get_nft_id_from_storage_key(key: StorageKey) -> u32 {
     u32::from_le_bytes(v)
 }
 
+/// Returns the last key of a `Blake2_128Concat` storage entry, given the length of the key
+/// bytes that precede it.
+pub fn get_last_key_from_storage_key<K: Decode>(key: &[u8], prefix_length: usize) -> Option<K> {
+    let mut encoded = key.get(prefix_length..)?;
+    decode_blake2_128_concat_key(&mut encoded)
+}
+
+/// Decodes the next `Blake2_128Concat` key of a storage entry, advancing over it.
+pub fn decode_blake2_128_concat_key<K: Decode>(encoded: &mut &[u8]) -> Option<K> {
+    // NOTE: Blake2_128Concat keys are the 16 bytes hash followed by the encoded key
+    *encoded = encoded.get(16..)?;
+    K::decode(encoded).ok()
+}
+
 pub fn str(bytes: Vec<u8>) -> String {
-    format!("{}", String::from_utf8(bytes).expect("Data not utf-8"))
+    String::from_utf8(bytes).expect("Data not utf-8")
 }
 
 pub fn compact(account: &AccountId32) -> String {
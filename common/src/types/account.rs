@@ -1,35 +1,153 @@
+use crate::errors::CorematchError;
+use crate::runtimes::asset_hub::EncodedCall;
+use crate::runtimes::metadata_hash::{
+    fetch_metadata_hash, AssetHubConfig, AssetHubExtrinsicParams, CheckMetadataHashParams,
+};
 use crate::runtimes::support::SupportedParachainRuntime;
+use crate::types::score::ScoreRecord;
 use js_sys::Promise;
+use schnorrkel::{derive::ChainCode, ExpansionMode, MiniSecretKey};
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
 use subxt::{
-    ext::codec::{Compact, Encode},
-    OnlineClient, PolkadotConfig,
+    config::{
+        signed_extensions::{
+            ChargeAssetTxPaymentParams, ChargeTransactionPaymentParams, CheckMortalityParams,
+            CheckNonceParams,
+        },
+        ExtrinsicParams,
+    },
+    ext::codec::{Decode, Encode},
+    tx::{PartialExtrinsic, SubmittableExtrinsic},
+    utils::{AccountId32, MultiSignature, H256},
+    OfflineClient, OnlineClient, PolkadotConfig,
 };
-use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 use wasm_bindgen_futures::JsFuture;
 use yew::{classes, function_component, html, AttrValue, Callback, Html, Properties};
 
 use anyhow::anyhow;
-use serde_json::json;
+use serde_json::{json, Value};
 use subxt::utils::Era;
 
+/// Number of blocks a signed extrinsic stays valid for, about 6 minutes on AssetHub.
+pub const DEFAULT_MORTALITY_PERIOD: u64 = 64;
+
 #[derive(Clone, PartialEq)]
 pub enum AccountStatus {
     None,
-    // Requesting access to load accounts from browser extension
-    Requesting,
+    // Waiting user to select which wallet to load accounts from
+    WalletSelection(Vec<Wallet>),
+    // Requesting access to load accounts from the wallet
+    Requesting(Wallet),
     // Waiting user to select which account to use
     Selection(Vec<Account>),
     // Account has been selected
     Selected,
     // Account available and ready to sign results
-    Signing(AttrValue),
+    Signing(MintRequest),
+}
+
+/// MintRequest holds the score to be minted by the selected account.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MintRequest {
+    pub score: ScoreRecord,
+    /// Store the score even if it does not beat the personal best already minted.
+    pub overwrite: bool,
+}
+
+impl MintRequest {
+    pub fn new(score: ScoreRecord) -> Self {
+        Self {
+            score,
+            overwrite: false,
+        }
+    }
 }
 
+/// TxInclusion locates an extrinsic within a block.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TxInclusion {
+    pub block_hash: AttrValue,
+    pub block_number: u32,
+    pub extrinsic_index: u32,
+}
+
+impl std::fmt::Display for TxInclusion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}-{}", self.block_number, self.extrinsic_index)
+    }
+}
+
+/// SigningStatus follows the mint extrinsic from signing until it is finalized or rejected.
 #[derive(Clone, PartialEq, Debug)]
 pub enum SigningStatus {
-    Succeeded,
-    Failed,
+    // Fee and balance checked, waiting for the results to be signed, holds a costs summary
+    Estimated(AttrValue),
+    // Results have been signed via the browser extension
+    Signed,
+    // Extrinsic submitted and broadcast to the network, waiting to be included in a block
+    Broadcast,
+    // Extrinsic included in a best block, which may still be retracted
+    InBlock(TxInclusion),
+    // Extrinsic finalized and dispatched successfully
+    Finalized(TxInclusion),
+    // Extrinsic dropped from the transaction pool, holds the reason
+    Dropped(AttrValue),
+    // Extrinsic rejected as invalid, e.g. bad nonce or signature, holds the reason
+    Invalid(AttrValue),
+    // Extrinsic finalized but its dispatch failed, holds the decoded dispatch error
+    DispatchFailed(TxInclusion, AttrValue),
+    // Signing was cancelled or the extrinsic could not be submitted, holds the reason
+    Failed(AttrValue),
+    // Nothing submitted, the score does not beat the personal best, holds the best summary
+    NotImproved(AttrValue),
+}
+
+impl SigningStatus {
+    pub fn is_finished(&self) -> bool {
+        !matches!(
+            self,
+            Self::Estimated(_) | Self::Signed | Self::Broadcast | Self::InBlock(_)
+        )
+    }
+
+    pub fn is_succeeded(&self) -> bool {
+        matches!(self, Self::Finalized(_))
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Estimated(_) => "signing...",
+            Self::Signed => "signed",
+            Self::Broadcast => "broadcast",
+            Self::InBlock(_) => "in block",
+            Self::Finalized(_) => "minted!",
+            Self::Dropped(_) => "dropped",
+            Self::Invalid(_) => "invalid",
+            Self::DispatchFailed(_, _) => "mint failed",
+            Self::Failed(_) => "mint failed",
+            Self::NotImproved(_) => "no new best",
+        }
+    }
+
+    pub fn details(&self) -> String {
+        match self {
+            Self::Estimated(costs) => format!("{costs} · Sign the results in your wallet"),
+            Self::Signed => "Submitting transaction".to_string(),
+            Self::Broadcast => "Waiting for block inclusion".to_string(),
+            Self::InBlock(inclusion) => {
+                format!("Extrinsic {inclusion} waiting for finalization")
+            }
+            Self::Finalized(inclusion) => format!("Extrinsic {inclusion} finalized"),
+            Self::DispatchFailed(inclusion, reason) => format!("Extrinsic {inclusion} {reason}"),
+            Self::Dropped(reason) | Self::Invalid(reason) | Self::Failed(reason) => {
+                reason.to_string()
+            }
+            Self::NotImproved(best) => format!("Your personal best is {best}"),
+        }
+    }
 }
 
 /// AccountState is a shared state between all components.
@@ -41,12 +159,16 @@ pub struct AccountState {
     pub account: Option<Account>,
     // A callback to handle accounts loaded from browser extension.
     pub accounts_callback: Callback<Vec<Account>>,
-    /// A parachain runtime supported by the App.
+    /// The AssetHub the game results are minted on.
     pub runtime: SupportedParachainRuntime,
     // // A runtime callback to handle data subscribed by the runtime.
     // pub runtime_callback: Callback<(SubscriptionId, Block)>,
     // A callback to handle stored results.
     pub signing_callback: Callback<SigningStatus>,
+    // A callback to handle the scores minted by the account.
+    pub history_callback: Callback<Vec<ScoreRecord>>,
+    /// Mortality and tip of the extrinsics signed.
+    pub signing_options: SigningOptions,
 }
 
 impl AccountState {
@@ -54,6 +176,7 @@ impl AccountState {
         runtime: SupportedParachainRuntime,
         accounts_callback: Callback<Vec<Account>>,
         signing_callback: Callback<SigningStatus>,
+        history_callback: Callback<Vec<ScoreRecord>>,
     ) -> Self {
         Self {
             status: AccountStatus::None,
@@ -61,6 +184,8 @@ impl AccountState {
             runtime,
             accounts_callback,
             signing_callback,
+            history_callback,
+            signing_options: SigningOptions::default(),
         }
     }
 
@@ -71,6 +196,191 @@ impl AccountState {
     pub fn is_available(&self) -> bool {
         self.status == AccountStatus::Selected
     }
+
+    pub fn is_signing(&self) -> bool {
+        matches!(self.status, AccountStatus::Signing(_))
+    }
+}
+
+/// SigningOptions defines how long a signed extrinsic is valid for and the tip paid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigningOptions {
+    /// Number of blocks the extrinsic is valid for, rounded up to a power of two by the era.
+    pub mortality_period: u64,
+    /// Optional tip paid to the block author, in planck.
+    pub tip: Option<u128>,
+}
+
+impl Default for SigningOptions {
+    fn default() -> Self {
+        Self {
+            mortality_period: DEFAULT_MORTALITY_PERIOD,
+            tip: None,
+        }
+    }
+}
+
+/// SigningParams holds the signed extensions data of an extrinsic, shared by the payload signed
+/// via the browser extension and the extrinsic submitted, so that both match.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigningParams {
+    pub nonce: u64,
+    /// Hash of the finalized block the extrinsic mortality is anchored at.
+    pub checkpoint_hash: H256,
+    /// Number of the finalized block the extrinsic mortality is anchored at.
+    pub checkpoint_number: u64,
+    pub mortality_period: u64,
+    pub tip: u128,
+    /// Hash of the runtime metadata checked by `CheckMetadataHash`, the check is disabled if
+    /// not given.
+    pub metadata_hash: Option<H256>,
+}
+
+impl SigningParams {
+    /// Fetch the account nonce and the metadata hash, and anchor the extrinsic at the latest
+    /// finalized block.
+    pub async fn fetch(
+        api: &OnlineClient<PolkadotConfig>,
+        runtime: SupportedParachainRuntime,
+        account_id: &AccountId32,
+        options: &SigningOptions,
+    ) -> Result<Self, CorematchError> {
+        let nonce = api.tx().account_nonce(account_id).await?;
+        let checkpoint = api.blocks().at_latest().await?;
+        let with_metadata_hash = api
+            .metadata()
+            .extrinsic()
+            .signed_extensions()
+            .iter()
+            .any(|e| e.identifier() == "CheckMetadataHash");
+        let metadata_hash = if with_metadata_hash {
+            Some(fetch_metadata_hash(api, runtime).await?)
+        } else {
+            None
+        };
+        Ok(Self {
+            nonce,
+            checkpoint_hash: checkpoint.hash(),
+            checkpoint_number: checkpoint.number().into(),
+            mortality_period: options.mortality_period,
+            tip: options.tip.unwrap_or_default(),
+            metadata_hash,
+        })
+    }
+
+    pub fn era(&self) -> Era {
+        Era::mortal(self.mortality_period, self.checkpoint_number)
+    }
+
+    /// Params used by subxt to build the extrinsic signed with these params.
+    pub fn extrinsic_params(
+        &self,
+    ) -> <AssetHubExtrinsicParams as ExtrinsicParams<AssetHubConfig>>::Params {
+        (
+            (),
+            (),
+            CheckNonceParams(Some(self.nonce)),
+            (),
+            CheckMortalityParams::mortal(
+                self.mortality_period,
+                self.checkpoint_number,
+                self.checkpoint_hash,
+            ),
+            ChargeAssetTxPaymentParams::tip(self.tip),
+            ChargeTransactionPaymentParams::tip(self.tip),
+            CheckMetadataHashParams(self.metadata_hash),
+        )
+    }
+
+    /// Build the extrinsic to be signed with these params.
+    ///
+    /// NOTE: it is built offline with the AssetHubConfig, as the PolkadotConfig of the online
+    /// client does not support the metadata hash, see [`submittable_extrinsic`].
+    pub fn partial_extrinsic(
+        &self,
+        call_data: &[u8],
+        api: &OnlineClient<PolkadotConfig>,
+    ) -> Result<PartialExtrinsic<AssetHubConfig, OfflineClient<AssetHubConfig>>, CorematchError>
+    {
+        let client = OfflineClient::<AssetHubConfig>::new(
+            api.genesis_hash(),
+            api.runtime_version(),
+            api.metadata(),
+        );
+        Ok(client.tx().create_partial_signed_offline(
+            &EncodedCall(call_data.to_vec()),
+            self.extrinsic_params(),
+        )?)
+    }
+
+    /// The payload, as expected by the browser extension `signPayload`, to sign the call data.
+    pub fn signer_payload(
+        &self,
+        call_data: &[u8],
+        account_address: &str,
+        api: &OnlineClient<PolkadotConfig>,
+    ) -> Value {
+        let signed_extensions: Vec<String> = api
+            .metadata()
+            .extrinsic()
+            .signed_extensions()
+            .iter()
+            .map(|e| e.identifier().to_string())
+            .collect();
+        let runtime_version = api.runtime_version();
+        self.signer_payload_with(
+            call_data,
+            account_address,
+            api.genesis_hash(),
+            runtime_version.spec_version,
+            runtime_version.transaction_version,
+            signed_extensions,
+        )
+    }
+
+    /// The payload to be signed, given the chain genesis, runtime versions and signed extensions.
+    pub fn signer_payload_with(
+        &self,
+        call_data: &[u8],
+        account_address: &str,
+        genesis_hash: H256,
+        spec_version: u32,
+        transaction_version: u32,
+        signed_extensions: Vec<String>,
+    ) -> Value {
+        // NOTE: the checkpoint block number is a u32 in the extension payload
+        let block_number = u32::try_from(self.checkpoint_number).unwrap_or(u32::MAX);
+        let with_metadata_hash = signed_extensions
+            .iter()
+            .any(|identifier| identifier == "CheckMetadataHash");
+
+        // These numbers aren't SCALE encoded; their bytes are just converted to hex:
+        let mut payload = json!({
+            "specVersion": to_hex(spec_version.to_be_bytes()),
+            "transactionVersion": to_hex(transaction_version.to_be_bytes()),
+            "address": account_address,
+            "blockHash": encode_then_hex(&self.checkpoint_hash),
+            "blockNumber": to_hex(block_number.to_be_bytes()),
+            "era": encode_then_hex(&self.era()),
+            "genesisHash": encode_then_hex(&genesis_hash),
+            "method": to_hex(call_data),
+            "nonce": to_hex(self.nonce.to_be_bytes()),
+            "signedExtensions": signed_extensions,
+            "tip": to_hex(self.tip.to_be_bytes()),
+            "version": 4,
+        });
+
+        if with_metadata_hash {
+            match self.metadata_hash {
+                Some(metadata_hash) => {
+                    payload["mode"] = json!(1);
+                    payload["metadataHash"] = json!(encode_then_hex(&metadata_hash));
+                }
+                None => payload["mode"] = json!(0),
+            }
+        }
+        payload
+    }
 }
 
 /// Account holds info needed to communicate with browser extension
@@ -90,6 +400,145 @@ impl Account {
     pub fn render(&self, onclick: Callback<()>) -> Html {
         html! { <AccountComponent account={self.clone()} {onclick} /> }
     }
+
+    /// The wallet the account has been loaded from.
+    pub fn wallet(&self) -> Option<Wallet> {
+        Wallet::from_source(&self.source)
+    }
+}
+
+/// Wallet is where accounts are loaded from and extrinsics signed with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter)]
+pub enum Wallet {
+    Talisman,
+    SubWallet,
+    PolkadotJs,
+    // Well-known development accounts signed locally, only available on testnets
+    Dev,
+}
+
+impl Wallet {
+    /// The source name the wallet is injected under, e.g. `window.injectedWeb3["talisman"]`.
+    pub fn source(&self) -> &'static str {
+        match self {
+            Self::Talisman => "talisman",
+            Self::SubWallet => "subwallet-js",
+            Self::PolkadotJs => "polkadot-js",
+            Self::Dev => "dev",
+        }
+    }
+
+    pub fn from_source(source: &str) -> Option<Self> {
+        Self::iter().find(|wallet| wallet.source() == source)
+    }
+
+    pub fn is_injected(&self) -> bool {
+        *self != Self::Dev
+    }
+
+    /// Returns true if accounts can be loaded from the wallet to sign on the given AssetHub,
+    /// given the sources injected in the browser. Dev accounts only sign on testnets.
+    pub fn is_available(&self, runtime: SupportedParachainRuntime, sources: &[String]) -> bool {
+        match self {
+            Self::Dev => runtime.relay_runtime().is_testnet(),
+            _ => sources.iter().any(|source| source == self.source()),
+        }
+    }
+
+    pub fn render(&self, onclick: Callback<()>) -> Html {
+        let onclick = onclick.reform(move |_| ());
+        html! {
+            <div class={classes!("control")}>
+                <div class={classes!("btn-link")} {onclick} >{ self.to_string() }</div>
+            </div>
+        }
+    }
+}
+
+impl std::fmt::Display for Wallet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Talisman => write!(f, "Talisman"),
+            Self::SubWallet => write!(f, "SubWallet"),
+            Self::PolkadotJs => write!(f, "Polkadot{{.js}}"),
+            Self::Dev => write!(f, "Dev accounts"),
+        }
+    }
+}
+
+/// Returns the wallets accounts can be loaded from to sign on the AssetHub the results are
+/// minted on, the injected ones found in the browser and the dev accounts on testnets.
+pub fn available_wallets(runtime: SupportedParachainRuntime) -> Vec<Wallet> {
+    let sources: Vec<String> = js_get_injected_sources()
+        .as_string()
+        .and_then(|sources| serde_json::from_str(&sources).ok())
+        .unwrap_or_default();
+    Wallet::iter()
+        .filter(|wallet| wallet.is_available(runtime, &sources))
+        .collect()
+}
+
+/// Names of the development accounts, each derived as `//Name` from the dev phrase.
+pub const DEV_ACCOUNTS: [&str; 6] = ["Alice", "Bob", "Charlie", "Dave", "Eve", "Ferdie"];
+
+// Mini secret key of the Substrate dev phrase
+// "bottom drive obey lake curtain smoke basket hold race lonely fit walk"
+const DEV_PHRASE_SEED: &str = "fac7959dbfe72f052e5a0c3c8d6530f202b02fd8f9f5ca3580ec8deb7797479e";
+
+/// DevSigner signs extrinsics locally with a development sr25519 key, as `subkey` derives it
+/// from a dev seed like `//Alice`, so that minting can be tested without a browser extension.
+pub struct DevSigner {
+    keypair: schnorrkel::Keypair,
+}
+
+impl DevSigner {
+    /// Derive the key of the dev account `//name`.
+    pub fn new(name: &str) -> Result<Self, CorematchError> {
+        let seed =
+            hex::decode(DEV_PHRASE_SEED).map_err(|err| CorematchError::Other(err.to_string()))?;
+        let root = MiniSecretKey::from_bytes(&seed)
+            .map_err(|err| CorematchError::Other(err.to_string()))?
+            .expand(ExpansionMode::Ed25519);
+        // NOTE: hard junctions are the SCALE encoded name, hashed if longer than a chain code
+        let mut chain_code = [0u8; 32];
+        let junction = name.encode();
+        if junction.len() > chain_code.len() {
+            chain_code = sp_core_hashing::blake2_256(&junction);
+        } else {
+            chain_code[..junction.len()].copy_from_slice(&junction);
+        }
+        let (mini_secret_key, _) =
+            root.hard_derive_mini_secret_key(Some(ChainCode(chain_code)), b"");
+        Ok(Self {
+            keypair: mini_secret_key.expand_to_keypair(ExpansionMode::Ed25519),
+        })
+    }
+
+    pub fn account_id(&self) -> AccountId32 {
+        AccountId32(self.keypair.public.to_bytes())
+    }
+
+    /// Sign the payload of an extrinsic, see [`subxt::tx::PartialExtrinsic::signer_payload`].
+    pub fn sign(&self, signer_payload: &[u8]) -> MultiSignature {
+        let signature = self.keypair.sign_simple(b"substrate", signer_payload);
+        MultiSignature::Sr25519(signature.to_bytes())
+    }
+}
+
+/// The development accounts, as if loaded from a wallet.
+pub fn dev_accounts() -> Vec<Account> {
+    DEV_ACCOUNTS
+        .iter()
+        .filter_map(|name| {
+            let signer = DevSigner::new(name).ok()?;
+            Some(Account {
+                name: name.to_string(),
+                source: Wallet::Dev.source().to_string(),
+                ty: "sr25519".to_string(),
+                address: signer.account_id().to_string(),
+            })
+        })
+        .collect()
 }
 
 #[derive(Properties, PartialEq)]
@@ -112,15 +561,20 @@ pub fn account(props: &Props) -> Html {
 // Import functionality from JS
 #[wasm_bindgen]
 extern "C" {
+    #[wasm_bindgen(js_name = getInjectedSources)]
+    pub fn js_get_injected_sources() -> JsValue;
     #[wasm_bindgen(js_name = getAccounts)]
-    pub fn js_get_accounts() -> Promise;
+    pub fn js_get_accounts(source: String) -> Promise;
     #[wasm_bindgen(js_name = signPayload)]
     pub fn js_sign_payload(payload: String, source: String, address: String) -> Promise;
 }
 
-// fetch PJS accounts
-pub async fn get_accounts() -> Result<Vec<Account>, anyhow::Error> {
-    let result = JsFuture::from(js_get_accounts())
+/// Fetch the accounts of the wallet, enabling the wallet extension if needed.
+pub async fn get_accounts(wallet: Wallet) -> Result<Vec<Account>, anyhow::Error> {
+    if !wallet.is_injected() {
+        return Ok(dev_accounts());
+    }
+    let result = JsFuture::from(js_get_accounts(wallet.source().to_string()))
         .await
         .map_err(|js_err| anyhow!("{js_err:?}"))?;
     let accounts_str = result
@@ -142,49 +596,17 @@ fn encode_then_hex<E: Encode>(input: &E) -> String {
 /// https://github.com/paritytech/subxt/blob/master/examples/wasm-example/src/services.rs#L121
 /// communicates with JavaScript to obtain a signature for the `partial_extrinsic` via a browser extension (e.g. polkadot-js or Talisman)
 ///
-/// Some parameters are hard-coded here and not taken from the partial_extrinsic itself (mortality_checkpoint, era, tip).
+/// The extrinsic submitted must be built from the same `params`, see [`SigningParams::extrinsic_params`].
 pub async fn extension_signature_for_extrinsic(
     call_data: &[u8],
     api: &OnlineClient<PolkadotConfig>,
-    account_nonce: u64,
+    params: &SigningParams,
     account_source: String,
     account_address: String,
 ) -> Result<Vec<u8>, anyhow::Error> {
-    let genesis_hash = encode_then_hex(&api.genesis_hash());
-    // These numbers aren't SCALE encoded; their bytes are just converted to hex:
-    let spec_version = to_hex(&api.runtime_version().spec_version.to_be_bytes());
-    let transaction_version = to_hex(&api.runtime_version().transaction_version.to_be_bytes());
-    let nonce = to_hex(&account_nonce.to_be_bytes());
-    // If you construct a mortal transaction, then this block hash needs to correspond
-    // to the block number passed to `Era::mortal()`.
-    let mortality_checkpoint = encode_then_hex(&api.genesis_hash());
-    let era = encode_then_hex(&Era::Immortal);
-    let method = to_hex(call_data);
-    let signed_extensions: Vec<String> = api
-        .metadata()
-        .extrinsic()
-        .signed_extensions()
-        .iter()
-        .map(|e| e.identifier().to_string())
-        .collect();
-    let tip = encode_then_hex(&Compact(0u128));
-
-    let payload = json!({
-        "specVersion": spec_version,
-        "transactionVersion": transaction_version,
-        "address": account_address,
-        "blockHash": mortality_checkpoint,
-        "blockNumber": "0x00000000",
-        "era": era,
-        "genesisHash": genesis_hash,
-        "method": method,
-        "nonce": nonce,
-        "signedExtensions": signed_extensions,
-        "tip": tip,
-        "version": 4,
-    });
-
-    let payload = payload.to_string();
+    let payload = params
+        .signer_payload(call_data, &account_address, api)
+        .to_string();
     let result = JsFuture::from(js_sign_payload(payload, account_source, account_address))
         .await
         .map_err(|js_err| anyhow!("{js_err:?}"))?;
@@ -194,3 +616,42 @@ pub async fn extension_signature_for_extrinsic(
     let signature = hex::decode(&signature[2..])?;
     Ok(signature)
 }
+
+/// Sign the extrinsic with the wallet the account has been loaded from.
+pub async fn sign_extrinsic(
+    partial_extrinsic: &PartialExtrinsic<AssetHubConfig, OfflineClient<AssetHubConfig>>,
+    call_data: &[u8],
+    api: &OnlineClient<PolkadotConfig>,
+    params: &SigningParams,
+    account: &Account,
+) -> Result<MultiSignature, anyhow::Error> {
+    match account.wallet() {
+        Some(Wallet::Dev) => {
+            let signer = DevSigner::new(&account.name)?;
+            Ok(signer.sign(&partial_extrinsic.signer_payload()))
+        }
+        _ => {
+            let signature = extension_signature_for_extrinsic(
+                call_data,
+                api,
+                params,
+                account.source.clone(),
+                account.address.clone(),
+            )
+            .await?;
+            Ok(MultiSignature::decode(&mut &signature[..])?)
+        }
+    }
+}
+
+/// Apply the signature to the extrinsic, so that it can be submitted with the online client.
+pub fn submittable_extrinsic(
+    partial_extrinsic: &PartialExtrinsic<AssetHubConfig, OfflineClient<AssetHubConfig>>,
+    api: &OnlineClient<PolkadotConfig>,
+    account_id: AccountId32,
+    signature: &MultiSignature,
+) -> SubmittableExtrinsic<PolkadotConfig, OnlineClient<PolkadotConfig>> {
+    let extrinsic =
+        partial_extrinsic.sign_with_address_and_signature(&account_id.into(), signature);
+    SubmittableExtrinsic::from_bytes(api.clone(), extrinsic.into_encoded())
+}
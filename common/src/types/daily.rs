@@ -0,0 +1,137 @@
+use crate::runtimes::support::SupportedRelayRuntime;
+use serde::{Deserialize, Serialize};
+
+const MILLIS_PER_DAY: u64 = 86_400_000;
+
+/// Day identifies a daily challenge, as the number of days since the unix epoch (UTC).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize)]
+pub struct Day(pub u32);
+
+impl Day {
+    /// The day the given time falls on, in milliseconds since the unix epoch.
+    pub fn from_unix_millis(millis: u64) -> Self {
+        Self((millis / MILLIS_PER_DAY) as u32)
+    }
+
+    /// The time the day starts at, in milliseconds since the unix epoch.
+    pub fn start_millis(&self) -> u64 {
+        u64::from(self.0) * MILLIS_PER_DAY
+    }
+
+    pub fn previous(&self) -> Self {
+        Self(self.0.saturating_sub(1))
+    }
+
+    /// The calendar date of the day as (year, month, day).
+    pub fn date(&self) -> (i32, u32, u32) {
+        // NOTE: days to civil date, from http://howardhinnant.github.io/date_algorithms.html
+        let z = i64::from(self.0) + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        (year, month, day)
+    }
+}
+
+impl std::fmt::Display for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (year, month, day) = self.date();
+        write!(f, "{year:04}-{month:02}-{day:02}")
+    }
+}
+
+/// DailyResult is the best outcome of the daily challenge of a day.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct DailyResult {
+    pub day: Day,
+    pub points: u32,
+    /// Number of pairs matched.
+    pub matched: u32,
+}
+
+impl DailyResult {
+    /// The result card shared once the challenge is over.
+    pub fn share_message(&self, runtime: SupportedRelayRuntime, streak: u32) -> String {
+        let data = [
+            format!("corematch.xyz daily challenge {} 📅", self.day),
+            format!(
+                "{} points · {} matches · {} day streak 🔥\n",
+                self.points, self.matched, streak
+            ),
+            runtime.hashtag(),
+        ];
+        data.join("\n")
+    }
+}
+
+/// DailyHistory keeps the best result of every daily challenge played, so that streaks can be
+/// tracked.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct DailyHistory {
+    results: Vec<DailyResult>,
+}
+
+impl DailyHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep the result, unless a better one has already been recorded for the same day.
+    pub fn record(&mut self, result: DailyResult) {
+        match self.results.binary_search_by_key(&result.day, |r| r.day) {
+            Ok(i) => {
+                if result.points > self.results[i].points {
+                    self.results[i] = result;
+                }
+            }
+            Err(i) => self.results.insert(i, result),
+        }
+    }
+
+    pub fn result(&self, day: Day) -> Option<&DailyResult> {
+        self.results
+            .binary_search_by_key(&day, |r| r.day)
+            .ok()
+            .map(|i| &self.results[i])
+    }
+
+    /// Number of consecutive days played up to today. A streak is not lost until today is over,
+    /// so if today has not been played yet it is counted up to yesterday.
+    pub fn streak(&self, today: Day) -> u32 {
+        let mut day = if self.result(today).is_some() {
+            today
+        } else {
+            today.previous()
+        };
+        let mut streak = 0;
+        while self.result(day).is_some() {
+            streak += 1;
+            if day.0 == 0 {
+                break;
+            }
+            day = day.previous();
+        }
+        streak
+    }
+
+    /// The longest run of consecutive days played.
+    pub fn best_streak(&self) -> u32 {
+        let mut best = 0;
+        let mut streak = 0;
+        let mut last_day: Option<Day> = None;
+        for result in self.results.iter() {
+            streak = match last_day {
+                Some(day) if day.0 + 1 == result.day.0 => streak + 1,
+                _ => 1,
+            };
+            best = best.max(streak);
+            last_day = Some(result.day);
+        }
+        best
+    }
+}
@@ -1,6 +1,14 @@
 use crate::components::block::BlockView;
 use crate::components::core::CoreView;
+use crate::engine::{
+    DAILY_CHALLENGE_BLOCKS, DEFAULT_BASE_POINTS, DEFAULT_BOARD_COLUMNS, TIME_ATTACK_BLOCKS,
+};
+use crate::errors::CorematchError;
 use crate::types::network::ParachainColors;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum BoardStatus {
@@ -12,7 +20,7 @@ pub enum BoardStatus {
     Leaderboard,
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum GameStatus {
     Init,
     // Ready: // TODO: after initial blocks loaded change status to Ready (game should be playable now)
@@ -40,22 +48,27 @@ impl std::fmt::Display for GameStatus {
     }
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum GameLevel {
+    Level0,
     Level1,
     Level2,
+    Level3,
 }
 
 impl GameLevel {
     pub fn block_view(&self) -> BlockView {
         match &self {
+            Self::Level0 => BlockView::Palette,
             Self::Level1 => BlockView::Cores,
             Self::Level2 => BlockView::Cores,
+            Self::Level3 => BlockView::Cores,
         }
     }
 
     pub fn core_view(&self, opt: Option<ParachainColors>) -> CoreView {
         match &self {
+            Self::Level0 => CoreView::NotApplicable,
             Self::Level1 => CoreView::Binary,
             Self::Level2 => {
                 if let Some(colors) = opt {
@@ -64,27 +77,62 @@ impl GameLevel {
                     CoreView::NotApplicable
                 }
             }
+            Self::Level3 => {
+                if let Some(colors) = opt {
+                    CoreView::Pattern(colors)
+                } else {
+                    CoreView::NotApplicable
+                }
+            }
         }
     }
 
+    /// Points to collect before moving to the next level.
     pub fn collected_points_per_level_minimum(&self) -> u32 {
         match &self {
+            Self::Level0 => 16,
             Self::Level1 => 32,
-            _ => unimplemented!(),
+            Self::Level2 => 128,
+            // NOTE: the last level can not be completed
+            Self::Level3 => u32::MAX,
+        }
+    }
+
+    /// The level unlocked once this one is completed, if any.
+    pub fn next(&self) -> Option<GameLevel> {
+        match &self {
+            Self::Level0 => Some(Self::Level1),
+            Self::Level1 => Some(Self::Level2),
+            Self::Level2 => Some(Self::Level3),
+            Self::Level3 => None,
         }
     }
 
     pub fn match_x_position(&self) -> u32 {
         match &self {
+            Self::Level0 => 0,
             Self::Level1 => 3,
             Self::Level2 => 0,
+            Self::Level3 => 0,
+        }
+    }
+
+    /// The level number, as stored in score records.
+    pub fn number(&self) -> u8 {
+        match &self {
+            Self::Level0 => 0,
+            Self::Level1 => 1,
+            Self::Level2 => 2,
+            Self::Level3 => 3,
         }
     }
 
     pub fn class(&self) -> String {
         match &self {
+            Self::Level0 => "level__0".to_string(),
             Self::Level1 => "level__1".to_string(),
             Self::Level2 => "level__2".to_string(),
+            Self::Level3 => "level__3".to_string(),
         }
     }
 }
@@ -92,13 +140,154 @@ impl GameLevel {
 impl std::fmt::Display for GameLevel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Level0 => write!(f, "Level 0"),
             Self::Level1 => write!(f, "Level 1"),
             Self::Level2 => write!(f, "Level 2"),
+            Self::Level3 => write!(f, "Level 3"),
+        }
+    }
+}
+
+/// GameMode defines how a game ends and is scored, e.g. `?game=time-attack`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, EnumIter)]
+#[serde(rename_all = "kebab-case")]
+pub enum GameMode {
+    /// The game is over once all tries are lost.
+    #[default]
+    Classic,
+    /// Collect as many points as possible while a fixed number of blocks is finalized.
+    TimeAttack,
+    /// No tries, no points, matches are always highlighted and the game only ends when stopped.
+    Zen,
+    /// Time Attack played on the blocks finalized right after the day started, the same for
+    /// every player.
+    DailyChallenge,
+}
+
+impl GameMode {
+    /// Misses only cost a try in Classic, the other modes end on their own terms.
+    pub fn has_tries(&self) -> bool {
+        *self == Self::Classic
+    }
+
+    pub fn has_points(&self) -> bool {
+        *self != Self::Zen
+    }
+
+    /// The game runs until it is stopped by the player.
+    pub fn is_endless(&self) -> bool {
+        *self == Self::Zen
+    }
+
+    /// Number of finalized blocks a game lasts, if limited.
+    pub fn duration_limit(&self) -> Option<u32> {
+        match self {
+            Self::TimeAttack => Some(TIME_ATTACK_BLOCKS),
+            Self::DailyChallenge => Some(DAILY_CHALLENGE_BLOCKS),
+            _ => None,
+        }
+    }
+
+    /// The mode switched to next, cycling through all of them.
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Classic => Self::TimeAttack,
+            Self::TimeAttack => Self::Zen,
+            Self::Zen => Self::DailyChallenge,
+            Self::DailyChallenge => Self::Classic,
+        }
+    }
+
+    pub fn class(&self) -> String {
+        match self {
+            Self::Classic => "mode__classic".to_string(),
+            Self::TimeAttack => "mode__time_attack".to_string(),
+            Self::Zen => "mode__zen".to_string(),
+            Self::DailyChallenge => "mode__daily_challenge".to_string(),
         }
     }
 }
 
-#[derive(Clone, PartialEq)]
+impl std::fmt::Display for GameMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Classic => write!(f, "Classic"),
+            Self::TimeAttack => write!(f, "Time Attack"),
+            Self::Zen => write!(f, "Zen"),
+            Self::DailyChallenge => write!(f, "Daily Challenge"),
+        }
+    }
+}
+
+/// BoardSize defines how many cells the board has, e.g. `?board=4x4`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, EnumIter)]
+pub enum BoardSize {
+    #[serde(rename = "2x2")]
+    Small,
+    #[default]
+    #[serde(rename = "3x3")]
+    Medium,
+    #[serde(rename = "4x4")]
+    Large,
+}
+
+impl BoardSize {
+    pub fn columns(&self) -> u8 {
+        match self {
+            Self::Small => 2,
+            Self::Medium => DEFAULT_BOARD_COLUMNS,
+            Self::Large => 4,
+        }
+    }
+
+    /// Number of cells on the board, i.e. the number of finalized blocks in play.
+    pub fn total_blocks(&self) -> u32 {
+        u32::from(self.columns()).pow(2)
+    }
+
+    /// Points of a first match, doubled for every following match in a row.
+    ///
+    /// A bigger board holds more blocks to remember, so matches are worth more.
+    pub fn base_points(&self) -> u32 {
+        match self {
+            Self::Small => DEFAULT_BASE_POINTS / 2,
+            Self::Medium => DEFAULT_BASE_POINTS,
+            Self::Large => DEFAULT_BASE_POINTS * 2,
+        }
+    }
+
+    /// The size switched to next, cycling through all of them.
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Small => Self::Medium,
+            Self::Medium => Self::Large,
+            Self::Large => Self::Small,
+        }
+    }
+
+    pub fn class(&self) -> String {
+        format!("board__{self}")
+    }
+}
+
+impl std::fmt::Display for BoardSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{0}x{0}", self.columns())
+    }
+}
+
+/// Parses the size as displayed, e.g. `4x4`.
+impl FromStr for BoardSize {
+    type Err = CorematchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|board_size| board_size.to_string() == s.to_lowercase())
+            .ok_or_else(|| CorematchError::Other(format!("Board {s} not supported")))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum GameHelpStatus {
     On,
     NotAvailable,
@@ -0,0 +1,86 @@
+use crate::runtimes::support::SupportedRelayRuntime;
+use crate::runtimes::utils::compact;
+use crate::types::score::ScoreRecord;
+use subxt::utils::AccountId32;
+
+/// Number of best scores ranked per network and level.
+pub const LEADERBOARD_TOP_N: usize = 100;
+/// Number of ranked scores shown per page.
+pub const LEADERBOARD_PAGE_SIZE: usize = 10;
+
+/// LeaderboardEntry is a score minted in the Corematch collection, with the player who set it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeaderboardEntry {
+    pub account: AccountId32,
+    pub score: ScoreRecord,
+}
+
+/// Leaderboard holds all the scores minted in the Corematch collection.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Leaderboard {
+    entries: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    pub fn new(entries: Vec<LeaderboardEntry>) -> Self {
+        Self { entries }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The top scores of the network and level, best first.
+    ///
+    /// Scores with the same points and duration are ranked by the earliest block matched.
+    pub fn ranking(&self, network: SupportedRelayRuntime, level: u8) -> Vec<&LeaderboardEntry> {
+        let mut ranking: Vec<&LeaderboardEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.score.network == network && entry.score.level == level)
+            .collect();
+        ranking.sort_by(|a, b| {
+            b.score
+                .points
+                .cmp(&a.score.points)
+                .then(a.score.duration.cmp(&b.score.duration))
+                .then(a.score.block_number.cmp(&b.score.block_number))
+        });
+        ranking.truncate(LEADERBOARD_TOP_N);
+        ranking
+    }
+
+    /// The ranked entries shown in the given page, starting at 0, along with their rank.
+    pub fn page(
+        &self,
+        network: SupportedRelayRuntime,
+        level: u8,
+        page: usize,
+    ) -> Vec<(usize, &LeaderboardEntry)> {
+        self.ranking(network, level)
+            .into_iter()
+            .enumerate()
+            .skip(page * LEADERBOARD_PAGE_SIZE)
+            .take(LEADERBOARD_PAGE_SIZE)
+            .map(|(i, entry)| (i + 1, entry))
+            .collect()
+    }
+
+    /// Number of pages of the network and level ranking.
+    pub fn pages(&self, network: SupportedRelayRuntime, level: u8) -> usize {
+        self.ranking(network, level)
+            .len()
+            .div_ceil(LEADERBOARD_PAGE_SIZE)
+    }
+}
+
+impl LeaderboardEntry {
+    /// The name shown for the player, the compact address unless a name is known.
+    pub fn display_name(&self, known_names: &[(AccountId32, String)]) -> String {
+        known_names
+            .iter()
+            .find(|(account, _)| *account == self.account)
+            .map(|(_, name)| name.clone())
+            .unwrap_or_else(|| compact(&self.account))
+    }
+}
@@ -1,3 +1,6 @@
 pub mod account;
+pub mod daily;
 pub mod game;
+pub mod leaderboard;
 pub mod network;
+pub mod score;
@@ -1,7 +1,12 @@
-use crate::components::block::Block;
-use crate::runtimes::support::SupportedRelayRuntime;
+use crate::components::block::{Block, BlockNumber};
+use crate::runtimes::{
+    practice::PracticeConfig, replay::Recording, support::SupportedRelayRuntime,
+};
+use crate::types::daily::Day;
+use crate::types::game::BoardSize;
 use rand::Rng;
-use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 use yew::Callback;
 
 pub type ParaId = u32;
@@ -17,10 +22,74 @@ pub const CONTINUE_SIGNAL: &str = "continue";
 pub enum NetworkStatus {
     Initializing,
     Switching,
+    Syncing(SyncState),
     Active,
+    Reconnecting,
     Inactive,
 }
 
+/// ConnectionMode defines how the App connects to the relay chain.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+pub enum ConnectionMode {
+    /// Connect to the default RPC endpoint of the runtime.
+    #[default]
+    #[serde(alias = "rpc")]
+    Rpc,
+    /// Sync an embedded smoldot light client from the bundled chain specs.
+    #[serde(alias = "light_client", alias = "lightclient", alias = "lc")]
+    LightClient,
+}
+
+impl std::fmt::Display for ConnectionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rpc => write!(f, "RPC"),
+            Self::LightClient => write!(f, "Light Client"),
+        }
+    }
+}
+
+/// SyncState reports the progress of the light client while it syncs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncState {
+    /// Number of connected peers.
+    pub peers: usize,
+    /// Best block number known by the light client.
+    pub best_block_number: BlockNumber,
+}
+
+/// Replay defines a recording to be played instead of subscribing to the relay chain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replay {
+    /// Location of the JSON lines recording.
+    pub url: String,
+    /// How many times faster than the network the blocks are played.
+    pub speed: u32,
+}
+
+/// Daily defines the daily challenge played instead of the latest finalized blocks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Daily {
+    /// The day of the challenge.
+    pub day: Day,
+    /// Number of times the challenge has been played again, each time from its first block.
+    pub replays: u32,
+}
+
+impl Daily {
+    pub fn new(day: Day) -> Self {
+        Self { day, replays: 0 }
+    }
+
+    /// The challenge played again from its first block, or the one of the new day if it has changed.
+    pub fn replay(&self, today: Day) -> Self {
+        Self {
+            day: today,
+            replays: self.replays + 1,
+        }
+    }
+}
+
 /// NetworkState is a shared state between all components.
 #[derive(Debug, Clone, PartialEq)]
 pub struct NetworkState {
@@ -32,6 +101,22 @@ pub struct NetworkState {
     pub subscription_callback: Callback<SubscriptionId>,
     /// A runtime supported by the App.
     pub runtime: SupportedRelayRuntime,
+    /// How the App connects to the runtime.
+    pub connection_mode: ConnectionMode,
+    /// Ordered list of RPC endpoints to connect to.
+    pub rpc_urls: Vec<String>,
+    /// Recording played instead of connecting to the runtime.
+    pub replay: Option<Replay>,
+    /// Synthetic corespace played instead of connecting to the runtime.
+    pub practice: Option<PracticeConfig>,
+    /// Daily challenge played instead of the latest finalized blocks.
+    pub daily: Option<Daily>,
+    /// Blocks received so far, when recording is enabled.
+    pub recording: Option<Rc<RefCell<Recording>>>,
+    /// Size of the board, which sets how many past blocks are fetched first.
+    pub board_size: BoardSize,
+    // A status callback to report connection changes, e.g. light client sync progress.
+    pub status_callback: Callback<NetworkStatus>,
     // A runtime callback to handle data subscribed by the runtime.
     pub runtime_callback: Callback<(SubscriptionId, Block)>,
     /// A map between parachain_id and color.
@@ -43,15 +128,26 @@ pub struct NetworkState {
 impl NetworkState {
     pub fn new(
         runtime: SupportedRelayRuntime,
+        connection_mode: ConnectionMode,
+        rpc_urls: Vec<String>,
         runtime_callback: Callback<(SubscriptionId, Block)>,
         subscription_callback: Callback<SubscriptionId>,
         parachains_callback: Callback<ParachainIds>,
+        status_callback: Callback<NetworkStatus>,
     ) -> Self {
         Self {
             status: NetworkStatus::Initializing,
             subscription_id: None,
             subscription_callback,
             runtime,
+            connection_mode,
+            rpc_urls,
+            replay: None,
+            practice: None,
+            daily: None,
+            recording: None,
+            board_size: BoardSize::default(),
+            status_callback,
             runtime_callback,
             parachain_colors: BTreeMap::new(),
             parachains_callback,
@@ -70,6 +166,26 @@ impl NetworkState {
         self.status == NetworkStatus::Switching
     }
 
+    pub fn is_reconnecting(&self) -> bool {
+        self.status == NetworkStatus::Reconnecting
+    }
+
+    pub fn is_syncing(&self) -> bool {
+        matches!(self.status, NetworkStatus::Syncing(_))
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.replay.is_some()
+    }
+
+    pub fn is_practicing(&self) -> bool {
+        self.practice.is_some()
+    }
+
+    pub fn is_playing_daily(&self) -> bool {
+        self.daily.is_some()
+    }
+
     pub fn is_valid(&self, id: SubscriptionId) -> bool {
         if let Some(subscription_id) = self.subscription_id {
             self.status == NetworkStatus::Active && subscription_id == id
@@ -89,7 +205,7 @@ pub fn generate_parachain_colors(para_ids: ParachainIds) -> ParachainColors {
     // generate colors
     let mut colors = Vec::<Color>::new();
     for i in 0..n {
-        let hue = 360 / n * i as u32;
+        let hue = 360 / n * i;
         colors.push((hue, 96_u32, 68_u32));
     }
 
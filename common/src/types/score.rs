@@ -0,0 +1,93 @@
+use crate::errors::CorematchError;
+use crate::runtimes::support::SupportedRelayRuntime;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use subxt::utils::H256;
+
+/// Version of the score record layout, bumped whenever a field is changed or removed.
+pub const SCORE_RECORD_VERSION: u8 = 1;
+/// Prefix of the item attribute keys holding Corematch scores.
+pub const SCORE_KEY_PREFIX: &str = "corematch/score";
+/// Maximum length of an item attribute value (`Nfts::ValueLimit` on AssetHub).
+pub const MAX_SCORE_VALUE_LENGTH: usize = 256;
+
+/// ScoreRecord is the result of a game as it is stored on-chain, as a JSON encoded attribute
+/// of the player NFT.
+///
+/// One record is kept per network and level, under the key returned by `attribute_key`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreRecord {
+    #[serde(rename = "v")]
+    pub version: u8,
+    pub network: SupportedRelayRuntime,
+    /// The level reached when the game was over.
+    pub level: u8,
+    pub points: u32,
+    /// Number of finalized blocks the game lasted.
+    pub duration: u32,
+    /// Number of the last block matched.
+    pub block_number: u32,
+    /// Hash of the corespace of the last block matched, as seen at the level reached.
+    pub corespace_hash: H256,
+    pub app_version: String,
+}
+
+impl ScoreRecord {
+    /// The attribute key under which the record is stored, e.g. `corematch/score/westend/2`.
+    pub fn attribute_key(&self) -> String {
+        score_attribute_key(self.network, self.level)
+    }
+
+    /// Encode the record as the attribute value.
+    pub fn encode(&self) -> Result<Vec<u8>, CorematchError> {
+        let value = serde_json::to_vec(self)
+            .map_err(|err| CorematchError::Other(format!("Score could not be encoded: {err}")))?;
+        if value.len() > MAX_SCORE_VALUE_LENGTH {
+            return Err(CorematchError::Other(format!(
+                "Score record is {} bytes long, the limit is {MAX_SCORE_VALUE_LENGTH}",
+                value.len()
+            )));
+        }
+        Ok(value)
+    }
+
+    /// Decode an item attribute, None if it does not hold a Corematch score (e.g. attributes
+    /// minted before score records were versioned).
+    pub fn decode(key: &[u8], value: &[u8]) -> Option<Self> {
+        if !key.starts_with(SCORE_KEY_PREFIX.as_bytes()) {
+            return None;
+        }
+        serde_json::from_slice::<Self>(value)
+            .ok()
+            .filter(|record| record.attribute_key().as_bytes() == key)
+    }
+
+    /// Whether the score is better than the other one: more points, or as many points in
+    /// fewer blocks.
+    pub fn beats(&self, other: &Self) -> bool {
+        (self.points, Reverse(self.duration)) > (other.points, Reverse(other.duration))
+    }
+
+    /// Short description of the score, e.g. `1234 points in 300 blocks`.
+    pub fn summary(&self) -> String {
+        format!("{} points in {} blocks", self.points, self.duration)
+    }
+}
+
+/// Returns the stored score of the same network and level as the given one, if any.
+pub fn personal_best<'a>(
+    scores: &'a [ScoreRecord],
+    score: &ScoreRecord,
+) -> Option<&'a ScoreRecord> {
+    scores
+        .iter()
+        .find(|stored| stored.network == score.network && stored.level == score.level)
+}
+
+/// The attribute key of the score of the given network and level.
+pub fn score_attribute_key(network: SupportedRelayRuntime, level: u8) -> String {
+    format!(
+        "{SCORE_KEY_PREFIX}/{}/{level}",
+        network.to_string().to_lowercase()
+    )
+}
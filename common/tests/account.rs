@@ -0,0 +1,234 @@
+use corematch_common::runtimes::support::SupportedParachainRuntime;
+use corematch_common::types::account::{
+    dev_accounts, DevSigner, SigningOptions, SigningParams, SigningStatus, TxInclusion, Wallet,
+    DEFAULT_MORTALITY_PERIOD, DEV_ACCOUNTS,
+};
+use strum::IntoEnumIterator;
+use subxt::{
+    ext::codec::Encode,
+    utils::{Era, MultiSignature, H256},
+};
+
+fn inclusion() -> TxInclusion {
+    TxInclusion {
+        block_hash: "0x01".into(),
+        block_number: 7_200_123,
+        extrinsic_index: 2,
+    }
+}
+
+#[test]
+fn tx_inclusion_displays_block_number_and_extrinsic_index() {
+    assert_eq!(inclusion().to_string(), "#7200123-2");
+}
+
+#[test]
+fn pending_statuses_are_not_finished() {
+    for status in [
+        SigningStatus::Signed,
+        SigningStatus::Broadcast,
+        SigningStatus::InBlock(inclusion()),
+    ] {
+        assert!(!status.is_finished(), "{status:?}");
+        assert!(!status.is_succeeded(), "{status:?}");
+    }
+}
+
+#[test]
+fn only_finalized_status_is_succeeded() {
+    let failed = [
+        SigningStatus::Dropped("dropped".into()),
+        SigningStatus::Invalid("bad nonce".into()),
+        SigningStatus::DispatchFailed(inclusion(), "Nfts::NoPermission".into()),
+        SigningStatus::Failed("cancelled".into()),
+        SigningStatus::NotImproved("1234 points in 300 blocks".into()),
+    ];
+    for status in failed {
+        assert!(status.is_finished(), "{status:?}");
+        assert!(!status.is_succeeded(), "{status:?}");
+    }
+
+    let finalized = SigningStatus::Finalized(inclusion());
+    assert!(finalized.is_finished());
+    assert!(finalized.is_succeeded());
+}
+
+#[test]
+fn details_locate_the_extrinsic_once_included() {
+    assert_eq!(
+        SigningStatus::Finalized(inclusion()).details(),
+        "Extrinsic #7200123-2 finalized"
+    );
+    assert_eq!(
+        SigningStatus::DispatchFailed(inclusion(), "Dispatch error: Nfts::NoPermission".into())
+            .details(),
+        "Extrinsic #7200123-2 Dispatch error: Nfts::NoPermission"
+    );
+    assert_eq!(
+        SigningStatus::Invalid("bad nonce".into()).details(),
+        "bad nonce"
+    );
+}
+
+fn signing_params() -> SigningParams {
+    SigningParams {
+        nonce: 3,
+        checkpoint_hash: H256::repeat_byte(0xab),
+        checkpoint_number: 7_200_123,
+        mortality_period: DEFAULT_MORTALITY_PERIOD,
+        tip: 0,
+        metadata_hash: None,
+    }
+}
+
+fn signer_payload(params: &SigningParams, signed_extensions: &[&str]) -> serde_json::Value {
+    params.signer_payload_with(
+        &[0x28, 0x00],
+        "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+        H256::repeat_byte(0x01),
+        1_016_000,
+        15,
+        signed_extensions.iter().map(|e| e.to_string()).collect(),
+    )
+}
+
+#[test]
+fn default_signing_options_are_mortal_without_tip() {
+    let options = SigningOptions::default();
+    assert_eq!(options.mortality_period, DEFAULT_MORTALITY_PERIOD);
+    assert_eq!(options.tip, None);
+}
+
+#[test]
+fn signer_payload_is_anchored_at_the_checkpoint() {
+    let params = signing_params();
+    let payload = signer_payload(&params, &["CheckMortality", "CheckNonce"]);
+
+    assert_eq!(payload["blockHash"], format!("0x{}", "ab".repeat(32)));
+    assert_eq!(payload["blockNumber"], "0x006ddd7b");
+    assert_eq!(
+        payload["era"],
+        format!("0x{}", hex::encode(params.era().encode()))
+    );
+    assert_ne!(payload["era"], "0x00");
+    assert_eq!(payload["genesisHash"], format!("0x{}", "01".repeat(32)));
+    assert_eq!(payload["nonce"], "0x0000000000000003");
+}
+
+#[test]
+fn era_is_mortal_for_the_mortality_period() {
+    let params = signing_params();
+    assert_eq!(
+        params.era(),
+        Era::Mortal {
+            period: DEFAULT_MORTALITY_PERIOD,
+            phase: params.checkpoint_number % DEFAULT_MORTALITY_PERIOD,
+        }
+    );
+}
+
+#[test]
+fn signer_payload_includes_the_tip() {
+    let params = SigningParams {
+        tip: 1_000_000,
+        ..signing_params()
+    };
+    let payload = signer_payload(&params, &[]);
+    assert_eq!(payload["tip"], format!("0x{:032x}", 1_000_000u128));
+}
+
+#[test]
+fn signer_payload_disables_metadata_hash_check_without_hash() {
+    let params = signing_params();
+    assert!(signer_payload(&params, &["CheckNonce"])
+        .get("mode")
+        .is_none());
+    let payload = signer_payload(&params, &["CheckNonce", "CheckMetadataHash"]);
+    assert_eq!(payload["mode"], 0);
+    assert!(payload.get("metadataHash").is_none());
+}
+
+#[test]
+fn signer_payload_enables_metadata_hash_check_with_hash() {
+    let params = SigningParams {
+        metadata_hash: Some(H256::repeat_byte(0xcd)),
+        ..signing_params()
+    };
+    assert!(signer_payload(&params, &["CheckNonce"])
+        .get("metadataHash")
+        .is_none());
+    let payload = signer_payload(&params, &["CheckNonce", "CheckMetadataHash"]);
+    assert_eq!(payload["mode"], 1);
+    assert_eq!(payload["metadataHash"], format!("0x{}", "cd".repeat(32)));
+}
+
+#[test]
+fn wallet_sources_round_trip() {
+    for wallet in Wallet::iter() {
+        assert_eq!(Wallet::from_source(wallet.source()), Some(wallet));
+    }
+    assert_eq!(Wallet::from_source("subwallet-js"), Some(Wallet::SubWallet));
+    assert_eq!(Wallet::from_source("enkrypt"), None);
+    assert!(!Wallet::Dev.is_injected());
+}
+
+#[test]
+fn wallets_are_available_if_injected_and_dev_accounts_on_testnets() {
+    // NOTE: the results of every network are minted on AssetHub Westend, whatever the network
+    // played, so dev accounts are always offered
+    let runtime = SupportedParachainRuntime::AssetHubWestend;
+    let injected = |sources: &[&str]| sources.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    for (sources, expected) in [
+        (injected(&[]), vec![Wallet::Dev]),
+        (injected(&["talisman"]), vec![Wallet::Talisman, Wallet::Dev]),
+        (
+            injected(&["polkadot-js", "subwallet-js", "enkrypt"]),
+            vec![Wallet::SubWallet, Wallet::PolkadotJs, Wallet::Dev],
+        ),
+    ] {
+        let available: Vec<Wallet> = Wallet::iter()
+            .filter(|wallet| wallet.is_available(runtime, &sources))
+            .collect();
+        assert_eq!(available, expected);
+    }
+}
+
+#[test]
+fn dev_signer_derives_well_known_accounts() {
+    let alice = DevSigner::new("Alice").unwrap();
+    assert_eq!(
+        hex::encode(alice.account_id().0),
+        "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+    );
+    assert_eq!(
+        alice.account_id().to_string(),
+        "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+    );
+
+    let accounts = dev_accounts();
+    assert_eq!(accounts.len(), DEV_ACCOUNTS.len());
+    assert_eq!(
+        accounts[1].address,
+        "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty"
+    );
+    assert!(accounts
+        .iter()
+        .all(|account| account.wallet() == Some(Wallet::Dev)));
+}
+
+#[test]
+fn dev_signer_signatures_verify() {
+    let alice = DevSigner::new("Alice").unwrap();
+    let payload = b"corematch payload";
+    let MultiSignature::Sr25519(signature) = alice.sign(payload) else {
+        panic!("dev accounts sign with sr25519");
+    };
+    let public = schnorrkel::PublicKey::from_bytes(&alice.account_id().0).unwrap();
+    let signature = schnorrkel::Signature::from_bytes(&signature).unwrap();
+    assert!(public
+        .verify_simple(b"substrate", payload, &signature)
+        .is_ok());
+    assert!(public
+        .verify_simple(b"substrate", b"another payload", &signature)
+        .is_err());
+}
@@ -0,0 +1,99 @@
+use corematch_common::errors::CorematchError;
+use corematch_common::runtimes::{
+    asset_hub::{nft_id_candidates, MintCosts, MAX_NFT_ID_PROBES, NFT_ID_SPACE},
+    support::{SupportedParachainRuntime, SupportedRelayRuntime},
+};
+use std::collections::HashSet;
+use subxt::utils::AccountId32;
+
+// 0.01 WND
+const FEE: u128 = 10_000_000_000;
+// 0.001 WND, as on AssetHub Westend
+const EXISTENTIAL_DEPOSIT: u128 = 1_000_000_000;
+
+fn costs(free_balance: u128) -> MintCosts {
+    MintCosts {
+        partial_fee: FEE,
+        free_balance,
+        existential_deposit: EXISTENTIAL_DEPOSIT,
+    }
+}
+
+#[test]
+fn mint_is_allowed_when_the_account_stays_alive() {
+    assert!(costs(FEE + EXISTENTIAL_DEPOSIT)
+        .check(SupportedRelayRuntime::Westend)
+        .is_ok());
+}
+
+#[test]
+fn mint_is_blocked_when_the_fee_can_not_be_paid() {
+    let err = costs(FEE - 1)
+        .check(SupportedRelayRuntime::Westend)
+        .unwrap_err();
+    assert!(matches!(err, CorematchError::InsufficientFunds(_)));
+    assert_eq!(
+        err.to_string(),
+        "Insufficient funds: minting costs ~0.01 WND but the account only has 0.0099 WND"
+    );
+}
+
+#[test]
+fn mint_is_blocked_when_the_existential_deposit_is_not_kept() {
+    let err = costs(FEE + EXISTENTIAL_DEPOSIT - 1)
+        .check(SupportedRelayRuntime::Westend)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Insufficient funds: after paying ~0.01 WND the account must still hold the existential deposit of 0.001 WND"
+    );
+}
+
+#[test]
+fn costs_summary_shows_fee_and_balance() {
+    assert_eq!(
+        costs(1_500_000_000_000).summary(SupportedRelayRuntime::Westend),
+        "Fee ~0.01 WND · Balance 1.5 WND"
+    );
+}
+
+#[test]
+fn asset_hubs_are_priced_in_their_relay_chain_unit() {
+    assert_eq!(
+        SupportedParachainRuntime::AssetHubWestend.relay_runtime(),
+        SupportedRelayRuntime::Westend
+    );
+    assert_eq!(
+        SupportedParachainRuntime::AssetHubWestend
+            .relay_runtime()
+            .unit(),
+        "WND"
+    );
+}
+
+#[test]
+fn nft_ids_are_derived_from_the_account() {
+    let alice = AccountId32([1u8; 32]);
+    let bob = AccountId32([2u8; 32]);
+    assert_eq!(
+        nft_id_candidates(&alice).collect::<Vec<_>>(),
+        nft_id_candidates(&alice).collect::<Vec<_>>()
+    );
+    assert_ne!(
+        nft_id_candidates(&alice).next(),
+        nft_id_candidates(&bob).next()
+    );
+}
+
+#[test]
+fn nft_id_candidates_are_distinct_and_within_the_id_space() {
+    for seed in 0..=u8::MAX {
+        let candidates: Vec<_> = nft_id_candidates(&AccountId32([seed; 32])).collect();
+        assert_eq!(candidates.len(), MAX_NFT_ID_PROBES as usize);
+        assert!(candidates.iter().all(|id| NFT_ID_SPACE.contains(id)));
+        assert_eq!(
+            candidates.iter().collect::<HashSet<_>>().len(),
+            candidates.len()
+        );
+    }
+}
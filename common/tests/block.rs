@@ -0,0 +1,171 @@
+mod fixtures;
+
+use corematch_common::components::block::USAGE_BUCKET_SIZE;
+use corematch_common::types::game::GameLevel;
+use fixtures::block;
+
+#[test]
+fn level1_hash_only_depends_on_core_occupancy() {
+    let a = block(1, &[Some(1000), None, Some(2000), None]);
+    let b = block(2, &[Some(3000), None, Some(4000), None]);
+    assert_eq!(
+        a.corespace_hash(GameLevel::Level1),
+        b.corespace_hash(GameLevel::Level1)
+    );
+}
+
+#[test]
+fn level1_hash_differs_when_occupancy_differs() {
+    let a = block(1, &[Some(1000), None, Some(2000), None]);
+    let b = block(2, &[Some(1000), Some(2000), None, None]);
+    assert_ne!(
+        a.corespace_hash(GameLevel::Level1),
+        b.corespace_hash(GameLevel::Level1)
+    );
+}
+
+#[test]
+fn level2_hash_depends_on_para_ids() {
+    let a = block(1, &[Some(1000), None, Some(2000), None]);
+    let b = block(2, &[Some(3000), None, Some(4000), None]);
+    assert_ne!(
+        a.corespace_hash(GameLevel::Level2),
+        b.corespace_hash(GameLevel::Level2)
+    );
+}
+
+#[test]
+fn level2_hash_depends_on_core_positions() {
+    let a = block(1, &[Some(1000), Some(2000), None]);
+    let b = block(2, &[Some(2000), Some(1000), None]);
+    assert_eq!(
+        a.corespace_hash(GameLevel::Level1),
+        b.corespace_hash(GameLevel::Level1)
+    );
+    assert_ne!(
+        a.corespace_hash(GameLevel::Level2),
+        b.corespace_hash(GameLevel::Level2)
+    );
+}
+
+#[test]
+fn level3_hash_ignores_core_positions() {
+    let a = block(1, &[Some(1000), None, Some(2000), Some(1000)]);
+    let b = block(2, &[None, Some(1000), Some(1000), Some(2000)]);
+    assert_ne!(
+        a.corespace_hash(GameLevel::Level2),
+        b.corespace_hash(GameLevel::Level2)
+    );
+    assert_eq!(
+        a.corespace_hash(GameLevel::Level3),
+        b.corespace_hash(GameLevel::Level3)
+    );
+}
+
+#[test]
+fn level3_hash_counts_repeated_para_ids() {
+    let a = block(1, &[Some(1000), Some(1000), Some(2000), None]);
+    let b = block(2, &[Some(1000), Some(2000), Some(2000), None]);
+    assert_ne!(
+        a.corespace_hash(GameLevel::Level3),
+        b.corespace_hash(GameLevel::Level3)
+    );
+}
+
+#[test]
+fn corespace_pattern_sorts_para_ids_with_idle_cores_last() {
+    let pattern: Vec<Option<u32>> = block(1, &[None, Some(2000), Some(1000), None, Some(1000)])
+        .corespace_pattern()
+        .iter()
+        .map(|core| core.para_id)
+        .collect();
+    assert_eq!(
+        pattern,
+        vec![Some(1000), Some(1000), Some(2000), None, None]
+    );
+}
+
+#[test]
+fn hash_ignores_block_number_and_cell_state() {
+    let a = block(1, &[Some(1000), None, Some(2000)]);
+    let mut b = block(2, &[Some(1000), None, Some(2000)]);
+    b.selected();
+    b.matched();
+    assert_eq!(
+        a.corespace_hash(GameLevel::Level1),
+        b.corespace_hash(GameLevel::Level1)
+    );
+    assert_eq!(
+        a.corespace_hash(GameLevel::Level2),
+        b.corespace_hash(GameLevel::Level2)
+    );
+}
+
+#[test]
+fn corespace_usage_is_a_percentage_of_occupied_cores() {
+    assert_eq!(block(1, &[None, None, None, None]).corespace_usage(), 0);
+    assert_eq!(
+        block(1, &[Some(1000), None, Some(2000), None]).corespace_usage(),
+        50
+    );
+    assert_eq!(
+        block(1, &[Some(1000), Some(1000), Some(2000), None]).corespace_usage(),
+        75
+    );
+}
+
+#[test]
+fn empty_corespace_has_no_usage() {
+    let empty = block(1, &[]);
+    assert_eq!(empty.corespace_usage(), 0);
+    assert_eq!(empty.corespace_usage_bucket(), 0);
+    assert_eq!(
+        empty.corespace_hash(GameLevel::Level0),
+        block(2, &[None, None]).corespace_hash(GameLevel::Level0)
+    );
+}
+
+#[test]
+fn level0_hash_matches_usage_buckets() {
+    // 50% and 75% usage of four cores
+    let a = block(1, &[Some(1000), None, Some(2000), None]);
+    let b = block(2, &[None, Some(3000), None, Some(4000)]);
+    let c = block(3, &[Some(1000), Some(2000), Some(3000), None]);
+    assert_eq!(a.corespace_usage_bucket(), 2);
+    assert_eq!(c.corespace_usage_bucket(), 3);
+    assert_eq!(
+        a.corespace_hash(GameLevel::Level0),
+        b.corespace_hash(GameLevel::Level0)
+    );
+    assert_ne!(
+        a.corespace_hash(GameLevel::Level0),
+        c.corespace_hash(GameLevel::Level0)
+    );
+}
+
+#[test]
+fn usage_buckets_group_close_percentages() {
+    // 5 out of 8 cores is 62%, 4 out of 8 is 50%
+    let a = block(
+        1,
+        &[
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+            None,
+            None,
+            None,
+        ],
+    );
+    let b = block(
+        2,
+        &[Some(1), Some(2), Some(3), Some(4), None, None, None, None],
+    );
+    assert_eq!(a.corespace_usage_bucket(), b.corespace_usage_bucket());
+    assert_eq!(
+        block(3, &[Some(1), Some(2)]).corespace_usage_bucket(),
+        100 / USAGE_BUCKET_SIZE
+    );
+}
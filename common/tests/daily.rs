@@ -0,0 +1,141 @@
+use corematch_common::engine::DAILY_CHALLENGE_BLOCKS;
+use corematch_common::runtimes::daily::{daily_challenge_length, DayStartSearch};
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+use corematch_common::types::daily::{DailyHistory, DailyResult, Day};
+
+const OCT_18_2026: Day = Day(20_744);
+
+fn result(day: u32, points: u32) -> DailyResult {
+    DailyResult {
+        day: Day(day),
+        points,
+        matched: points / 100,
+    }
+}
+
+fn history(days: &[u32]) -> DailyHistory {
+    let mut history = DailyHistory::new();
+    for day in days {
+        history.record(result(*day, 100));
+    }
+    history
+}
+
+#[test]
+fn days_are_counted_in_utc_since_the_unix_epoch() {
+    let day_start = OCT_18_2026.start_millis();
+    assert_eq!(Day::from_unix_millis(day_start), OCT_18_2026);
+    assert_eq!(Day::from_unix_millis(day_start + 86_399_999), OCT_18_2026);
+    assert_eq!(Day::from_unix_millis(day_start - 1), OCT_18_2026.previous());
+    assert_eq!(Day::from_unix_millis(0), Day(0));
+}
+
+#[test]
+fn days_are_displayed_as_calendar_dates() {
+    assert_eq!(Day(0).to_string(), "1970-01-01");
+    assert_eq!(OCT_18_2026.to_string(), "2026-10-18");
+    assert_eq!(Day(19_782).to_string(), "2024-02-29");
+    assert_eq!(Day(19_783).to_string(), "2024-03-01");
+}
+
+#[test]
+fn only_the_best_result_of_a_day_is_kept() {
+    let mut history = DailyHistory::new();
+    history.record(result(10, 300));
+    history.record(result(10, 200));
+    assert_eq!(history.result(Day(10)), Some(&result(10, 300)));
+    history.record(result(10, 500));
+    assert_eq!(history.result(Day(10)), Some(&result(10, 500)));
+    assert!(history.result(Day(11)).is_none());
+}
+
+#[test]
+fn streak_counts_consecutive_days_up_to_today() {
+    let history = history(&[3, 5, 6, 7]);
+    assert_eq!(history.streak(Day(7)), 3);
+    // today is not over yet, the streak is kept until then
+    assert_eq!(history.streak(Day(8)), 3);
+    assert_eq!(history.streak(Day(9)), 0);
+    assert_eq!(history.streak(Day(4)), 1);
+    assert_eq!(DailyHistory::new().streak(OCT_18_2026), 0);
+}
+
+#[test]
+fn best_streak_is_the_longest_run_of_days() {
+    assert_eq!(history(&[1, 2, 3, 7, 8]).best_streak(), 3);
+    assert_eq!(history(&[8, 7, 1]).best_streak(), 2);
+    assert_eq!(DailyHistory::new().best_streak(), 0);
+}
+
+#[test]
+fn history_round_trips_as_json() {
+    let history = history(&[1, 2, 4]);
+    let json = serde_json::to_string(&history).unwrap();
+    assert_eq!(
+        serde_json::from_str::<DailyHistory>(&json).unwrap(),
+        history
+    );
+}
+
+#[test]
+fn result_card_holds_the_day_score_and_streak() {
+    let result = DailyResult {
+        day: OCT_18_2026,
+        points: 1_200,
+        matched: 9,
+    };
+    assert_eq!(
+        result.share_message(SupportedRelayRuntime::Kusama, 4),
+        "corematch.xyz daily challenge 2026-10-18 📅\n1200 points · 9 matches · 4 day streak 🔥\n\n@kusamanetwork #BuildOnKusama"
+    );
+}
+
+/// Searches the first block of the day on a chain with the given timestamps, returns it along with
+/// the number of blocks probed.
+fn search(timestamp: impl Fn(u32) -> u64, latest: u32, day_start: u64) -> (u32, u32) {
+    let mut search = DayStartSearch::new(day_start, (latest, timestamp(latest)));
+    let mut probes = 0;
+    loop {
+        if let Some(block_number) = search.first_block() {
+            return (block_number, probes);
+        }
+        let block_number = search.probe();
+        assert!(block_number < latest);
+        search.narrow(block_number, timestamp(block_number));
+        probes += 1;
+    }
+}
+
+#[test]
+fn first_block_of_the_day_is_found_in_a_couple_of_probes() {
+    let day_start = OCT_18_2026.start_millis();
+    // blocks every six seconds, block 1_000 finalized 2 seconds after midnight
+    let timestamp = |n: u32| day_start + 2_000 + 6_000 * u64::from(n) - 6_000_000;
+
+    let (first, probes) = search(timestamp, 10_000, day_start);
+    assert_eq!(first, 1_000);
+    assert!(probes <= 2);
+}
+
+#[test]
+fn first_block_of_the_day_is_found_across_missed_slots() {
+    let day_start = OCT_18_2026.start_millis();
+    // a one hour stall right before midnight, then a block missed every now and then
+    let timestamp = |n: u32| {
+        let n = u64::from(n);
+        let stall = if n >= 500 { 3_600_000 } else { 0 };
+        let missed = if n >= 500 { (n - 500) / 7 * 6_000 } else { 0 };
+        day_start - 4_000_000 + 6_000 * n + stall + missed
+    };
+    let expected = (0..).find(|n| timestamp(*n) >= day_start).unwrap();
+
+    let (first, probes) = search(timestamp, 5_000, day_start);
+    assert_eq!(first, expected);
+    assert!(probes < 32);
+}
+
+#[test]
+fn daily_challenge_fills_the_board_before_the_blocks_played() {
+    assert_eq!(daily_challenge_length(9), 9 + DAILY_CHALLENGE_BLOCKS);
+    assert_eq!(daily_challenge_length(16), 16 + DAILY_CHALLENGE_BLOCKS);
+}
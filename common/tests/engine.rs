@@ -0,0 +1,751 @@
+mod fixtures;
+
+use corematch_common::components::block::Block;
+use corematch_common::engine::{
+    Direction, GameCommand, GameEngine, GameEvent, DAILY_CHALLENGE_BLOCKS, DEFAULT_BASE_POINTS,
+    DEFAULT_INITIAL_HELPS, DEFAULT_INITIAL_TRIES, DEFAULT_TOTAL_BLOCKS, TIME_ATTACK_BLOCKS,
+};
+use corematch_common::types::game::{BoardSize, GameHelpStatus, GameLevel, GameMode, GameStatus};
+use fixtures::{block, unique_block};
+
+const PATTERN_A: [Option<u32>; 4] = [Some(1000), None, Some(2000), None];
+const PATTERN_B: [Option<u32>; 4] = [None, Some(1000), None, Some(2000)];
+
+/// Starts a game at Level 1 and fills the board with the given blocks, the last one ending up in
+/// the first cell.
+fn started_engine(blocks: Vec<Block>) -> GameEngine {
+    started_engine_in_mode(GameMode::Classic, blocks)
+}
+
+fn started_engine_in_mode(game_mode: GameMode, blocks: Vec<Block>) -> GameEngine {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::ChangeMode(game_mode));
+    engine.handle(GameCommand::ChangeLevel(GameLevel::Level1));
+    engine.handle(GameCommand::Start);
+    for block in blocks {
+        engine.handle(GameCommand::PushBlock(block));
+    }
+    engine
+}
+
+/// Board with `n` cells holding PATTERN_A on the first cells and unique patterns on the others.
+fn board_with_repeated_pattern(n: usize) -> GameEngine {
+    let total = DEFAULT_TOTAL_BLOCKS as usize;
+    let mut blocks = Vec::new();
+    for i in 0..total {
+        let block_number = i as u32 + 1;
+        if i >= total - n {
+            blocks.push(block(block_number, &PATTERN_A));
+        } else {
+            blocks.push(unique_block(block_number, 0x10 + i as u8));
+        }
+    }
+    started_engine(blocks)
+}
+
+fn select_and_press(engine: &mut GameEngine, i: usize) -> Vec<GameEvent> {
+    engine.handle(GameCommand::SelectCell(i));
+    engine.handle(GameCommand::PressCell(i))
+}
+
+#[test]
+fn new_engine_has_an_empty_board() {
+    let engine = GameEngine::new();
+    assert_eq!(engine.blocks().len(), DEFAULT_TOTAL_BLOCKS as usize);
+    assert!(engine.blocks().iter().all(|opt| opt.is_none()));
+    assert_eq!(*engine.game_status(), GameStatus::Init);
+    assert!(!engine.is_game_on());
+}
+
+#[test]
+fn start_resets_stats_and_plays_level0() {
+    let mut engine = GameEngine::new();
+    let events = engine.handle(GameCommand::Start);
+    assert_eq!(events, vec![GameEvent::Started]);
+    assert_eq!(*engine.game_status(), GameStatus::On);
+    assert_eq!(*engine.game_level(), GameLevel::Level0);
+    assert_eq!(engine.points(), 0);
+    assert_eq!(engine.tries(), DEFAULT_INITIAL_TRIES);
+    assert_eq!(engine.helps(), DEFAULT_INITIAL_HELPS);
+    // a game already on can not be started again
+    assert!(engine.handle(GameCommand::Start).is_empty());
+}
+
+#[test]
+fn latest_block_is_pushed_into_the_first_cell() {
+    let engine = started_engine(vec![unique_block(1, 1), unique_block(2, 2)]);
+    let numbers: Vec<u32> = engine
+        .blocks()
+        .iter()
+        .flatten()
+        .map(|block| block.block_number)
+        .collect();
+    assert_eq!(numbers, vec![2, 1]);
+    assert_eq!(engine.last_finalized_block_number(), Some(2));
+}
+
+#[test]
+fn duration_only_increases_while_game_is_on() {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::PushBlock(unique_block(1, 1)));
+    assert_eq!(engine.duration(), 0);
+    engine.handle(GameCommand::Start);
+    engine.handle(GameCommand::PushBlock(unique_block(2, 2)));
+    engine.handle(GameCommand::PushBlock(unique_block(3, 3)));
+    assert_eq!(engine.duration(), 2);
+}
+
+#[test]
+fn board_keeps_a_sliding_window_of_nine_blocks() {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::ChangeLevel(GameLevel::Level1));
+    for n in 1..=12 {
+        engine.handle(GameCommand::PushBlock(unique_block(n, n as u8)));
+    }
+    let numbers: Vec<u32> = engine
+        .blocks()
+        .iter()
+        .flatten()
+        .map(|block| block.block_number)
+        .collect();
+    assert_eq!(numbers, (4..=12).rev().collect::<Vec<u32>>());
+    // evicted blocks are removed from the matches bookkeeping
+    assert_eq!(engine.matches().len(), DEFAULT_TOTAL_BLOCKS as usize);
+    assert!(engine.matches().values().all(|counter| *counter == 1));
+}
+
+#[test]
+fn evicted_block_decrements_its_pattern_counter() {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::ChangeLevel(GameLevel::Level1));
+    let hash = block(0, &PATTERN_A).corespace_hash(GameLevel::Level1);
+    for n in 1..=3 {
+        engine.handle(GameCommand::PushBlock(block(n, &PATTERN_A)));
+    }
+    assert_eq!(engine.matches().get(&hash), Some(&3));
+    for n in 4..=10 {
+        engine.handle(GameCommand::PushBlock(unique_block(n, n as u8)));
+    }
+    // block #1 was evicted from the board
+    assert_eq!(engine.matches().get(&hash), Some(&2));
+    for n in 11..=12 {
+        engine.handle(GameCommand::PushBlock(unique_block(n, n as u8)));
+    }
+    // counter reaches zero and the pattern is dropped
+    assert_eq!(engine.matches().get(&hash), None);
+}
+
+#[test]
+fn first_pressed_cell_becomes_the_match_cell() {
+    let mut engine = board_with_repeated_pattern(2);
+    let events = select_and_press(&mut engine, 0);
+    assert_eq!(events, vec![GameEvent::MatchSelected(0)]);
+    assert_eq!(engine.match_index(), Some(0));
+    assert_eq!(engine.match_class(), "match__0");
+    // pressing the same cell again unselects it
+    assert!(engine.handle(GameCommand::PressCell(0)).is_empty());
+    assert_eq!(engine.match_index(), None);
+}
+
+#[test]
+fn match_scores_base_points() {
+    let mut engine = board_with_repeated_pattern(2);
+    select_and_press(&mut engine, 0);
+    let events = engine.handle(GameCommand::PressCell(1));
+    assert_eq!(events, vec![GameEvent::Matched(1)]);
+    assert_eq!(engine.points(), DEFAULT_BASE_POINTS);
+    assert!(engine.blocks()[0].as_ref().unwrap().is_matched());
+    assert!(engine.blocks()[1].as_ref().unwrap().is_matched());
+}
+
+#[test]
+fn consecutive_matches_score_exponentially() {
+    let mut engine = board_with_repeated_pattern(5);
+    select_and_press(&mut engine, 0);
+    let mut expected = 0;
+    for (counter, i) in (1..5).enumerate() {
+        assert_eq!(
+            engine.handle(GameCommand::PressCell(i)),
+            vec![GameEvent::Matched(i)]
+        );
+        expected += DEFAULT_BASE_POINTS * 2_u32.pow(counter as u32);
+        assert_eq!(engine.points(), expected);
+    }
+    // 4 + 8 + 16 + 32
+    assert_eq!(engine.points(), 60);
+}
+
+#[test]
+fn match_counter_restarts_when_a_new_block_arrives() {
+    let mut engine = board_with_repeated_pattern(3);
+    select_and_press(&mut engine, 0);
+    engine.handle(GameCommand::PressCell(1));
+    assert_eq!(engine.points(), 4);
+    // new block shifts the board, cells 1..=3 hold the remaining pattern
+    engine.handle(GameCommand::PushBlock(block(100, &PATTERN_A)));
+    select_and_press(&mut engine, 0);
+    engine.handle(GameCommand::PressCell(3));
+    assert_eq!(engine.points(), 8);
+}
+
+#[test]
+fn matched_and_disabled_cells_can_not_be_pressed() {
+    let mut engine = board_with_repeated_pattern(3);
+    select_and_press(&mut engine, 0);
+    engine.handle(GameCommand::PressCell(1));
+    assert!(engine.handle(GameCommand::PressCell(1)).is_empty());
+    let block_number = engine.blocks()[1].as_ref().unwrap().block_number;
+    engine.handle(GameCommand::AnimationEnded(block_number));
+    assert!(engine.blocks()[1].as_ref().unwrap().is_disabled());
+    assert!(engine.handle(GameCommand::PressCell(1)).is_empty());
+    assert_eq!(engine.points(), 4);
+}
+
+#[test]
+fn wrong_match_costs_one_try() {
+    let mut engine = board_with_repeated_pattern(2);
+    select_and_press(&mut engine, 0);
+    let events = engine.handle(GameCommand::PressCell(5));
+    assert_eq!(events, vec![GameEvent::Missed(5)]);
+    assert_eq!(engine.tries(), DEFAULT_INITIAL_TRIES - 1);
+    assert_eq!(engine.points(), 0);
+    // match cell is released after a miss
+    assert_eq!(engine.match_index(), None);
+}
+
+#[test]
+fn game_is_over_when_no_tries_are_left() {
+    let mut engine = board_with_repeated_pattern(2);
+    select_and_press(&mut engine, 0);
+    engine.handle(GameCommand::PressCell(1));
+    // a new block releases the match cell
+    engine.handle(GameCommand::PushBlock(unique_block(100, 100)));
+    for n in 1..DEFAULT_INITIAL_TRIES {
+        select_and_press(&mut engine, 3);
+        assert_eq!(
+            engine.handle(GameCommand::PressCell(4)),
+            vec![GameEvent::Missed(4)]
+        );
+        assert_eq!(engine.tries(), DEFAULT_INITIAL_TRIES - n);
+    }
+    select_and_press(&mut engine, 3);
+    let events = engine.handle(GameCommand::PressCell(4));
+    assert_eq!(events, vec![GameEvent::Missed(4), GameEvent::Over]);
+    assert!(engine.is_game_over());
+    assert!(!engine.is_game_on());
+
+    let last_block = engine.previous_match_block().unwrap().block_number;
+    let results = format!("4/{}/{}", engine.duration(), last_block);
+    assert_eq!(engine.game_results(), Some(results.clone()));
+    assert!(engine
+        .share_message()
+        .unwrap()
+        .starts_with(&format!("corematch.xyz {results}")));
+    let score = engine.score_record().unwrap();
+    assert_eq!(score.points, 4);
+    assert_eq!(score.level, engine.game_level().number());
+    assert_eq!(score.block_number, last_block);
+    assert_eq!(
+        score.corespace_hash,
+        engine
+            .previous_match_block()
+            .unwrap()
+            .corespace_hash(engine.game_level().clone())
+    );
+    // cells can not be pressed once the game is over
+    assert!(engine.handle(GameCommand::PressCell(0)).is_empty());
+}
+
+#[test]
+fn warm_up_can_be_skipped_before_starting() {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::ChangeMode(GameMode::Zen));
+    // upper levels have to be unlocked by collecting points
+    assert!(engine
+        .handle(GameCommand::ChangeLevel(GameLevel::Level2))
+        .is_empty());
+    assert_eq!(
+        engine.handle(GameCommand::ChangeLevel(GameLevel::Level1)),
+        vec![GameEvent::LevelChanged(GameLevel::Level1)]
+    );
+    engine.handle(GameCommand::Start);
+    assert_eq!(*engine.game_level(), GameLevel::Level1);
+
+    // the level picked is kept for the next games
+    engine.handle(GameCommand::ChangeLevel(GameLevel::Level2));
+    engine.handle(GameCommand::Stop);
+    engine.handle(GameCommand::Start);
+    assert_eq!(*engine.game_level(), GameLevel::Level1);
+}
+
+#[test]
+fn level0_moves_on_to_level1_at_16_points() {
+    let total = DEFAULT_TOTAL_BLOCKS;
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::Start);
+    // 50% usage on the first four cells, 25% on the others
+    for n in 1..=total {
+        if n > total - 4 {
+            engine.handle(GameCommand::PushBlock(block(n, &PATTERN_A)));
+        } else {
+            engine.handle(GameCommand::PushBlock(block(
+                n,
+                &[Some(1000), None, None, None],
+            )));
+        }
+    }
+    select_and_press(&mut engine, 0);
+    engine.handle(GameCommand::PressCell(1));
+    engine.handle(GameCommand::PressCell(2));
+    engine.handle(GameCommand::PressCell(3));
+    // 4 + 8 + 16
+    assert_eq!(engine.points(), 28);
+    let events = engine.handle(GameCommand::AnimationEnded(total));
+    assert_eq!(events, vec![GameEvent::NextLevel(GameLevel::Level1)]);
+    engine.handle(GameCommand::EnterLevel(GameLevel::Level1));
+    assert_eq!(*engine.game_level(), GameLevel::Level1);
+}
+
+#[test]
+fn level2_unlocks_at_32_points() {
+    assert_eq!(GameLevel::Level1.collected_points_per_level_minimum(), 32);
+
+    let mut engine = board_with_repeated_pattern(4);
+    select_and_press(&mut engine, 0);
+    for i in 1..4 {
+        engine.handle(GameCommand::PressCell(i));
+    }
+    // 4 + 8 + 16
+    assert_eq!(engine.points(), 28);
+    assert!(!engine.is_level_x_completed(GameLevel::Level1));
+    let block_number = engine.blocks()[3].as_ref().unwrap().block_number;
+    assert!(engine
+        .handle(GameCommand::AnimationEnded(block_number))
+        .is_empty());
+
+    // a new set of matches crosses the 32 points threshold
+    engine.handle(GameCommand::PushBlock(block(100, &PATTERN_B)));
+    engine.handle(GameCommand::PushBlock(block(101, &PATTERN_B)));
+    select_and_press(&mut engine, 0);
+    engine.handle(GameCommand::PressCell(1));
+    assert_eq!(engine.points(), 32);
+    assert!(engine.is_level_x_completed(GameLevel::Level1));
+    assert!(engine.is_next_level_available(GameLevel::Level1));
+
+    let events = engine.handle(GameCommand::AnimationEnded(101));
+    assert_eq!(events, vec![GameEvent::NextLevel(GameLevel::Level2)]);
+    assert_eq!(*engine.game_status(), GameStatus::MoveTo(GameLevel::Level2));
+    assert!(engine.is_game_on());
+
+    let events = engine.handle(GameCommand::EnterLevel(GameLevel::Level2));
+    assert_eq!(events, vec![GameEvent::LevelChanged(GameLevel::Level2)]);
+    assert_eq!(*engine.game_status(), GameStatus::On);
+    assert_eq!(*engine.game_level(), GameLevel::Level2);
+}
+
+#[test]
+fn levels_unlock_in_order_from_level0_up_to_level3() {
+    assert_eq!(GameLevel::Level0.next(), Some(GameLevel::Level1));
+    assert_eq!(GameLevel::Level1.next(), Some(GameLevel::Level2));
+    assert_eq!(GameLevel::Level2.next(), Some(GameLevel::Level3));
+    assert_eq!(GameLevel::Level3.next(), None);
+    assert_eq!(GameLevel::Level2.collected_points_per_level_minimum(), 128);
+    assert_eq!(GameLevel::Level0.collected_points_per_level_minimum(), 16);
+    assert_eq!(GameLevel::Level0.number(), 0);
+    assert_eq!(GameLevel::Level3.number(), 3);
+}
+
+#[test]
+fn level2_is_only_offered_when_crossing_the_threshold() {
+    let mut engine = board_with_repeated_pattern(5);
+    select_and_press(&mut engine, 0);
+    for i in 1..5 {
+        engine.handle(GameCommand::PressCell(i));
+    }
+    // 28 points before the last match
+    assert_eq!(engine.points(), 60);
+    assert!(engine.is_next_level_available(GameLevel::Level1));
+
+    engine.handle(GameCommand::PushBlock(block(100, &PATTERN_B)));
+    engine.handle(GameCommand::PushBlock(block(101, &PATTERN_B)));
+    select_and_press(&mut engine, 0);
+    engine.handle(GameCommand::PressCell(1));
+    assert_eq!(engine.points(), 64);
+    assert!(!engine.is_next_level_available(GameLevel::Level1));
+    assert!(engine.handle(GameCommand::AnimationEnded(101)).is_empty());
+}
+
+#[test]
+fn next_level_restores_helps() {
+    let mut engine = started_engine(vec![
+        block(1, &PATTERN_A),
+        block(2, &PATTERN_A),
+        block(3, &PATTERN_B),
+        block(4, &PATTERN_B),
+    ]);
+    engine.handle(GameCommand::RequestHelp);
+    engine.handle(GameCommand::PushBlock(unique_block(5, 5)));
+    assert!(engine.helps() < DEFAULT_INITIAL_HELPS);
+
+    for n in 100..105 {
+        engine.handle(GameCommand::PushBlock(unique_block(n, 0x10)));
+    }
+    select_and_press(&mut engine, 0);
+    for i in 1..5 {
+        engine.handle(GameCommand::PressCell(i));
+    }
+    assert_eq!(engine.points(), 60);
+    let events = engine.handle(GameCommand::AnimationEnded(100));
+    assert_eq!(events, vec![GameEvent::NextLevel(GameLevel::Level2)]);
+    assert_eq!(engine.helps(), DEFAULT_INITIAL_HELPS);
+    assert_eq!(*engine.game_help_status(), GameHelpStatus::Available);
+}
+
+#[test]
+fn help_is_only_available_while_game_is_on() {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::RequestHelp);
+    assert!(!engine.is_help_on());
+    engine.handle(GameCommand::Start);
+    engine.handle(GameCommand::RequestHelp);
+    assert!(engine.is_help_on());
+}
+
+#[test]
+fn help_highlights_one_repeated_pattern_per_block() {
+    let mut engine = started_engine(vec![
+        block(1, &PATTERN_A),
+        block(2, &PATTERN_A),
+        block(3, &PATTERN_B),
+    ]);
+    engine.handle(GameCommand::RequestHelp);
+    let events = engine.handle(GameCommand::PushBlock(block(4, &PATTERN_B)));
+    assert!(events.contains(&GameEvent::HelpHighlighted(2)));
+    assert_eq!(engine.helps(), DEFAULT_INITIAL_HELPS - 2);
+    let highlighted = engine
+        .blocks()
+        .iter()
+        .flatten()
+        .filter(|block| block.help_class.is_some())
+        .count();
+    assert_eq!(highlighted, 2);
+}
+
+#[test]
+fn help_is_exhausted_after_eight_highlights() {
+    let mut engine = started_engine(vec![
+        block(1, &PATTERN_A),
+        block(2, &PATTERN_A),
+        block(3, &PATTERN_B),
+        block(4, &PATTERN_B),
+    ]);
+    engine.handle(GameCommand::RequestHelp);
+    let mut n = 5;
+    while engine.is_help_on() {
+        engine.handle(GameCommand::PushBlock(unique_block(n, n as u8)));
+        n += 1;
+        assert!(n < 20, "helps were never exhausted");
+    }
+    assert_eq!(engine.helps(), 0);
+    assert_eq!(*engine.game_help_status(), GameHelpStatus::NotAvailable);
+    // help can not be requested again
+    engine.handle(GameCommand::RequestHelp);
+    assert!(!engine.is_help_on());
+}
+
+#[test]
+fn cursor_wraps_around_the_board() {
+    let mut engine = started_engine(vec![unique_block(1, 1)]);
+    engine.handle(GameCommand::MoveCursor(Direction::Up));
+    assert_eq!(engine.cursor_position(), (0, 2));
+    engine.handle(GameCommand::MoveCursor(Direction::Left));
+    assert_eq!(engine.cursor_position(), (2, 2));
+    engine.handle(GameCommand::MoveCursor(Direction::Down));
+    assert_eq!(engine.cursor_position(), (2, 0));
+    engine.handle(GameCommand::MoveCursor(Direction::Right));
+    assert_eq!(engine.cursor_position(), (0, 0));
+}
+
+#[test]
+fn select_cell_moves_the_cursor() {
+    let mut engine = started_engine(vec![unique_block(1, 1); 9]);
+    engine.handle(GameCommand::SelectCell(7));
+    assert_eq!(engine.cursor_position(), (1, 2));
+    assert_eq!(engine.cursor_index(), 7);
+    let selected: Vec<bool> = engine
+        .blocks()
+        .iter()
+        .flatten()
+        .map(|block| block.is_selected())
+        .collect();
+    assert_eq!(selected.iter().filter(|s| **s).count(), 1);
+    assert!(selected[7]);
+}
+
+#[test]
+fn cursor_does_not_move_while_game_is_off() {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::MoveCursor(Direction::Right));
+    engine.handle(GameCommand::SelectCell(4));
+    assert_eq!(engine.cursor_position(), (0, 0));
+}
+
+#[test]
+fn full_reset_clears_the_board() {
+    let mut engine = started_engine(vec![unique_block(1, 1), unique_block(2, 2)]);
+    engine.handle(GameCommand::FullReset);
+    assert!(engine.blocks().iter().all(|opt| opt.is_none()));
+    assert_eq!(*engine.game_status(), GameStatus::Ready);
+}
+
+#[test]
+fn full_reset_keeps_the_level_picked() {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::ChangeLevel(GameLevel::Level1));
+    engine.handle(GameCommand::FullReset);
+    assert_eq!(*engine.game_level(), GameLevel::Level1);
+    engine.handle(GameCommand::ResizeBoard(BoardSize::Large));
+    assert_eq!(*engine.game_level(), GameLevel::Level1);
+    engine.handle(GameCommand::Start);
+    assert_eq!(*engine.game_level(), GameLevel::Level1);
+}
+
+#[test]
+fn board_size_sets_the_number_of_cells() {
+    assert_eq!(BoardSize::default(), BoardSize::Medium);
+    assert_eq!(BoardSize::Medium.total_blocks(), DEFAULT_TOTAL_BLOCKS);
+    assert_eq!(BoardSize::Small.to_string(), "2x2");
+    assert_eq!(BoardSize::Large.class(), "board__4x4");
+    assert_eq!(BoardSize::Large.next(), BoardSize::Small);
+    assert_eq!("4X4".parse::<BoardSize>().unwrap(), BoardSize::Large);
+    assert!("5x5".parse::<BoardSize>().is_err());
+
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::ResizeBoard(BoardSize::Large));
+    assert_eq!(engine.board_size(), BoardSize::Large);
+    assert_eq!(engine.blocks().len(), 16);
+
+    for n in 1..=20 {
+        engine.handle(GameCommand::PushBlock(unique_block(n, n as u8)));
+    }
+    assert_eq!(engine.blocks().len(), 16);
+    assert_eq!(
+        engine.blocks()[15].as_ref().map(|block| block.block_number),
+        Some(5)
+    );
+}
+
+#[test]
+fn cursor_wraps_around_the_board_columns() {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::ResizeBoard(BoardSize::Small));
+    engine.handle(GameCommand::Start);
+    for n in 1..=4 {
+        engine.handle(GameCommand::PushBlock(unique_block(n, n as u8)));
+    }
+
+    engine.handle(GameCommand::MoveCursor(Direction::Left));
+    assert_eq!(engine.cursor_position(), (1, 0));
+    assert_eq!(engine.cursor_index(), 1);
+    engine.handle(GameCommand::MoveCursor(Direction::Up));
+    assert_eq!(engine.cursor_position(), (1, 1));
+    assert_eq!(engine.cursor_index(), 3);
+    engine.handle(GameCommand::MoveCursor(Direction::Right));
+    assert_eq!(engine.cursor_position(), (0, 1));
+}
+
+#[test]
+fn bigger_boards_score_more_per_match() {
+    assert_eq!(BoardSize::Small.base_points(), DEFAULT_BASE_POINTS / 2);
+    assert_eq!(BoardSize::Large.base_points(), DEFAULT_BASE_POINTS * 2);
+
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::ResizeBoard(BoardSize::Large));
+    engine.handle(GameCommand::Start);
+    for n in 1..=14 {
+        engine.handle(GameCommand::PushBlock(unique_block(n, n as u8)));
+    }
+    engine.handle(GameCommand::PushBlock(block(15, &PATTERN_A)));
+    engine.handle(GameCommand::PushBlock(block(16, &PATTERN_A)));
+
+    select_and_press(&mut engine, 0);
+    let events = engine.handle(GameCommand::PressCell(1));
+    assert_eq!(events, vec![GameEvent::Matched(1)]);
+    assert_eq!(engine.points(), DEFAULT_BASE_POINTS * 2);
+    assert_eq!(engine.match_class(), "match__0");
+}
+
+/// Board in the given mode holding PATTERN_A on the first two cells and unique patterns on the others.
+fn board_in_mode(game_mode: GameMode) -> GameEngine {
+    let total = DEFAULT_TOTAL_BLOCKS;
+    let blocks = (1..=total)
+        .map(|n| {
+            if n > total - 2 {
+                block(n, &PATTERN_A)
+            } else {
+                unique_block(n, 0x10 + n as u8)
+            }
+        })
+        .collect();
+    started_engine_in_mode(game_mode, blocks)
+}
+
+#[test]
+fn game_mode_can_only_be_changed_while_game_is_off() {
+    let mut engine = GameEngine::new();
+    assert_eq!(engine.game_mode(), GameMode::Classic);
+    assert_eq!(
+        engine.handle(GameCommand::ChangeMode(GameMode::Zen)),
+        vec![GameEvent::ModeChanged(GameMode::Zen)]
+    );
+    engine.handle(GameCommand::Start);
+    assert!(engine
+        .handle(GameCommand::ChangeMode(GameMode::Classic))
+        .is_empty());
+    assert_eq!(engine.game_mode(), GameMode::Zen);
+}
+
+#[test]
+fn time_attack_is_over_after_a_fixed_number_of_blocks() {
+    let mut engine = board_in_mode(GameMode::TimeAttack);
+    select_and_press(&mut engine, 0);
+    assert_eq!(
+        engine.handle(GameCommand::PressCell(1)),
+        vec![GameEvent::Matched(1)]
+    );
+    engine.handle(GameCommand::PushBlock(unique_block(100, 100)));
+    // misses do not cost any tries
+    select_and_press(&mut engine, 3);
+    assert_eq!(
+        engine.handle(GameCommand::PressCell(4)),
+        vec![GameEvent::Missed(4)]
+    );
+    assert_eq!(engine.tries(), DEFAULT_INITIAL_TRIES);
+    assert!(engine.is_game_on());
+
+    let mut block_number = 100;
+    while engine.duration() < TIME_ATTACK_BLOCKS - 1 {
+        block_number += 1;
+        engine.handle(GameCommand::PushBlock(unique_block(block_number, 0)));
+    }
+    assert!(engine.is_game_on());
+    let events = engine.handle(GameCommand::PushBlock(unique_block(block_number + 1, 0)));
+    assert_eq!(
+        events,
+        vec![GameEvent::BlockPushed(block_number + 1), GameEvent::Over]
+    );
+    assert!(engine.is_game_over());
+    assert_eq!(
+        engine.previous_match_block().unwrap().block_number,
+        block_number + 1
+    );
+
+    let results = format!("{DEFAULT_BASE_POINTS} points in {TIME_ATTACK_BLOCKS} blocks");
+    assert_eq!(engine.game_results(), Some(results.clone()));
+    assert!(engine
+        .share_message()
+        .unwrap()
+        .starts_with(&format!("corematch.xyz time attack {results}")));
+    // only classic games are minted
+    assert!(engine.score_record().is_none());
+}
+
+#[test]
+fn daily_challenge_starts_once_the_board_is_filled_up() {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::ChangeMode(GameMode::DailyChallenge));
+    // NOTE: players can not pick the blocks the challenge starts with
+    assert!(engine.handle(GameCommand::Start).is_empty());
+
+    let total = DEFAULT_TOTAL_BLOCKS;
+    for n in 1..total {
+        engine.handle(GameCommand::PushBlock(unique_block(n, n as u8)));
+        assert!(!engine.is_game_on());
+    }
+    assert_eq!(
+        engine.handle(GameCommand::PushBlock(unique_block(total, total as u8))),
+        vec![GameEvent::BlockPushed(total), GameEvent::Started]
+    );
+    assert!(engine.is_game_on());
+
+    let mut block_number = total;
+    while engine.duration() < DAILY_CHALLENGE_BLOCKS - 1 {
+        block_number += 1;
+        engine.handle(GameCommand::PushBlock(unique_block(block_number, 0)));
+    }
+    assert!(engine.is_game_on());
+    let events = engine.handle(GameCommand::PushBlock(unique_block(block_number + 1, 0)));
+    assert_eq!(
+        events,
+        vec![GameEvent::BlockPushed(block_number + 1), GameEvent::Over]
+    );
+    assert!(engine
+        .share_message()
+        .unwrap()
+        .starts_with("corematch.xyz daily challenge 0 points in"));
+    assert!(engine.score_record().is_none());
+}
+
+#[test]
+fn zen_has_no_tries_nor_points_and_ends_when_stopped() {
+    let mut engine = board_in_mode(GameMode::Zen);
+    // matches are highlighted from the start, without running out of helps
+    assert!(engine.is_help_on());
+    assert_eq!(engine.helps(), DEFAULT_INITIAL_HELPS);
+    assert!(engine.is_level_x_completed(GameLevel::Level2));
+
+    select_and_press(&mut engine, 0);
+    assert_eq!(
+        engine.handle(GameCommand::PressCell(1)),
+        vec![GameEvent::Matched(1)]
+    );
+    assert_eq!(engine.points(), 0);
+    assert_eq!(engine.matched(), 1);
+    engine.handle(GameCommand::PushBlock(unique_block(100, 100)));
+    for _ in 0..DEFAULT_INITIAL_TRIES {
+        select_and_press(&mut engine, 3);
+        engine.handle(GameCommand::PressCell(4));
+    }
+    assert_eq!(engine.tries(), DEFAULT_INITIAL_TRIES);
+    assert!(engine.is_game_on());
+
+    assert_eq!(engine.handle(GameCommand::Stop), vec![GameEvent::Over]);
+    assert!(engine.is_game_over());
+    let results = format!("1 matches in {} blocks", engine.duration());
+    assert_eq!(engine.game_results(), Some(results.clone()));
+    assert!(engine
+        .share_message()
+        .unwrap()
+        .starts_with(&format!("corematch.xyz zen {results}")));
+    assert!(engine.score_record().is_none());
+}
+
+#[test]
+fn zen_highlights_blocks_pushed_before_start() {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::ChangeMode(GameMode::Zen));
+    let full = [Some(1000), Some(1001), Some(1002), Some(1003)];
+    for block in [block(1, &PATTERN_A), block(2, &PATTERN_A), block(3, &full)] {
+        engine.handle(GameCommand::PushBlock(block));
+    }
+    // the level picked after the blocks were pushed is the one matches are counted at
+    engine.handle(GameCommand::ChangeLevel(GameLevel::Level1));
+    engine.handle(GameCommand::Start);
+    assert_eq!(*engine.game_level(), GameLevel::Level1);
+    let events = engine.handle(GameCommand::PushBlock(block(4, &full)));
+    assert!(events.contains(&GameEvent::HelpHighlighted(2)));
+    let highlighted = engine
+        .blocks()
+        .iter()
+        .flatten()
+        .filter(|block| block.help_class.is_some())
+        .count();
+    assert!(highlighted > 0);
+}
+
+#[test]
+fn classic_games_can_not_be_stopped() {
+    let mut engine = board_with_repeated_pattern(2);
+    assert!(engine.handle(GameCommand::Stop).is_empty());
+    assert!(engine.is_game_on());
+}
@@ -0,0 +1,39 @@
+// Not every test crate uses every fixture
+#![allow(dead_code)]
+
+use corematch_common::components::block::{Block, BlockNumber, Corespace};
+use corematch_common::components::core::Core;
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+use corematch_common::types::network::ParaId;
+
+/// Builds a corespace where each entry represents a core, `None` being a free core.
+pub fn corespace(cores: &[Option<ParaId>]) -> Corespace {
+    cores
+        .iter()
+        .enumerate()
+        .map(|(i, para_id)| Core::new(i, *para_id))
+        .collect()
+}
+
+pub fn block(block_number: BlockNumber, cores: &[Option<ParaId>]) -> Block {
+    Block::new(
+        block_number,
+        corespace(cores),
+        SupportedRelayRuntime::Polkadot,
+    )
+}
+
+/// Builds a block with a unique occupancy pattern derived from the seed,
+/// so that no two seeds ever match each other at Level 1 or Level 2.
+pub fn unique_block(block_number: BlockNumber, seed: u8) -> Block {
+    let cores: Vec<Option<ParaId>> = (0..8)
+        .map(|i| {
+            if (seed >> i) & 1 == 1 {
+                Some(1000 + i as ParaId)
+            } else {
+                None
+            }
+        })
+        .collect();
+    block(block_number, &cores)
+}
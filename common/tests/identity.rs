@@ -0,0 +1,92 @@
+use corematch_common::runtimes::identity::{identity_from_value, super_from_value, Identity};
+use subxt::{dynamic::Value, utils::AccountId32};
+
+fn raw(name: &str) -> Value {
+    Value::unnamed_variant(
+        format!("Raw{}", name.len()),
+        [Value::from_bytes(name.as_bytes())],
+    )
+}
+
+fn judgement(name: &str) -> Value {
+    Value::unnamed_composite([Value::u128(0), Value::unnamed_variant(name, [])])
+}
+
+fn registration(display: Value, judgements: Vec<Value>) -> Value {
+    Value::named_composite([
+        ("judgements", Value::unnamed_composite(judgements)),
+        ("deposit", Value::u128(0)),
+        (
+            "info",
+            Value::named_composite([("display", display), ("legal", raw(""))]),
+        ),
+    ])
+}
+
+#[test]
+fn identity_is_decoded_from_the_registration() {
+    let identity = identity_from_value(&registration(raw("turboflakes"), vec![])).unwrap();
+    assert_eq!(
+        identity,
+        Identity {
+            display: "turboflakes".to_string(),
+            sub: None,
+            is_verified: false,
+        }
+    );
+    assert_eq!(identity.label(), "turboflakes");
+}
+
+#[test]
+fn identity_is_decoded_from_older_registrations_with_username() {
+    let value = Value::unnamed_composite([
+        registration(raw("turboflakes"), vec![]),
+        Value::unnamed_variant("None", []),
+    ]);
+    assert_eq!(identity_from_value(&value).unwrap().display, "turboflakes");
+}
+
+#[test]
+fn only_reasonable_or_known_good_judgements_verify_identities() {
+    for (judgements, is_verified) in [
+        (vec![judgement("FeePaid")], false),
+        (vec![judgement("Erroneous")], false),
+        (vec![judgement("Reasonable")], true),
+        (vec![judgement("OutOfDate"), judgement("KnownGood")], true),
+    ] {
+        let identity = identity_from_value(&registration(raw("alice"), judgements)).unwrap();
+        assert_eq!(identity.is_verified, is_verified);
+    }
+    let verified = identity_from_value(&registration(raw("alice"), vec![judgement("KnownGood")]));
+    assert_eq!(verified.unwrap().label(), "alice ✓");
+}
+
+#[test]
+fn identities_without_raw_display_are_ignored() {
+    assert_eq!(
+        identity_from_value(&registration(Value::unnamed_variant("None", []), vec![])),
+        None
+    );
+    assert_eq!(identity_from_value(&registration(raw(""), vec![])), None);
+}
+
+#[test]
+fn sub_accounts_resolve_to_their_parent() {
+    let parent = AccountId32([7; 32]);
+    let value = Value::unnamed_composite([
+        Value::unnamed_composite([Value::from_bytes(parent.0)]),
+        raw("corematch"),
+    ]);
+    assert_eq!(
+        super_from_value(&value),
+        Some((parent, Some("corematch".to_string())))
+    );
+
+    let identity = Identity {
+        display: "turboflakes".to_string(),
+        sub: Some("corematch".to_string()),
+        is_verified: true,
+    };
+    assert_eq!(identity.name(), "turboflakes/corematch");
+    assert_eq!(identity.label(), "turboflakes/corematch ✓");
+}
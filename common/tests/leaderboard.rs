@@ -0,0 +1,106 @@
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+use corematch_common::types::leaderboard::{
+    Leaderboard, LeaderboardEntry, LEADERBOARD_PAGE_SIZE, LEADERBOARD_TOP_N,
+};
+use corematch_common::types::score::{ScoreRecord, SCORE_RECORD_VERSION};
+use subxt::utils::{AccountId32, H256};
+
+fn entry(player: u8, network: SupportedRelayRuntime, level: u8, points: u32) -> LeaderboardEntry {
+    LeaderboardEntry {
+        account: AccountId32([player; 32]),
+        score: ScoreRecord {
+            version: SCORE_RECORD_VERSION,
+            network,
+            level,
+            points,
+            duration: 100,
+            block_number: 1_000,
+            corespace_hash: H256::zero(),
+            app_version: "0.6.0".to_string(),
+        },
+    }
+}
+
+fn players(n: u8) -> Leaderboard {
+    Leaderboard::new(
+        (0..n)
+            .map(|player| entry(player, SupportedRelayRuntime::Westend, 1, player as u32))
+            .collect(),
+    )
+}
+
+#[test]
+fn ranking_is_filtered_by_network_and_level() {
+    let leaderboard = Leaderboard::new(vec![
+        entry(1, SupportedRelayRuntime::Westend, 1, 10),
+        entry(2, SupportedRelayRuntime::Westend, 2, 20),
+        entry(3, SupportedRelayRuntime::Kusama, 1, 30),
+    ]);
+    let ranking = leaderboard.ranking(SupportedRelayRuntime::Westend, 1);
+    assert_eq!(ranking.len(), 1);
+    assert_eq!(ranking[0].account, AccountId32([1; 32]));
+    assert!(leaderboard
+        .ranking(SupportedRelayRuntime::Polkadot, 1)
+        .is_empty());
+}
+
+#[test]
+fn ranking_is_by_points_then_duration_then_block() {
+    let mut slower = entry(1, SupportedRelayRuntime::Westend, 1, 50);
+    slower.score.duration = 200;
+    let mut later = entry(2, SupportedRelayRuntime::Westend, 1, 50);
+    later.score.block_number = 2_000;
+    let first = entry(3, SupportedRelayRuntime::Westend, 1, 50);
+    let best = entry(4, SupportedRelayRuntime::Westend, 1, 60);
+    let leaderboard = Leaderboard::new(vec![slower, later, first, best]);
+
+    let ranked: Vec<u8> = leaderboard
+        .ranking(SupportedRelayRuntime::Westend, 1)
+        .iter()
+        .map(|entry| entry.account.0[0])
+        .collect();
+    assert_eq!(ranked, vec![4, 3, 2, 1]);
+}
+
+#[test]
+fn ranking_is_paginated_up_to_the_top_n() {
+    let leaderboard = players(u8::MAX);
+    assert_eq!(
+        leaderboard.ranking(SupportedRelayRuntime::Westend, 1).len(),
+        LEADERBOARD_TOP_N
+    );
+    assert_eq!(
+        leaderboard.pages(SupportedRelayRuntime::Westend, 1),
+        LEADERBOARD_TOP_N / LEADERBOARD_PAGE_SIZE
+    );
+
+    let second_page = leaderboard.page(SupportedRelayRuntime::Westend, 1, 1);
+    assert_eq!(second_page.len(), LEADERBOARD_PAGE_SIZE);
+    assert_eq!(second_page[0].0, LEADERBOARD_PAGE_SIZE + 1);
+    assert!(leaderboard
+        .page(SupportedRelayRuntime::Westend, 1, 100)
+        .is_empty());
+}
+
+#[test]
+fn last_page_holds_the_remaining_entries() {
+    let leaderboard = players(12);
+    assert_eq!(leaderboard.pages(SupportedRelayRuntime::Westend, 1), 2);
+    let last_page = leaderboard.page(SupportedRelayRuntime::Westend, 1, 1);
+    assert_eq!(last_page.len(), 2);
+    assert_eq!(last_page[1].0, 12);
+}
+
+#[test]
+fn players_are_shown_by_known_name_or_compact_address() {
+    let entry = entry(1, SupportedRelayRuntime::Westend, 1, 10);
+    assert_eq!(
+        entry.display_name(&[(AccountId32([1; 32]), "Alice".to_string())]),
+        "Alice"
+    );
+    let address = entry.account.to_string();
+    assert_eq!(
+        entry.display_name(&[]),
+        format!("{}...{}", &address[..4], &address[address.len() - 4..])
+    );
+}
@@ -0,0 +1,113 @@
+use corematch_common::runtimes::{
+    asset_hub::EncodedCall,
+    metadata_hash::{metadata_hash, AssetHubConfig},
+    support::SupportedParachainRuntime,
+};
+use corematch_common::types::account::SigningParams;
+use subxt::{
+    client::RuntimeVersion,
+    ext::codec::Decode,
+    utils::{AccountId32, H256},
+    Metadata, OfflineClient,
+};
+
+const METADATA: &[u8] =
+    include_bytes!("../../asset_hub_westend/artifacts/metadata/asset_hub_westend_metadata.scale");
+
+fn hash(spec_version: u32) -> H256 {
+    metadata_hash(
+        METADATA,
+        "westmint".to_string(),
+        spec_version,
+        42,
+        SupportedParachainRuntime::AssetHubWestend,
+    )
+    .unwrap()
+}
+
+fn signing_params(metadata_hash: Option<H256>) -> SigningParams {
+    SigningParams {
+        nonce: 3,
+        checkpoint_hash: H256::repeat_byte(0xab),
+        checkpoint_number: 7_200_123,
+        mortality_period: 64,
+        tip: 0,
+        metadata_hash,
+    }
+}
+
+/// The payload signed for a short call, which is not hashed before signing.
+fn signer_payload(params: &SigningParams) -> Vec<u8> {
+    let client = OfflineClient::<AssetHubConfig>::new(
+        H256::repeat_byte(0x01),
+        RuntimeVersion {
+            spec_version: 1_016_000,
+            transaction_version: 15,
+        },
+        Metadata::decode(&mut &METADATA[..]).unwrap(),
+    );
+    client
+        .tx()
+        .create_partial_signed_offline(&EncodedCall(vec![0x00, 0x00]), params.extrinsic_params())
+        .unwrap()
+        .signer_payload()
+}
+
+#[test]
+fn metadata_hash_depends_on_the_runtime_version() {
+    assert_eq!(hash(1_016_000), hash(1_016_000));
+    assert_ne!(hash(1_016_000), hash(1_016_001));
+    assert_ne!(hash(1_016_000), H256::zero());
+}
+
+#[test]
+fn metadata_can_not_be_hashed_if_invalid() {
+    assert!(metadata_hash(
+        &[0x00, 0x01],
+        "westmint".to_string(),
+        1,
+        42,
+        SupportedParachainRuntime::AssetHubWestend
+    )
+    .is_err());
+}
+
+#[test]
+fn extrinsics_sign_the_metadata_hash_if_given() {
+    let metadata_hash = hash(1_016_000);
+    let payload = signer_payload(&signing_params(Some(metadata_hash)));
+    // the metadata hash is the last additional signed data
+    assert!(payload.ends_with(&[&[0x01][..], metadata_hash.as_bytes()].concat()));
+
+    let payload = signer_payload(&signing_params(None));
+    assert_eq!(payload.last(), Some(&0x00));
+}
+
+#[test]
+fn extrinsics_send_the_metadata_hash_mode() {
+    let client = OfflineClient::<AssetHubConfig>::new(
+        H256::repeat_byte(0x01),
+        RuntimeVersion {
+            spec_version: 1_016_000,
+            transaction_version: 15,
+        },
+        Metadata::decode(&mut &METADATA[..]).unwrap(),
+    );
+    let call = vec![0x00, 0x00];
+    let encoded = |params: SigningParams| {
+        client
+            .tx()
+            .create_partial_signed_offline(&EncodedCall(call.clone()), params.extrinsic_params())
+            .unwrap()
+            .sign_with_address_and_signature(
+                &AccountId32([0x02; 32]).into(),
+                &subxt::utils::MultiSignature::Sr25519([0x03; 64]),
+            )
+            .into_encoded()
+    };
+    // the mode is the last signed extra, right before the call
+    let enabled = encoded(signing_params(Some(hash(1_016_000))));
+    assert!(enabled.ends_with(&[&[0x01][..], &call[..]].concat()));
+    let disabled = encoded(signing_params(None));
+    assert!(disabled.ends_with(&[&[0x00][..], &call[..]].concat()));
+}
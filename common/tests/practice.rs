@@ -0,0 +1,148 @@
+use corematch_common::components::block::Block;
+use corematch_common::engine::DEFAULT_TOTAL_BLOCKS;
+use corematch_common::runtimes::practice::{CorespaceGenerator, Occupancy, PracticeConfig};
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+
+fn generator(config: PracticeConfig) -> CorespaceGenerator {
+    CorespaceGenerator::with_seed(config, SupportedRelayRuntime::Polkadot, 42)
+}
+
+fn occupied_cores(block: &Block) -> usize {
+    block
+        .corespace
+        .iter()
+        .filter(|core| core.para_id.is_some())
+        .count()
+}
+
+#[test]
+fn same_seed_generates_same_blocks() {
+    let blocks: Vec<Block> = generator(PracticeConfig::default()).take(20).collect();
+    let other_blocks: Vec<Block> = generator(PracticeConfig::default()).take(20).collect();
+
+    assert_eq!(blocks, other_blocks);
+    assert_eq!(
+        blocks.iter().map(|b| b.block_number).collect::<Vec<_>>(),
+        (1..=20).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn blocks_have_the_configured_number_of_cores() {
+    let config = PracticeConfig {
+        total_cores: 16,
+        ..Default::default()
+    };
+
+    assert!(generator(config).take(10).all(|b| b.corespace.len() == 16));
+}
+
+#[test]
+fn occupancy_sets_the_share_of_occupied_cores() {
+    let free = PracticeConfig {
+        occupancy: Occupancy::Fixed(0.0),
+        repeat_probability: 0.0,
+        ..Default::default()
+    };
+    assert!(generator(free).take(10).all(|b| occupied_cores(&b) == 0));
+
+    let full = PracticeConfig {
+        occupancy: Occupancy::Fixed(1.0),
+        para_ids: vec![2000, 3000],
+        repeat_probability: 0.0,
+        ..Default::default()
+    };
+    for block in generator(full).take(10) {
+        assert_eq!(occupied_cores(&block), block.corespace.len());
+        assert!(block
+            .corespace
+            .iter()
+            .all(|core| matches!(core.para_id, Some(2000) | Some(3000))));
+    }
+}
+
+#[test]
+fn without_para_ids_every_core_is_free() {
+    let config = PracticeConfig {
+        occupancy: Occupancy::Fixed(1.0),
+        para_ids: vec![],
+        repeat_probability: 0.0,
+        ..Default::default()
+    };
+
+    assert!(generator(config).take(10).all(|b| occupied_cores(&b) == 0));
+}
+
+#[test]
+fn repeated_patterns_match_a_block_still_on_the_board() {
+    let config = PracticeConfig {
+        repeat_probability: 1.0,
+        ..Default::default()
+    };
+
+    let blocks: Vec<Block> = generator(config).take(30).collect();
+
+    let on_board = DEFAULT_TOTAL_BLOCKS as usize - 1;
+    for (i, block) in blocks.iter().enumerate().skip(1) {
+        let first = i.saturating_sub(on_board);
+        assert!(blocks[first..i]
+            .iter()
+            .any(|other| other.corespace == block.corespace));
+    }
+}
+
+#[test]
+fn repeated_patterns_fit_smaller_boards() {
+    let config = PracticeConfig {
+        repeat_probability: 1.0,
+        total_blocks: 4,
+        ..Default::default()
+    };
+
+    let blocks: Vec<Block> = generator(config).take(30).collect();
+
+    for (i, block) in blocks.iter().enumerate().skip(1) {
+        let first = i.saturating_sub(3);
+        assert!(blocks[first..i]
+            .iter()
+            .any(|other| other.corespace == block.corespace));
+    }
+}
+
+#[test]
+fn unrepeated_patterns_are_unlikely_to_match() {
+    let config = PracticeConfig {
+        repeat_probability: 0.0,
+        ..Default::default()
+    };
+
+    let blocks: Vec<Block> = generator(config).take(30).collect();
+
+    for (i, block) in blocks.iter().enumerate() {
+        assert!(blocks[..i]
+            .iter()
+            .all(|other| other.corespace != block.corespace));
+    }
+}
+
+#[test]
+fn para_ids_are_sorted_and_unique() {
+    let config = PracticeConfig {
+        para_ids: vec![3000, 1000, 2000, 1000],
+        ..Default::default()
+    };
+
+    assert_eq!(generator(config).para_ids(), vec![1000, 2000, 3000]);
+}
+
+#[test]
+fn occupancy_is_parsed_as_a_share_or_a_range() {
+    assert_eq!("0.5".parse::<Occupancy>().unwrap(), Occupancy::Fixed(0.5));
+    assert_eq!(
+        "0.2-0.8".parse::<Occupancy>().unwrap(),
+        Occupancy::Uniform { min: 0.2, max: 0.8 }
+    );
+    assert!("1.5".parse::<Occupancy>().is_err());
+    assert!("0.8-0.2".parse::<Occupancy>().is_err());
+    assert!("busy".parse::<Occupancy>().is_err());
+}
@@ -0,0 +1,132 @@
+mod fixtures;
+
+use corematch_common::components::block::Block;
+use corematch_common::engine::{GameCommand, GameEngine, DEFAULT_TOTAL_BLOCKS};
+use corematch_common::runtimes::replay::{Recording, ReplaySource};
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+use fixtures::{block, unique_block};
+use std::time::Duration;
+
+fn recording(total: u32) -> Recording {
+    let mut recording = Recording::new();
+    for block_number in 1..=total {
+        recording.push(&unique_block(block_number, block_number as u8));
+    }
+    recording
+}
+
+/// Plays every tick of the recording into a started engine.
+fn replay(recording: &Recording) -> GameEngine {
+    let mut engine = GameEngine::new();
+    engine.handle(GameCommand::Start);
+    for records in recording.ticks(DEFAULT_TOTAL_BLOCKS) {
+        for record in records {
+            engine.handle(GameCommand::PushBlock(record.into()));
+        }
+    }
+    engine
+}
+
+#[test]
+fn recording_round_trips_as_json_lines() {
+    let recording = recording(12);
+
+    let json_lines = recording.to_json_lines();
+
+    assert_eq!(json_lines.lines().count(), 12);
+    assert_eq!(Recording::from_json_lines(&json_lines).unwrap(), recording);
+}
+
+#[test]
+fn records_hold_the_block_corespace_and_runtime() {
+    let json_lines = r#"
+{"block_number":7,"corespace":[{"index":0,"para_id":2000},{"index":1,"para_id":null}],"runtime":"kusama"}
+"#;
+
+    let recording = Recording::from_json_lines(json_lines).unwrap();
+    let block: Block = (&recording.records()[0]).into();
+
+    assert_eq!(recording.len(), 1);
+    assert_eq!(recording.runtime(), Some(SupportedRelayRuntime::Kusama));
+    assert_eq!(block.block_number, 7);
+    assert_eq!(block.corespace[0].para_id, Some(2000));
+    assert_eq!(block.corespace[1].para_id, None);
+}
+
+#[test]
+fn invalid_records_report_their_line() {
+    let json_lines = format!("{}not a record\n", recording(2).to_json_lines());
+
+    let err = Recording::from_json_lines(&json_lines).unwrap_err();
+
+    assert!(err.to_string().contains("line 3"), "{err}");
+}
+
+#[test]
+fn blocks_already_recorded_are_ignored() {
+    let mut recording = recording(3);
+
+    recording.push(&unique_block(2, 0xff));
+    recording.push(&unique_block(3, 0xff));
+
+    assert_eq!(recording.len(), 3);
+}
+
+#[test]
+fn para_ids_are_collected_from_the_recorded_corespace() {
+    let mut recording = Recording::new();
+    recording.push(&block(1, &[Some(2000), None, Some(1000)]));
+    recording.push(&block(2, &[Some(1000), Some(3000)]));
+
+    assert_eq!(recording.para_ids(), vec![1000, 2000, 3000]);
+}
+
+#[test]
+fn first_tick_fills_the_board_and_others_send_a_single_block() {
+    let recording = recording(15);
+
+    let ticks: Vec<usize> = recording
+        .ticks(DEFAULT_TOTAL_BLOCKS)
+        .map(|records| records.len())
+        .collect();
+
+    assert_eq!(ticks[0], DEFAULT_TOTAL_BLOCKS as usize + 1);
+    assert!(ticks[1..].iter().all(|len| *len == 1));
+    assert_eq!(ticks.iter().sum::<usize>(), 15);
+    assert_eq!(Recording::new().ticks(DEFAULT_TOTAL_BLOCKS).count(), 0);
+
+    let ticks: Vec<usize> = recording.ticks(4).map(|records| records.len()).collect();
+    assert_eq!(ticks[0], 5);
+    assert_eq!(ticks.len(), 11);
+}
+
+#[test]
+fn replays_are_deterministic() {
+    let recording = recording(30);
+
+    let engine = replay(&recording);
+    let other_engine = replay(&Recording::from_json_lines(&recording.to_json_lines()).unwrap());
+
+    assert_eq!(engine.blocks(), other_engine.blocks());
+    assert_eq!(
+        engine.blocks()[0].as_ref().map(|block| block.block_number),
+        Some(30)
+    );
+}
+
+#[test]
+fn replay_speed_shortens_the_block_interval() {
+    assert_eq!(
+        ReplaySource::new(Recording::new(), 1, DEFAULT_TOTAL_BLOCKS).interval(),
+        Duration::from_secs(6)
+    );
+    assert_eq!(
+        ReplaySource::new(Recording::new(), 6, DEFAULT_TOTAL_BLOCKS).interval(),
+        Duration::from_secs(1)
+    );
+    // NOTE: a zero speed plays at the network pace
+    assert_eq!(
+        ReplaySource::new(Recording::new(), 0, DEFAULT_TOTAL_BLOCKS).interval(),
+        Duration::from_secs(6)
+    );
+}
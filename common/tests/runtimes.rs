@@ -0,0 +1,230 @@
+use corematch_common::components::core::Core;
+use corematch_common::engine::DEFAULT_TOTAL_BLOCKS;
+use corematch_common::runtimes::dynamic::corespace_from_value;
+use corematch_common::runtimes::source::{block_hash_hops, blocks_to_backfill, BLOCK_HASH_COUNT};
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+use corematch_common::runtimes::utils::{
+    decode_blake2_128_concat_key, get_last_key_from_storage_key,
+};
+use corematch_common::types::game::BoardSize;
+use corematch_common::types::network::ConnectionMode;
+use subxt::dynamic::Value;
+use subxt::ext::codec::Encode;
+
+fn id(para_id: u32) -> Value {
+    Value::unnamed_composite([Value::u128(para_id as u128)])
+}
+
+fn paras(assignment: Value) -> Value {
+    Value::unnamed_variant(
+        "Paras",
+        [Value::named_composite([
+            ("assignment", assignment),
+            ("availability_timeouts", Value::u128(0)),
+            ("ttl", Value::u128(0)),
+        ])],
+    )
+}
+
+#[test]
+fn testnets_are_parsed_from_query_params() {
+    for (chain, runtime) in [
+        ("westend", SupportedRelayRuntime::Westend),
+        ("Westend", SupportedRelayRuntime::Westend),
+        ("paseo", SupportedRelayRuntime::Paseo),
+        ("Paseo", SupportedRelayRuntime::Paseo),
+        ("polkadot", SupportedRelayRuntime::Polkadot),
+        ("Kusama", SupportedRelayRuntime::Kusama),
+    ] {
+        let parsed: SupportedRelayRuntime = serde_json::from_str(&format!("\"{chain}\"")).unwrap();
+        assert_eq!(parsed, runtime);
+        assert_eq!(
+            SupportedRelayRuntime::try_from(chain.to_string()).unwrap(),
+            runtime
+        );
+    }
+}
+
+#[test]
+fn unsupported_chains_are_an_error() {
+    assert_eq!(
+        "WND".parse::<SupportedRelayRuntime>().unwrap(),
+        SupportedRelayRuntime::Westend
+    );
+    assert!("rococo".parse::<SupportedRelayRuntime>().is_err());
+    assert!(SupportedRelayRuntime::try_from(String::new()).is_err());
+    assert_eq!(
+        SupportedRelayRuntime::try_from(2_u16).unwrap(),
+        SupportedRelayRuntime::Kusama
+    );
+    assert!(SupportedRelayRuntime::try_from(7_u16).is_err());
+}
+
+#[test]
+fn connection_mode_is_parsed_from_query_params() {
+    for (mode, connection_mode) in [
+        ("rpc", ConnectionMode::Rpc),
+        ("Rpc", ConnectionMode::Rpc),
+        ("light_client", ConnectionMode::LightClient),
+        ("LightClient", ConnectionMode::LightClient),
+    ] {
+        let parsed: ConnectionMode = serde_json::from_str(&format!("\"{mode}\"")).unwrap();
+        assert_eq!(parsed, connection_mode);
+    }
+    assert_eq!(ConnectionMode::default(), ConnectionMode::Rpc);
+}
+
+#[test]
+fn only_mainnets_bundle_chain_specs() {
+    assert!(SupportedRelayRuntime::Polkadot.chain_specs().is_some());
+    assert!(SupportedRelayRuntime::Kusama.chain_specs().is_some());
+    assert!(SupportedRelayRuntime::Westend.chain_specs().is_none());
+    assert!(SupportedRelayRuntime::Paseo.chain_specs().is_none());
+}
+
+#[test]
+fn every_runtime_has_secure_fallback_rpc_urls() {
+    use strum::IntoEnumIterator;
+
+    for runtime in SupportedRelayRuntime::iter() {
+        let rpc_urls = runtime.default_rpc_urls();
+        assert!(rpc_urls.len() > 1, "{runtime} has no fallback endpoint");
+        assert_eq!(rpc_urls[0], runtime.default_rpc_url());
+        assert!(rpc_urls.iter().all(|url| url.starts_with("wss://")));
+    }
+}
+
+#[test]
+fn testnets_have_their_own_class_and_unit() {
+    assert_eq!(SupportedRelayRuntime::Westend.class(), "westend");
+    assert_eq!(SupportedRelayRuntime::Westend.unit(), "WND");
+    assert_eq!(SupportedRelayRuntime::Paseo.class(), "paseo");
+    assert_eq!(SupportedRelayRuntime::Paseo.unit(), "PAS");
+}
+
+#[test]
+fn corespace_is_decoded_from_dynamic_availability_cores() {
+    let availability_cores = Value::unnamed_composite([
+        Value::unnamed_variant("Free", []),
+        paras(Value::named_variant(
+            "Pool",
+            [("para_id", id(2000)), ("core_index", Value::u128(1))],
+        )),
+        paras(Value::unnamed_variant("Bulk", [id(1000)])),
+    ]);
+
+    let corespace = corespace_from_value(&availability_cores);
+
+    assert_eq!(
+        corespace,
+        vec![
+            Core::new(0, None),
+            Core::new(1, Some(2000)),
+            Core::new(2, Some(1000)),
+        ]
+    );
+}
+
+#[test]
+fn unknown_core_shapes_are_decoded_as_free() {
+    let availability_cores = Value::unnamed_composite([
+        paras(Value::unnamed_variant("Unknown", [id(3000)])),
+        Value::u128(7),
+    ]);
+
+    let corespace = corespace_from_value(&availability_cores);
+
+    assert_eq!(corespace, vec![Core::new(0, None), Core::new(1, None)]);
+}
+
+#[test]
+fn a_new_subscription_backfills_a_full_board() {
+    let range = blocks_to_backfill(None, 100, DEFAULT_TOTAL_BLOCKS);
+
+    assert_eq!(range, 91..100);
+    assert_eq!(range.len(), DEFAULT_TOTAL_BLOCKS as usize);
+}
+
+#[test]
+fn a_reconnected_subscription_backfills_only_missed_blocks() {
+    assert_eq!(
+        blocks_to_backfill(Some(96), 100, DEFAULT_TOTAL_BLOCKS),
+        97..100
+    );
+    assert!(blocks_to_backfill(Some(99), 100, DEFAULT_TOTAL_BLOCKS).is_empty());
+}
+
+#[test]
+fn a_long_outage_backfills_at_most_a_full_board() {
+    assert_eq!(
+        blocks_to_backfill(Some(10), 100, DEFAULT_TOTAL_BLOCKS),
+        91..100
+    );
+}
+
+#[test]
+fn bigger_boards_backfill_more_blocks() {
+    let range = blocks_to_backfill(None, 100, BoardSize::Large.total_blocks());
+
+    assert_eq!(range, 84..100);
+    assert_eq!(blocks_to_backfill(None, 100, 4), 96..100);
+}
+
+#[test]
+fn recent_block_hashes_are_fetched_straight_from_the_latest_block() {
+    assert!(block_hash_hops(100, 100).is_empty());
+    assert_eq!(block_hash_hops(100, 100 + BLOCK_HASH_COUNT), vec![100]);
+}
+
+#[test]
+fn older_block_hashes_are_fetched_hopping_back_through_past_blocks() {
+    assert_eq!(block_hash_hops(100, 10_000), vec![5_904, 1_808, 100]);
+    assert_eq!(block_hash_hops(0, 8_192), vec![4_096, 0]);
+}
+
+#[test]
+fn amounts_are_formatted_in_the_runtime_unit() {
+    let westend = SupportedRelayRuntime::Westend;
+    assert_eq!(westend.format_amount(0), "0 WND");
+    assert_eq!(westend.format_amount(2_000_000_000_000), "2 WND");
+    assert_eq!(westend.format_amount(15_712_345_678), "0.0157 WND");
+    assert_eq!(westend.format_amount(1_500_000_000_000), "1.5 WND");
+    assert_eq!(westend.format_amount(1_000), "<0.0001 WND");
+
+    // NOTE: Polkadot has 10 decimals instead of 12
+    let polkadot = SupportedRelayRuntime::Polkadot;
+    assert_eq!(polkadot.format_amount(10_000_000_000), "1 DOT");
+    assert_eq!(polkadot.format_amount(1_000_000_000), "0.1 DOT");
+}
+
+#[test]
+fn blake2_128_concat_keys_are_decoded_in_order() {
+    let prefix = [0xaa; 32];
+    let mut key = prefix.to_vec();
+    for encoded in [
+        38u32.encode(),
+        Some(100_001u32).encode(),
+        b"score".to_vec().encode(),
+    ] {
+        key.extend([0xff; 16]);
+        key.extend(encoded);
+    }
+
+    let mut encoded = &key[prefix.len()..];
+    assert_eq!(decode_blake2_128_concat_key::<u32>(&mut encoded), Some(38));
+    assert_eq!(
+        decode_blake2_128_concat_key::<Option<u32>>(&mut encoded),
+        Some(Some(100_001))
+    );
+    assert_eq!(
+        decode_blake2_128_concat_key::<Vec<u8>>(&mut encoded),
+        Some(b"score".to_vec())
+    );
+    assert_eq!(decode_blake2_128_concat_key::<u32>(&mut encoded), None);
+
+    let last_prefix_length = key.len() - 16 - b"score".to_vec().encode().len();
+    assert_eq!(
+        get_last_key_from_storage_key::<Vec<u8>>(&key, last_prefix_length),
+        Some(b"score".to_vec())
+    );
+}
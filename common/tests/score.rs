@@ -0,0 +1,103 @@
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+use corematch_common::types::score::{
+    personal_best, ScoreRecord, MAX_SCORE_VALUE_LENGTH, SCORE_RECORD_VERSION,
+};
+use subxt::utils::H256;
+
+fn score() -> ScoreRecord {
+    ScoreRecord {
+        version: SCORE_RECORD_VERSION,
+        network: SupportedRelayRuntime::Westend,
+        level: 2,
+        points: 1_234,
+        duration: 300,
+        block_number: 23_456_789,
+        corespace_hash: H256::repeat_byte(0xcd),
+        app_version: "0.6.0".to_string(),
+    }
+}
+
+#[test]
+fn attribute_key_is_stable_per_network_and_level() {
+    assert_eq!(score().attribute_key(), "corematch/score/westend/2");
+    let kusama = ScoreRecord {
+        network: SupportedRelayRuntime::Kusama,
+        level: 1,
+        ..score()
+    };
+    assert_eq!(kusama.attribute_key(), "corematch/score/kusama/1");
+}
+
+#[test]
+fn score_record_round_trips_through_the_attribute() {
+    let value = score().encode().unwrap();
+    assert!(value.len() <= MAX_SCORE_VALUE_LENGTH);
+    assert_eq!(
+        ScoreRecord::decode(score().attribute_key().as_bytes(), &value),
+        Some(score())
+    );
+}
+
+#[test]
+fn non_score_attributes_are_not_decoded() {
+    let value = score().encode().unwrap();
+    // attributes minted as `points/duration` under the block number
+    assert_eq!(ScoreRecord::decode(b"23456789", b"1234/300"), None);
+    // a record stored under the key of another level
+    assert_eq!(
+        ScoreRecord::decode(b"corematch/score/westend/1", &value),
+        None
+    );
+    assert_eq!(
+        ScoreRecord::decode(b"corematch/score/westend/2", b"not json"),
+        None
+    );
+}
+
+#[test]
+fn oversized_score_records_are_rejected() {
+    let oversized = ScoreRecord {
+        app_version: "x".repeat(MAX_SCORE_VALUE_LENGTH),
+        ..score()
+    };
+    assert!(oversized.encode().is_err());
+}
+
+#[test]
+fn more_points_or_fewer_blocks_beat_the_stored_score() {
+    let best = score();
+    let more_points = ScoreRecord {
+        points: best.points + 1,
+        duration: best.duration + 100,
+        ..score()
+    };
+    let faster = ScoreRecord {
+        duration: best.duration - 1,
+        ..score()
+    };
+    let worse = ScoreRecord {
+        points: best.points - 1,
+        duration: 1,
+        ..score()
+    };
+    assert!(more_points.beats(&best));
+    assert!(faster.beats(&best));
+    assert!(!worse.beats(&best));
+    assert!(!best.beats(&best));
+}
+
+#[test]
+fn personal_best_is_kept_per_network_and_level() {
+    let level1 = ScoreRecord {
+        level: 1,
+        ..score()
+    };
+    let kusama = ScoreRecord {
+        network: SupportedRelayRuntime::Kusama,
+        ..score()
+    };
+    let stored = vec![level1.clone(), kusama.clone()];
+    assert_eq!(personal_best(&stored, &level1), Some(&level1));
+    assert_eq!(personal_best(&stored, &kusama), Some(&kusama));
+    assert_eq!(personal_best(&stored, &score()), None);
+}
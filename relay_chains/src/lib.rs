@@ -0,0 +1,17 @@
+use corematch_common::runtimes::{
+    dynamic::DynamicSource, source::RelayChainSource, support::SupportedRelayRuntime,
+};
+use corematch_kusama::kusama::KusamaSource;
+use corematch_polkadot::polkadot::PolkadotSource;
+use std::rc::Rc;
+
+/// Returns the corespace source of the given relay chain runtime.
+pub fn relay_chain_source(runtime: SupportedRelayRuntime) -> Rc<dyn RelayChainSource> {
+    match runtime {
+        SupportedRelayRuntime::Polkadot => Rc::new(PolkadotSource),
+        SupportedRelayRuntime::Kusama => Rc::new(KusamaSource),
+        SupportedRelayRuntime::Westend | SupportedRelayRuntime::Paseo => {
+            Rc::new(DynamicSource::new(runtime))
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use crate::game::Game;
+use corematch_common::components::block::{Block, USAGE_BUCKET_SIZE};
+use corematch_common::engine::DEFAULT_INITIAL_TRIES;
+use corematch_common::runtimes::{source::DEFAULT_TOTAL_CORES, support::SupportedRelayRuntime};
+use corematch_common::types::game::{GameLevel, GameMode};
+use corematch_common::types::network::{Color, ParachainColors};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const MAGENTA: &str = "\x1b[35m";
+const CYAN: &str = "\x1b[36m";
+const GRAY: &str = "\x1b[90m";
+
+const OCCUPIED_CORE: char = '■';
+const FREE_CORE: char = '·';
+
+/// Renders the whole screen, header, board of cells and footer.
+pub fn render(game: &Game) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let engine = &game.engine;
+
+    lines.push(format!(
+        "{BOLD}CORE MATCH{RESET} · {} · {} · {}",
+        game.runtime,
+        engine.game_mode(),
+        engine.game_level()
+    ));
+    let stats = match engine.game_mode() {
+        GameMode::Classic => format!(
+            "points {BOLD}{}{RESET}   tries {RED}{}{GRAY}{}{RESET}   helps {BOLD}{}{RESET}",
+            engine.points(),
+            "♥".repeat(engine.tries() as usize),
+            "♡".repeat(DEFAULT_INITIAL_TRIES.saturating_sub(engine.tries()) as usize),
+            engine.helps()
+        ),
+        GameMode::TimeAttack | GameMode::DailyChallenge => format!(
+            "points {BOLD}{}{RESET}   blocks left {BOLD}{}{RESET}   helps {BOLD}{}{RESET}",
+            engine.points(),
+            engine
+                .game_mode()
+                .duration_limit()
+                .unwrap_or_default()
+                .saturating_sub(engine.duration()),
+            engine.helps()
+        ),
+        GameMode::Zen => format!(
+            "matches {BOLD}{}{RESET}   blocks {BOLD}{}{RESET}",
+            engine.matched(),
+            engine.duration()
+        ),
+    };
+    lines.push(format!("{stats}   {}", engine.game_status()));
+    lines.push(String::new());
+
+    let columns = usize::from(engine.board_size().columns());
+    let cells: Vec<Vec<String>> = engine
+        .blocks()
+        .iter()
+        .enumerate()
+        .map(|(i, block)| render_cell(game, i, block.as_ref()))
+        .collect();
+    for row in cells.chunks(columns) {
+        let height = row.iter().map(|cell| cell.len()).max().unwrap_or_default();
+        for y in 0..height {
+            let line: Vec<&str> = row
+                .iter()
+                .map(|cell| cell.get(y).map(|s| s.as_str()).unwrap_or_default())
+                .collect();
+            lines.push(line.join(" "));
+        }
+    }
+
+    lines.push(String::new());
+    lines.push(format!("{GRAY}{}{RESET}", game.status));
+    if let Some(message) = &game.message {
+        lines.extend(message.lines().map(|line| line.to_string()));
+    }
+    lines.push(format!(
+        "{GRAY}Arrows move · Enter/Space match · S start/stop · H help · F flip · Q quit{RESET}"
+    ));
+
+    lines
+        .into_iter()
+        .map(|line| format!("{line}\r\n"))
+        .collect()
+}
+
+/// Renders a cell as a framed grid of cores, one line per row of cores.
+fn render_cell(game: &Game, i: usize, block: Option<&Block>) -> Vec<String> {
+    let columns = game.runtime.columns_size() as usize;
+    let rows = (DEFAULT_TOTAL_CORES as usize).div_ceil(columns);
+    // NOTE: cores are separated by a space
+    let width = columns * 2 - 1;
+
+    let frame_color = match block {
+        Some(block) if block.is_matched() => GREEN,
+        Some(block) if block.missed_class.is_some() => RED,
+        Some(_) if game.engine.match_index() == Some(i) => MAGENTA,
+        Some(block) if block.help_class.is_some() => CYAN,
+        Some(block) if block.is_selected() => YELLOW,
+        Some(block) if block.is_disabled() => DIM,
+        _ => GRAY,
+    };
+
+    let content: Vec<String> = match block {
+        None => vec![" ".repeat(width); rows],
+        Some(block) if block.is_flipped => flipped_lines(block, width, rows),
+        Some(block) if *game.engine.game_level() == GameLevel::Level0 => {
+            palette_lines(block, game.runtime, width, rows)
+        }
+        Some(block) => core_lines(block, game, columns, rows),
+    };
+
+    let mut lines = Vec::with_capacity(rows + 2);
+    lines.push(format!("{frame_color}┌{}┐{RESET}", "─".repeat(width)));
+    for line in content {
+        lines.push(format!("{frame_color}│{RESET}{line}{frame_color}│{RESET}"));
+    }
+    lines.push(format!("{frame_color}└{}┘{RESET}", "─".repeat(width)));
+    lines
+}
+
+fn core_lines(block: &Block, game: &Game, columns: usize, rows: usize) -> Vec<String> {
+    let dim = if block.is_disabled() { DIM } else { "" };
+    let corespace = match game.engine.game_level() {
+        GameLevel::Level3 => block.corespace_pattern(),
+        _ => block.corespace.clone(),
+    };
+    (0..rows)
+        .map(|y| {
+            (0..columns)
+                .map(|x| match corespace.get(y * columns + x) {
+                    Some(core) => match core.para_id {
+                        Some(para_id) => {
+                            let color = match game.engine.game_level() {
+                                GameLevel::Level1 => runtime_color(game.runtime),
+                                _ => para_color(&game.parachain_colors, para_id),
+                            };
+                            format!("{dim}{color}{OCCUPIED_CORE}{RESET}")
+                        }
+                        None => format!("{GRAY}{FREE_CORE}{RESET}"),
+                    },
+                    // core not available in this runtime
+                    None => " ".to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+/// Renders the palette of Level 0, the cell is painted in the color of its core usage bucket.
+fn palette_lines(
+    block: &Block,
+    runtime: SupportedRelayRuntime,
+    width: usize,
+    rows: usize,
+) -> Vec<String> {
+    let dim = if block.is_disabled() { DIM } else { "" };
+    let (r, g, b) = usage_color(runtime, block.corespace_usage_bucket());
+    let usage = format!("{}%", block.corespace_usage());
+    (0..rows)
+        .map(|y| {
+            let text = if y == rows / 2 { usage.as_str() } else { "" };
+            format!("{dim}{BOLD}\x1b[48;2;{r};{g};{b}m{text:^width$}{RESET}")
+        })
+        .collect()
+}
+
+fn flipped_lines(block: &Block, width: usize, rows: usize) -> Vec<String> {
+    let details = [
+        format!("#{}", block.block_number),
+        String::new(),
+        "core usage".to_string(),
+        format!("{}%", block.corespace_usage()),
+    ];
+    let top = rows.saturating_sub(details.len()) / 2;
+    (0..rows)
+        .map(|y| {
+            let text = y
+                .checked_sub(top)
+                .and_then(|j| details.get(j))
+                .cloned()
+                .unwrap_or_default();
+            format!("{text:^width$}")
+        })
+        .collect()
+}
+
+fn runtime_color(runtime: SupportedRelayRuntime) -> String {
+    let (r, g, b) = runtime_rgb(runtime);
+    format!("\x1b[38;2;{r};{g};{b}m")
+}
+
+fn runtime_rgb(runtime: SupportedRelayRuntime) -> (u8, u8, u8) {
+    match runtime {
+        SupportedRelayRuntime::Polkadot => (230, 0, 122),
+        SupportedRelayRuntime::Kusama => (255, 255, 255),
+        SupportedRelayRuntime::Westend => (218, 104, 167),
+        SupportedRelayRuntime::Paseo => (0, 177, 138),
+    }
+}
+
+/// The runtime color faded into black by the core usage bucket, as the web edition does with
+/// the usage opacity, so that cells in the same bucket share the same color.
+pub fn usage_color(runtime: SupportedRelayRuntime, bucket: usize) -> (u8, u8, u8) {
+    let (r, g, b) = runtime_rgb(runtime);
+    let opacity = (bucket * USAGE_BUCKET_SIZE).min(100) as f32 / 100.0;
+    let fade = |v: u8| (v as f32 * opacity).round() as u8;
+    (fade(r), fade(g), fade(b))
+}
+
+fn para_color(parachain_colors: &ParachainColors, para_id: u32) -> String {
+    match parachain_colors.get(&para_id) {
+        Some(color) => {
+            let (r, g, b) = hsl_to_rgb(*color);
+            format!("\x1b[38;2;{r};{g};{b}m")
+        }
+        None => String::new(),
+    }
+}
+
+/// Converts a color in HSL format, as used by the web edition, into RGB.
+pub fn hsl_to_rgb((h, s, l): Color) -> (u8, u8, u8) {
+    let s = s as f32 / 100.0;
+    let l = l as f32 / 100.0;
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h = (h % 360) as f32 / 60.0;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = l - c / 2.0;
+    let to_u8 = |v: f32| ((v + m) * 255.0).round() as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
@@ -0,0 +1,158 @@
+use corematch_common::components::block::Block;
+use corematch_common::components::keyboard::SupportedKeys;
+use corematch_common::engine::{Direction, GameCommand, GameEngine, GameEvent};
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+use corematch_common::types::game::{BoardSize, GameLevel, GameMode};
+use corematch_common::types::network::{generate_parachain_colors, ParachainColors, ParachainIds};
+use std::time::{Duration, Instant};
+
+// Time a matched or missed cell stays highlighted, the web edition waits for the CSS animation.
+const ANIMATION_DURATION: Duration = Duration::from_millis(800);
+// Time before entering the next level, as in the web edition.
+const NEXT_LEVEL_DELAY: Duration = Duration::from_secs(6);
+
+/// Game drives the engine from terminal events, it mirrors how the web App dispatches commands.
+pub struct Game {
+    pub runtime: SupportedRelayRuntime,
+    pub engine: GameEngine,
+    pub parachain_colors: ParachainColors,
+    /// Latest connection status.
+    pub status: String,
+    /// Latest game message, e.g. a match or a game over.
+    pub message: Option<String>,
+    // Commands to be dispatched once their deadline is reached.
+    timers: Vec<(Instant, GameCommand)>,
+}
+
+impl Game {
+    pub fn new(
+        runtime: SupportedRelayRuntime,
+        game_mode: GameMode,
+        game_level: GameLevel,
+        board_size: BoardSize,
+    ) -> Self {
+        let mut engine = GameEngine::new();
+        // NOTE: resizing resets the game, so the board is resized first
+        engine.handle(GameCommand::ResizeBoard(board_size));
+        engine.handle(GameCommand::ChangeMode(game_mode));
+        engine.handle(GameCommand::ChangeLevel(game_level));
+        Self {
+            runtime,
+            engine,
+            parachain_colors: ParachainColors::new(),
+            status: "Initializing".to_string(),
+            message: None,
+            timers: Vec::new(),
+        }
+    }
+
+    pub fn parachains_collected(&mut self, para_ids: ParachainIds) {
+        self.parachain_colors = generate_parachain_colors(para_ids);
+    }
+
+    pub fn push_block(&mut self, block: Block) {
+        self.dispatch(GameCommand::PushBlock(block));
+    }
+
+    pub fn key_pressed(&mut self, key: SupportedKeys) {
+        match key {
+            SupportedKeys::Enter => {
+                if !self.engine.is_game_on() {
+                    self.dispatch(GameCommand::Start)
+                } else {
+                    self.press_cursor_cell()
+                }
+            }
+            SupportedKeys::Space => {
+                if self.engine.is_game_on() {
+                    self.press_cursor_cell()
+                }
+            }
+            SupportedKeys::Up => self.dispatch(GameCommand::MoveCursor(Direction::Up)),
+            SupportedKeys::Down => self.dispatch(GameCommand::MoveCursor(Direction::Down)),
+            SupportedKeys::Left => self.dispatch(GameCommand::MoveCursor(Direction::Left)),
+            SupportedKeys::Right => self.dispatch(GameCommand::MoveCursor(Direction::Right)),
+            SupportedKeys::S => {
+                // NOTE: endless games are stopped with the same key
+                if self.engine.is_game_on() {
+                    self.dispatch(GameCommand::Stop)
+                } else {
+                    self.dispatch(GameCommand::Start)
+                }
+            }
+            SupportedKeys::H => self.dispatch(GameCommand::RequestHelp),
+            SupportedKeys::F => {
+                self.dispatch(GameCommand::FlipCell);
+                self.animate(self.engine.cursor_index());
+            }
+            SupportedKeys::NotSupported => (),
+        }
+    }
+
+    /// Time left until the next timer is due.
+    pub fn next_timeout(&self) -> Option<Duration> {
+        self.timers
+            .iter()
+            .map(|(deadline, _)| deadline.saturating_duration_since(Instant::now()))
+            .min()
+    }
+
+    /// Dispatches the commands of all timers that are due.
+    pub fn run_timers(&mut self) {
+        let now = Instant::now();
+        let (due, pending): (Vec<_>, Vec<_>) = self
+            .timers
+            .drain(..)
+            .partition(|(deadline, _)| *deadline <= now);
+        self.timers = pending;
+        for (_, command) in due {
+            self.dispatch(command);
+        }
+    }
+
+    fn press_cursor_cell(&mut self) {
+        let i = self.engine.cursor_index();
+        self.dispatch(GameCommand::PressCell(i));
+    }
+
+    fn dispatch(&mut self, command: GameCommand) {
+        for event in self.engine.handle(command) {
+            match event {
+                GameEvent::Started => self.message = None,
+                GameEvent::Matched(i) => {
+                    self.message = Some("Congrats, you found a match!".to_string());
+                    self.animate(i);
+                }
+                GameEvent::Missed(i) => {
+                    self.message = Some("Wrong match!".to_string());
+                    self.animate(i);
+                }
+                GameEvent::NextLevel(next_level) => {
+                    self.message = Some(format!("Well Done! {next_level} available for playing."));
+                    self.timers.push((
+                        Instant::now() + NEXT_LEVEL_DELAY,
+                        GameCommand::EnterLevel(next_level),
+                    ));
+                }
+                GameEvent::LevelChanged(_) => self.message = None,
+                GameEvent::Over => {
+                    self.message = Some(match self.engine.share_message() {
+                        Some(share_message) => format!("** Game Over **\n{share_message}"),
+                        None => "** Game Over **".to_string(),
+                    });
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Schedules the end of the cell animation, the engine settles the cell afterwards.
+    fn animate(&mut self, i: usize) {
+        if let Some(Some(block)) = self.engine.blocks().get(i) {
+            self.timers.push((
+                Instant::now() + ANIMATION_DURATION,
+                GameCommand::AnimationEnded(block.block_number),
+            ));
+        }
+    }
+}
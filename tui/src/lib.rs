@@ -0,0 +1,18 @@
+pub mod board;
+pub mod game;
+pub mod network;
+pub mod options;
+pub mod terminal;
+
+use corematch_common::components::block::Block;
+use corematch_common::components::keyboard::SupportedKeys;
+use corematch_common::types::network::ParachainIds;
+
+/// Event represents everything the terminal game reacts to.
+pub enum Event {
+    Key(SupportedKeys),
+    Quit,
+    Block(Block),
+    ParachainsCollected(ParachainIds),
+    Status(String),
+}
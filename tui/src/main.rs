@@ -0,0 +1,67 @@
+use corematch_tui::game::Game;
+use corematch_tui::options::{Options, USAGE};
+use corematch_tui::terminal::{self, Terminal};
+use corematch_tui::{board, network, Event};
+use std::process;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+// Maximum time without redrawing the screen, so that due timers are run.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(1);
+
+fn main() {
+    let options = match Options::from_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{err}\n\n{USAGE}");
+            process::exit(1);
+        }
+    };
+    if options.help {
+        println!("{USAGE}");
+        return;
+    }
+    if let Err(err) = run(options) {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+}
+
+fn run(options: Options) -> anyhow::Result<()> {
+    let (tx, rx) = mpsc::channel::<Event>();
+    // NOTE: the runtime must be kept alive for blocks to be received
+    let _runtime = network::spawn(
+        options.runtime,
+        options.source,
+        options.board_size.total_blocks(),
+        tx.clone(),
+    )?;
+
+    let terminal = Terminal::enter()?;
+    terminal::spawn_key_reader(tx);
+
+    let mut game = Game::new(
+        options.runtime,
+        options.game_mode,
+        options.game_level,
+        options.board_size,
+    );
+    loop {
+        terminal.draw(&board::render(&game))?;
+
+        let timeout = game
+            .next_timeout()
+            .unwrap_or(IDLE_TIMEOUT)
+            .min(IDLE_TIMEOUT);
+        match rx.recv_timeout(timeout) {
+            Ok(Event::Quit) | Err(RecvTimeoutError::Disconnected) => break,
+            Ok(Event::Key(key)) => game.key_pressed(key),
+            Ok(Event::Block(block)) => game.push_block(block),
+            Ok(Event::ParachainsCollected(para_ids)) => game.parachains_collected(para_ids),
+            Ok(Event::Status(status)) => game.status = status,
+            Err(RecvTimeoutError::Timeout) => (),
+        }
+        game.run_timers();
+    }
+    Ok(())
+}
@@ -0,0 +1,235 @@
+use crate::options::Source;
+use crate::Event;
+use corematch_common::components::block::{Block, BlockNumber};
+use corematch_common::runtimes::{
+    daily::fetch_daily_challenge,
+    practice::{CorespaceGenerator, PracticeConfig},
+    replay::{Recording, ReplaySource},
+    source::{connect_healthy_rpc, SubscriptionEvent},
+    support::SupportedRelayRuntime,
+};
+use corematch_common::types::daily::Day;
+use corematch_common::types::network::{SubscriptionId, CONTINUE_SIGNAL};
+use corematch_relay_chains::relay_chain_source;
+use futures::StreamExt;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    sync::mpsc::Sender,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use yew::{
+    platform::{pinned::mpsc::UnboundedSender, time::sleep, Runtime},
+    AttrValue, Callback,
+};
+
+// Pause before trying the next endpoint once a connection has failed.
+const RPC_RECONNECT_DELAY: Duration = Duration::from_secs(6);
+
+type Signals = Rc<RefCell<Option<UnboundedSender<AttrValue>>>>;
+
+/// Spawns the task that sends blocks to the game, the returned runtime must be kept alive.
+///
+/// The source is filled with as many blocks as the board holds, `total_blocks`.
+pub fn spawn(
+    runtime: SupportedRelayRuntime,
+    source: Source,
+    total_blocks: u32,
+    tx: Sender<Event>,
+) -> std::io::Result<Runtime> {
+    let rt = Runtime::builder().worker_threads(1).build()?;
+    rt.spawn_pinned(move || async move {
+        match source {
+            Source::Live { rpc_urls } => subscribe(runtime, rpc_urls, total_blocks, tx).await,
+            Source::Daily { rpc_urls } => daily(runtime, rpc_urls, total_blocks, tx).await,
+            Source::Practice => practice(runtime, total_blocks, tx),
+            Source::Replay { path, speed } => replay(path, speed, total_blocks, tx),
+        }
+    });
+    Ok(rt)
+}
+
+/// Callback that forwards blocks to the game and asks the subscription for the next one.
+fn block_callback(
+    tx: Sender<Event>,
+    signals: Signals,
+    last_block_number: Rc<Cell<Option<BlockNumber>>>,
+) -> Callback<(SubscriptionId, Block)> {
+    Callback::from(move |(_, block): (SubscriptionId, Block)| {
+        last_block_number.set(Some(block.block_number));
+        if let Some(signals) = signals.borrow().as_ref() {
+            // NOTE: the subscription task might have already ended
+            let _ = signals.send_now(CONTINUE_SIGNAL.into());
+        }
+        let _ = tx.send(Event::Block(block));
+    })
+}
+
+/// Keeps the signals channel of a new subscription and lets it send the first blocks.
+fn start(signals: &Signals, subscription: (SubscriptionId, UnboundedSender<AttrValue>)) {
+    let (_, channel) = subscription;
+    let _ = channel.send_now(CONTINUE_SIGNAL.into());
+    *signals.borrow_mut() = Some(channel);
+}
+
+fn practice(runtime: SupportedRelayRuntime, total_blocks: u32, tx: Sender<Event>) {
+    let config = PracticeConfig {
+        total_blocks,
+        ..PracticeConfig::default()
+    };
+    let generator = CorespaceGenerator::new(config, runtime);
+    let _ = tx.send(Event::ParachainsCollected(generator.para_ids()));
+    let _ = tx.send(Event::Status(format!(
+        "Practicing with synthetic {runtime} blocks"
+    )));
+
+    let signals: Signals = Rc::default();
+    let cb = block_callback(tx, signals.clone(), Rc::default());
+    start(&signals, generator.subscribe(cb));
+}
+
+fn replay(path: String, speed: u32, total_blocks: u32, tx: Sender<Event>) {
+    let recording = match std::fs::read_to_string(&path)
+        .map_err(anyhow::Error::from)
+        .and_then(|json_lines| Ok(Recording::from_json_lines(&json_lines)?))
+    {
+        Ok(recording) => recording,
+        Err(err) => {
+            let _ = tx.send(Event::Status(format!(
+                "Recording could not be loaded from {path}: {err}"
+            )));
+            return;
+        }
+    };
+    let _ = tx.send(Event::ParachainsCollected(recording.para_ids()));
+    let _ = tx.send(Event::Status(format!(
+        "Replaying {} blocks from {path}",
+        recording.len()
+    )));
+
+    let signals: Signals = Rc::default();
+    let cb = block_callback(tx, signals.clone(), Rc::default());
+    start(
+        &signals,
+        Rc::new(ReplaySource::new(recording, speed, total_blocks)).subscribe(cb),
+    );
+}
+
+/// Fetches the blocks of today's daily challenge and plays them at the network pace, trying each
+/// RPC endpoint once.
+async fn daily(
+    runtime: SupportedRelayRuntime,
+    rpc_urls: Vec<String>,
+    total_blocks: u32,
+    tx: Sender<Event>,
+) {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    let day = Day::from_unix_millis(millis);
+
+    for rpc_url in rpc_urls.iter() {
+        let _ = tx.send(Event::Status(format!(
+            "Loading daily challenge of {day} from {rpc_url}"
+        )));
+        let source = relay_chain_source(runtime);
+        let recording = match connect_healthy_rpc(rpc_url).await {
+            Ok(api) => fetch_daily_challenge(source.as_ref(), &api, day, total_blocks).await,
+            Err(err) => Err(err),
+        };
+        let recording = match recording {
+            Ok(recording) => recording,
+            Err(err) => {
+                let _ = tx.send(Event::Status(format!(
+                    "Daily challenge could not be loaded from {rpc_url}: {err}"
+                )));
+                sleep(RPC_RECONNECT_DELAY).await;
+                continue;
+            }
+        };
+        let _ = tx.send(Event::ParachainsCollected(recording.para_ids()));
+        let _ = tx.send(Event::Status(format!("Daily challenge of {day}")));
+
+        let signals: Signals = Rc::default();
+        let cb = block_callback(tx, signals.clone(), Rc::default());
+        start(
+            &signals,
+            Rc::new(ReplaySource::new(recording, 1, total_blocks)).subscribe(cb),
+        );
+        return;
+    }
+}
+
+/// Subscribes the finalized corespace, failing over to the next RPC endpoint when the
+/// subscription can not be recovered.
+async fn subscribe(
+    runtime: SupportedRelayRuntime,
+    rpc_urls: Vec<String>,
+    total_blocks: u32,
+    tx: Sender<Event>,
+) {
+    let signals: Signals = Rc::default();
+    let last_block_number: Rc<Cell<Option<BlockNumber>>> = Rc::default();
+    let mut para_ids_collected = false;
+
+    for rpc_url in rpc_urls.iter().cycle() {
+        let _ = tx.send(Event::Status(format!("Connecting to {rpc_url}")));
+        let api = match connect_healthy_rpc(rpc_url).await {
+            Ok(api) => api,
+            Err(err) => {
+                let _ = tx.send(Event::Status(format!(
+                    "RPC connection could not be established with {rpc_url}: {err}"
+                )));
+                sleep(RPC_RECONNECT_DELAY).await;
+                continue;
+            }
+        };
+
+        let source = relay_chain_source(runtime);
+        if !para_ids_collected {
+            match source.fetch_para_ids(&api).await {
+                Ok(para_ids) => {
+                    para_ids_collected = true;
+                    let _ = tx.send(Event::ParachainsCollected(para_ids));
+                }
+                Err(err) => {
+                    let _ = tx.send(Event::Status(err.to_string()));
+                }
+            }
+        }
+
+        let (events_tx, mut events_rx) = futures::channel::mpsc::unbounded();
+        let on_event = Callback::from(move |(_, event): (SubscriptionId, SubscriptionEvent)| {
+            let _ = events_tx.unbounded_send(event);
+        });
+        let cb = block_callback(tx.clone(), signals.clone(), last_block_number.clone());
+        match source
+            .subscribe_finalized_corespace(api, last_block_number.get(), total_blocks, cb, on_event)
+            .await
+        {
+            Ok(subscription) => start(&signals, subscription),
+            Err(err) => {
+                let _ = tx.send(Event::Status(err.to_string()));
+                continue;
+            }
+        }
+        let _ = tx.send(Event::Status(format!("Connected to {rpc_url}")));
+
+        while let Some(event) = events_rx.next().await {
+            match event {
+                SubscriptionEvent::Reconnecting => {
+                    let _ = tx.send(Event::Status("Reconnecting...".to_string()));
+                }
+                SubscriptionEvent::Reconnected => {
+                    let _ = tx.send(Event::Status(format!("Connected to {rpc_url}")));
+                }
+                SubscriptionEvent::Failed(err) => {
+                    let _ = tx.send(Event::Status(err.to_string()));
+                    break;
+                }
+            }
+        }
+        *signals.borrow_mut() = None;
+    }
+}
@@ -0,0 +1,138 @@
+use anyhow::{anyhow, bail};
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+use corematch_common::types::game::{BoardSize, GameLevel, GameMode};
+use strum::IntoEnumIterator;
+
+pub const USAGE: &str = "Corematch terminal edition
+
+USAGE:
+    corematch-tui [OPTIONS]
+
+OPTIONS:
+    -c, --chain <CHAIN>    Relay chain to play: polkadot, kusama, westend or paseo [default: polkadot]
+    -r, --rpc <URLS>       Comma separated list of RPC endpoints, tried in order
+    -g, --game <MODE>      Game mode: classic, time-attack, zen or daily-challenge [default: classic]
+    -l, --level <LEVEL>    Level to start at, 0 or 1, the upper ones are unlocked by points [default: 0]
+    -b, --board <SIZE>     Board size: 2x2, 3x3 or 4x4 [default: 3x3]
+    -p, --practice         Play synthetic blocks offline
+        --replay <FILE>    Replay a JSON lines recording instead of connecting to the chain
+        --speed <SPEED>    Replay speed, e.g. 6 plays a block every second [default: 1]
+    -h, --help             Print help
+
+KEYS:
+    Arrows move · Enter/Space match · S start/stop · H help · F flip · Q or Ctrl+C quit";
+
+/// Source defines where the blocks played in the terminal come from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Live {
+        rpc_urls: Vec<String>,
+    },
+    /// The blocks of today's daily challenge, fetched from the chain.
+    Daily {
+        rpc_urls: Vec<String>,
+    },
+    Practice,
+    Replay {
+        path: String,
+        speed: u32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub runtime: SupportedRelayRuntime,
+    pub source: Source,
+    pub game_mode: GameMode,
+    pub game_level: GameLevel,
+    pub board_size: BoardSize,
+    pub help: bool,
+}
+
+impl Options {
+    pub fn from_args<I: Iterator<Item = String>>(mut args: I) -> anyhow::Result<Self> {
+        let mut runtime = SupportedRelayRuntime::default();
+        let mut rpc_urls: Vec<String> = Vec::new();
+        let mut practice = false;
+        let mut replay: Option<String> = None;
+        let mut speed: u32 = 1;
+        let mut game_mode = GameMode::default();
+        let mut game_level = GameLevel::Level0;
+        let mut board_size = BoardSize::default();
+        let mut help = false;
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| anyhow!("Missing value for {name}"))
+            };
+            match arg.as_str() {
+                "-c" | "--chain" => runtime = parse_runtime(&value(&arg)?)?,
+                "-r" | "--rpc" => {
+                    rpc_urls = value(&arg)?
+                        .split(',')
+                        .map(|url| url.trim().to_string())
+                        .filter(|url| !url.is_empty())
+                        .collect()
+                }
+                "-g" | "--game" => game_mode = parse_game_mode(&value(&arg)?)?,
+                "-l" | "--level" => game_level = parse_game_level(&value(&arg)?)?,
+                "-b" | "--board" => board_size = value(&arg)?.parse()?,
+                "-p" | "--practice" => practice = true,
+                "--replay" => replay = Some(value(&arg)?),
+                "--speed" => speed = value(&arg)?.parse()?,
+                "-h" | "--help" => help = true,
+                _ => bail!("Unknown argument {arg}"),
+            }
+        }
+
+        if rpc_urls.is_empty() {
+            rpc_urls = runtime
+                .default_rpc_urls()
+                .iter()
+                .map(|url| url.to_string())
+                .collect();
+        }
+
+        let source = match (replay, practice) {
+            (Some(path), _) => Source::Replay {
+                path,
+                speed: speed.max(1),
+            },
+            (None, true) => Source::Practice,
+            (None, false) if game_mode == GameMode::DailyChallenge => Source::Daily { rpc_urls },
+            (None, false) => Source::Live { rpc_urls },
+        };
+
+        Ok(Self {
+            runtime,
+            source,
+            game_mode,
+            game_level,
+            board_size,
+            help,
+        })
+    }
+}
+
+fn parse_runtime(chain: &str) -> anyhow::Result<SupportedRelayRuntime> {
+    SupportedRelayRuntime::iter()
+        .find(|runtime| runtime.class() == chain.to_lowercase())
+        .ok_or_else(|| anyhow!("Unsupported chain {chain}"))
+}
+
+fn parse_game_mode(mode: &str) -> anyhow::Result<GameMode> {
+    GameMode::iter()
+        .find(|game_mode| {
+            game_mode.to_string().to_lowercase().replace(' ', "-") == mode.to_lowercase()
+        })
+        .ok_or_else(|| anyhow!("Unsupported game mode {mode}"))
+}
+
+fn parse_game_level(level: &str) -> anyhow::Result<GameLevel> {
+    match level {
+        "0" => Ok(GameLevel::Level0),
+        "1" => Ok(GameLevel::Level1),
+        _ => Err(anyhow!("Unsupported starting level {level}, only 0 or 1")),
+    }
+}
@@ -0,0 +1,169 @@
+use crate::Event;
+use corematch_common::components::keyboard::SupportedKeys;
+use std::io::{self, Read, Write};
+use std::sync::{mpsc::Sender, OnceLock};
+use std::thread;
+
+// Terminal settings before entering raw mode, restored on exit or panic.
+static ORIGINAL_TERMIOS: OnceLock<libc::termios> = OnceLock::new();
+
+const ENTER_ALTERNATE_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?25h\x1b[?1049l";
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+/// Terminal switches stdin to raw mode and draws on the alternate screen while it is alive.
+pub struct Terminal;
+
+impl Terminal {
+    pub fn enter() -> io::Result<Self> {
+        let fd = libc::STDIN_FILENO;
+        // SAFETY: termios is a plain C struct, fully written by tcgetattr on success
+        let mut original: libc::termios = unsafe { std::mem::zeroed() };
+        if unsafe { libc::tcgetattr(fd, &mut original) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let _ = ORIGINAL_TERMIOS.set(original);
+
+        let mut raw = original;
+        unsafe { libc::cfmakeraw(&mut raw) };
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // NOTE: panic = "abort" skips Drop, so the terminal is also restored from the panic hook
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            restore();
+            default_hook(info);
+        }));
+
+        let mut stdout = io::stdout();
+        stdout.write_all(ENTER_ALTERNATE_SCREEN.as_bytes())?;
+        stdout.flush()?;
+        Ok(Self)
+    }
+
+    /// Replaces the screen with the given frame, lines are expected to end with "\r\n".
+    pub fn draw(&self, frame: &str) -> io::Result<()> {
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(CLEAR_SCREEN.as_bytes())?;
+        stdout.write_all(frame.as_bytes())?;
+        stdout.flush()
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        restore();
+    }
+}
+
+fn restore() {
+    if let Some(original) = ORIGINAL_TERMIOS.get() {
+        unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, original) };
+    }
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(LEAVE_ALTERNATE_SCREEN.as_bytes());
+    let _ = stdout.flush();
+}
+
+/// Reads key presses from stdin on a dedicated thread.
+pub fn spawn_key_reader(tx: Sender<Event>) {
+    thread::spawn(move || {
+        let mut stdin = io::stdin();
+        let mut buf = [0u8; 16];
+        let mut decoder = KeyDecoder::default();
+        while let Ok(n) = stdin.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            for key in decoder.decode(&buf[..n]) {
+                let event = match key {
+                    Key::Quit => Event::Quit,
+                    Key::Named(name) => Event::Key(SupportedKeys::from(name)),
+                };
+                if tx.send(event).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+}
+
+/// Key is a key press read from the terminal.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Key {
+    /// 'q' or 'Ctrl+C'
+    Quit,
+    /// Key named as in the browser, e.g. "ArrowUp".
+    Named(String),
+}
+
+/// KeyDecoder splits the bytes read from stdin into key presses.
+///
+/// A read might hold several key presses, or only part of an escape sequence, which is kept until
+/// the rest of it is read.
+#[derive(Debug, Default)]
+pub struct KeyDecoder {
+    pending: Vec<u8>,
+}
+
+impl KeyDecoder {
+    pub fn decode(&mut self, bytes: &[u8]) -> Vec<Key> {
+        self.pending.extend_from_slice(bytes);
+        let mut keys = Vec::new();
+        let mut i = 0;
+        while i < self.pending.len() {
+            let Some(len) = key_len(&self.pending[i..]) else {
+                break;
+            };
+            let key = &self.pending[i..i + len];
+            match key {
+                b"q" | b"Q" | b"\x03" => keys.push(Key::Quit),
+                // NOTE: the escape key is not played
+                b"\x1b" => (),
+                _ => keys.push(Key::Named(key_name(key))),
+            }
+            i += len;
+        }
+        self.pending.drain(..i);
+        keys
+    }
+}
+
+/// Length of the key press the bytes start with, none if it is not complete yet.
+fn key_len(bytes: &[u8]) -> Option<usize> {
+    match bytes {
+        [] | [b'\x1b'] | [b'\x1b', b'[' | b'O'] => None,
+        // SS3 sequences end with the byte right after the introducer
+        [b'\x1b', b'O', ..] => Some(3),
+        // CSI sequences end with the first byte in the 0x40..=0x7e range
+        [b'\x1b', b'[', rest @ ..] => rest
+            .iter()
+            .position(|byte| (0x40..=0x7e).contains(byte))
+            .map(|j| j + 3),
+        // a lone escape, so Alt+key plays the key alone
+        [b'\x1b', ..] => Some(1),
+        [first, ..] => {
+            // NOTE: multi-byte characters are kept whole
+            let len = match first.leading_ones() {
+                2..=4 => first.leading_ones() as usize,
+                _ => 1,
+            };
+            (bytes.len() >= len).then_some(len)
+        }
+    }
+}
+
+/// Maps the bytes of a key press to the key names used by the browser, so that keys are handled by
+/// the same `SupportedKeys` in both editions.
+pub fn key_name(bytes: &[u8]) -> String {
+    match bytes {
+        b"\x1b[A" | b"\x1bOA" => "ArrowUp".to_string(),
+        b"\x1b[B" | b"\x1bOB" => "ArrowDown".to_string(),
+        b"\x1b[C" | b"\x1bOC" => "ArrowRight".to_string(),
+        b"\x1b[D" | b"\x1bOD" => "ArrowLeft".to_string(),
+        b"\r" | b"\n" => "Enter".to_string(),
+        _ => String::from_utf8_lossy(bytes).to_string(),
+    }
+}
@@ -0,0 +1,26 @@
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+use corematch_tui::board::{hsl_to_rgb, usage_color};
+
+#[test]
+fn hsl_colors_are_converted_to_rgb() {
+    assert_eq!(hsl_to_rgb((0, 100, 50)), (255, 0, 0));
+    assert_eq!(hsl_to_rgb((120, 100, 50)), (0, 255, 0));
+    assert_eq!(hsl_to_rgb((240, 100, 50)), (0, 0, 255));
+    assert_eq!(hsl_to_rgb((60, 100, 25)), (128, 128, 0));
+    assert_eq!(hsl_to_rgb((0, 0, 100)), (255, 255, 255));
+    assert_eq!(hsl_to_rgb((0, 0, 0)), (0, 0, 0));
+    // hues wrap around the color wheel
+    assert_eq!(hsl_to_rgb((480, 100, 50)), hsl_to_rgb((120, 100, 50)));
+}
+
+#[test]
+fn usage_buckets_fade_the_runtime_color() {
+    let polkadot = SupportedRelayRuntime::Polkadot;
+    assert_eq!(usage_color(polkadot, 0), (0, 0, 0));
+    assert_eq!(usage_color(polkadot, 2), (115, 0, 61));
+    assert_eq!(usage_color(polkadot, 4), (230, 0, 122));
+    assert_ne!(
+        usage_color(polkadot, 3),
+        usage_color(SupportedRelayRuntime::Kusama, 3)
+    );
+}
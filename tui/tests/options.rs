@@ -0,0 +1,105 @@
+use corematch_common::runtimes::support::SupportedRelayRuntime;
+use corematch_common::types::game::{BoardSize, GameLevel, GameMode};
+use corematch_tui::options::{Options, Source};
+
+fn options(args: &[&str]) -> anyhow::Result<Options> {
+    Options::from_args(args.iter().map(|arg| arg.to_string()))
+}
+
+#[test]
+fn defaults_to_classic_on_live_polkadot_blocks() {
+    let options = options(&[]).unwrap();
+    assert_eq!(options.runtime, SupportedRelayRuntime::Polkadot);
+    assert_eq!(options.game_mode, GameMode::Classic);
+    assert_eq!(options.game_level, GameLevel::Level0);
+    assert_eq!(options.board_size, BoardSize::Medium);
+    assert!(!options.help);
+    let rpc_urls: Vec<String> = SupportedRelayRuntime::Polkadot
+        .default_rpc_urls()
+        .iter()
+        .map(|url| url.to_string())
+        .collect();
+    assert_eq!(options.source, Source::Live { rpc_urls });
+}
+
+#[test]
+fn rpc_urls_are_given_as_a_comma_separated_list() {
+    let options = options(&["--chain", "Kusama", "-r", "wss://a, ,wss://b"]).unwrap();
+    assert_eq!(options.runtime, SupportedRelayRuntime::Kusama);
+    assert_eq!(
+        options.source,
+        Source::Live {
+            rpc_urls: vec!["wss://a".to_string(), "wss://b".to_string()]
+        }
+    );
+}
+
+#[test]
+fn replays_take_precedence_over_practice() {
+    let options = options(&["-p", "--replay", "blocks.jsonl", "--speed", "0"]).unwrap();
+    assert_eq!(
+        options.source,
+        Source::Replay {
+            path: "blocks.jsonl".to_string(),
+            speed: 1
+        }
+    );
+    assert_eq!(
+        self::options(&["--practice"]).unwrap().source,
+        Source::Practice
+    );
+}
+
+#[test]
+fn daily_challenge_is_fetched_from_the_chain() {
+    let options = options(&["-c", "westend", "-g", "daily-challenge", "-r", "wss://a"]).unwrap();
+    assert_eq!(options.runtime, SupportedRelayRuntime::Westend);
+    assert_eq!(options.game_mode, GameMode::DailyChallenge);
+    assert_eq!(
+        options.source,
+        Source::Daily {
+            rpc_urls: vec!["wss://a".to_string()]
+        }
+    );
+    // the challenge is not played on synthetic blocks
+    assert_eq!(
+        self::options(&["-g", "daily-challenge", "-p"])
+            .unwrap()
+            .source,
+        Source::Practice
+    );
+}
+
+#[test]
+fn games_start_at_the_level_picked() {
+    assert_eq!(
+        options(&["--level", "1"]).unwrap().game_level,
+        GameLevel::Level1
+    );
+    assert_eq!(options(&["-l", "0"]).unwrap().game_level, GameLevel::Level0);
+    // the upper levels have to be unlocked by collecting points
+    assert!(options(&["-l", "2"]).is_err());
+}
+
+#[test]
+fn board_size_is_given_as_columns_by_rows() {
+    assert_eq!(
+        options(&["--board", "4x4"]).unwrap().board_size,
+        BoardSize::Large
+    );
+    assert_eq!(
+        options(&["-b", "2x2"]).unwrap().board_size,
+        BoardSize::Small
+    );
+    assert!(options(&["-b", "3x4"]).is_err());
+}
+
+#[test]
+fn invalid_arguments_are_an_error() {
+    assert!(options(&["--chain", "rococo"]).is_err());
+    assert!(options(&["--game", "blitz"]).is_err());
+    assert!(options(&["--speed", "fast"]).is_err());
+    assert!(options(&["--replay"]).is_err());
+    assert!(options(&["--verbose"]).is_err());
+    assert!(options(&["-h"]).unwrap().help);
+}
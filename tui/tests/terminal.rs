@@ -0,0 +1,55 @@
+use corematch_tui::terminal::{key_name, Key, KeyDecoder};
+
+fn named(name: &str) -> Key {
+    Key::Named(name.to_string())
+}
+
+#[test]
+fn arrows_and_enter_are_named_as_in_the_browser() {
+    assert_eq!(key_name(b"\x1b[A"), "ArrowUp");
+    assert_eq!(key_name(b"\x1bOB"), "ArrowDown");
+    assert_eq!(key_name(b"\x1b[C"), "ArrowRight");
+    assert_eq!(key_name(b"\x1bOD"), "ArrowLeft");
+    assert_eq!(key_name(b"\r"), "Enter");
+    assert_eq!(key_name(b"s"), "s");
+}
+
+#[test]
+fn only_q_and_ctrl_c_quit() {
+    let mut decoder = KeyDecoder::default();
+    assert_eq!(decoder.decode(b"q"), vec![Key::Quit]);
+    assert_eq!(decoder.decode(b"\x03"), vec![Key::Quit]);
+    // the escape key is ignored
+    assert!(decoder.decode(b"\x1b").is_empty());
+    assert_eq!(decoder.decode(b"h"), vec![named("h")]);
+}
+
+#[test]
+fn escape_sequences_split_across_reads_are_kept_whole() {
+    let mut decoder = KeyDecoder::default();
+    assert!(decoder.decode(b"\x1b").is_empty());
+    assert!(decoder.decode(b"[").is_empty());
+    assert_eq!(decoder.decode(b"A"), vec![named("ArrowUp")]);
+    assert!(decoder.decode(b"\x1b[1;5").is_empty());
+    assert_eq!(decoder.decode(b"C"), vec![named("\x1b[1;5C")]);
+}
+
+#[test]
+fn several_keys_read_at_once_are_all_pressed() {
+    let mut decoder = KeyDecoder::default();
+    assert_eq!(
+        decoder.decode(b"\x1b[B\x1bOC \rs"),
+        vec![
+            named("ArrowDown"),
+            named("ArrowRight"),
+            named(" "),
+            named("Enter"),
+            named("s")
+        ]
+    );
+    // Alt+key plays the key alone
+    assert_eq!(decoder.decode(b"\x1bh"), vec![named("h")]);
+    // multi-byte characters are not split
+    assert!(decoder.decode(&"é".as_bytes()[..1]).is_empty());
+    assert_eq!(decoder.decode(&"é".as_bytes()[1..]), vec![named("é")]);
+}